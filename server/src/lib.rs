@@ -1,2 +1,10 @@
+use spacetimedb::{reducer, ReducerContext};
+
 mod tables;
 mod reducers;
+
+#[reducer(init)]
+pub fn init(ctx: &ReducerContext) -> Result<(), String> {
+    reducers::notifications::ensure_prune_schedule(ctx)?;
+    reducers::suggestions::ensure_suggestion_schedule(ctx)
+}
@@ -0,0 +1,234 @@
+use spacetimedb::{reducer, ReducerContext, Table};
+use crate::tables::*;
+use crate::reducers::moderation::{is_blocked, is_muted};
+use crate::reducers::sanctions::is_shadow_banned;
+use std::collections::HashSet;
+
+/// Actors with more followers than this are fanned out on read (refresh_feed)
+/// instead of writing a FeedEntry per follower.
+const FANOUT_ON_WRITE_MAX_FOLLOWERS: usize = 1_000;
+/// Feed rows kept per user; push_feed_entry trims the oldest beyond this.
+const FEED_MAX_ENTRIES: usize = 300;
+/// How far back a first refresh_feed looks for fan-out-on-read events.
+const FEED_BACKFILL_MICROS: u64 = 7 * 24 * 60 * 60 * 1_000_000;
+
+fn now_micros(ctx: &ReducerContext) -> u64 {
+    ctx.timestamp.to_micros_since_unix_epoch() as u64
+}
+
+fn caller_str(ctx: &ReducerContext) -> String {
+    ctx.sender().to_hex().to_string()
+}
+
+/// Whether `owner` should see `event` in their feed.
+fn visible_to(ctx: &ReducerContext, owner: &str, event: &ActivityEvent) -> bool {
+    owner != event.actor_identity
+        && !is_muted(ctx, owner, &event.actor_identity)
+        && !is_blocked(ctx, owner, &event.actor_identity)
+        && (event.target_identity.is_empty() || !is_blocked(ctx, owner, &event.target_identity))
+}
+
+/// Copy `event` into `owner`'s feed, then trim the feed to the newest
+/// FEED_MAX_ENTRIES rows.
+fn push_feed_entry(ctx: &ReducerContext, owner: &str, event: &ActivityEvent) {
+    let exists = ctx
+        .db
+        .feed_entry()
+        .event_id()
+        .filter(event.id)
+        .any(|f| f.owner_identity == owner);
+    if exists {
+        return;
+    }
+    let _ = ctx.db.feed_entry().try_insert(FeedEntry {
+        id: 0,
+        owner_identity: owner.to_string(),
+        event_id: event.id,
+        actor_identity: event.actor_identity.clone(),
+        event_type: event.event_type.clone(),
+        block_id: event.block_id,
+        comment_id: event.comment_id,
+        topic_id: event.topic_id,
+        target_identity: event.target_identity.clone(),
+        created_at: event.created_at,
+    });
+
+    let mut entries: Vec<(u64, u64)> = ctx
+        .db
+        .feed_entry()
+        .owner_identity()
+        .filter(owner)
+        .map(|f| (f.created_at, f.id))
+        .collect();
+    if entries.len() > FEED_MAX_ENTRIES {
+        entries.sort_unstable_by(|a, b| b.cmp(a));
+        for &(_, id) in &entries[FEED_MAX_ENTRIES..] {
+            ctx.db.feed_entry().id().delete(id);
+        }
+    }
+}
+
+/// Record an activity event and, for actors with a modest following, copy it
+/// straight into each follower's feed. Shadow-banned actors record nothing.
+pub fn record_activity(
+    ctx: &ReducerContext,
+    actor: &str,
+    event_type: &str,
+    block_id: u64,
+    comment_id: u64,
+    topic_id: u64,
+    target_identity: &str,
+) -> Result<(), String> {
+    if is_shadow_banned(ctx, actor) {
+        return Ok(());
+    }
+
+    let followers: Vec<String> = ctx
+        .db
+        .user_follow()
+        .following_identity()
+        .filter(actor)
+        .map(|f| f.follower_identity)
+        .collect();
+    let fanned_out = followers.len() <= FANOUT_ON_WRITE_MAX_FOLLOWERS;
+
+    let event = ctx
+        .db
+        .activity_event()
+        .try_insert(ActivityEvent {
+            id: 0,
+            actor_identity: actor.to_string(),
+            event_type: event_type.to_string(),
+            block_id,
+            comment_id,
+            topic_id,
+            target_identity: target_identity.to_string(),
+            created_at: now_micros(ctx),
+            fanned_out,
+        })
+        .map_err(|e| format!("Activity insert failed: {e}"))?;
+
+    if fanned_out {
+        for follower in followers {
+            if visible_to(ctx, &follower, &event) {
+                push_feed_entry(ctx, &follower, &event);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Delete events (and the feed rows copied from them).
+fn delete_events(ctx: &ReducerContext, event_ids: Vec<u64>) {
+    for id in event_ids {
+        ctx.db.feed_entry().event_id().delete(id);
+        ctx.db.activity_event().id().delete(id);
+    }
+}
+
+/// Withdraw an actor's event when they undo it (unlike, unfollow).
+pub fn retract_activity(ctx: &ReducerContext, actor: &str, event_type: &str, block_id: u64, target_identity: &str) {
+    let ids: Vec<u64> = ctx
+        .db
+        .activity_event()
+        .actor_identity()
+        .filter(actor)
+        .filter(|e| e.event_type == event_type && e.block_id == block_id && e.target_identity == target_identity)
+        .map(|e| e.id)
+        .collect();
+    delete_events(ctx, ids);
+}
+
+/// Remove every event about a block that is being unclaimed or removed.
+pub fn remove_block_activity(ctx: &ReducerContext, block_id: u64) {
+    let ids: Vec<u64> = ctx.db.activity_event().block_id().filter(block_id).map(|e| e.id).collect();
+    delete_events(ctx, ids);
+}
+
+/// Remove the event for a deleted comment or repost.
+pub fn remove_comment_activity(ctx: &ReducerContext, block_id: u64, comment_id: u64) {
+    let ids: Vec<u64> = ctx
+        .db
+        .activity_event()
+        .block_id()
+        .filter(block_id)
+        .filter(|e| e.comment_id == comment_id)
+        .map(|e| e.id)
+        .collect();
+    delete_events(ctx, ids);
+}
+
+/// Delete a user's own events, their feed and their cursor (account deletion).
+pub fn remove_user_activity(ctx: &ReducerContext, identity: &str) {
+    let ids: Vec<u64> = ctx.db.activity_event().actor_identity().filter(identity).map(|e| e.id).collect();
+    delete_events(ctx, ids);
+    ctx.db.feed_entry().owner_identity().delete(identity);
+    ctx.db.feed_cursor().identity().delete(identity.to_string());
+}
+
+// ─── refresh_feed ────────────────────────────────────────────────────────────
+
+/// Pull fan-out-on-read events into the caller's feed: activity from followed
+/// users too popular to fan out on write, and new videos in followed topics.
+#[reducer]
+pub fn refresh_feed(ctx: &ReducerContext) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(caller.clone())
+        .ok_or("User not found")?;
+
+    let now = now_micros(ctx);
+    let since = ctx
+        .db
+        .feed_cursor()
+        .identity()
+        .find(caller.clone())
+        .map(|c| c.refreshed_at)
+        .unwrap_or_else(|| now.saturating_sub(FEED_BACKFILL_MICROS));
+
+    let followed_users: HashSet<String> = ctx
+        .db
+        .user_follow()
+        .follower_identity()
+        .filter(caller.as_str())
+        .map(|f| f.following_identity)
+        .collect();
+    let followed_topics: HashSet<u64> = ctx
+        .db
+        .topic_follow()
+        .follower_identity()
+        .filter(caller.as_str())
+        .map(|f| f.topic_id)
+        .collect();
+
+    let pulled: Vec<ActivityEvent> = ctx
+        .db
+        .activity_event()
+        .created_at()
+        .filter(since..)
+        .filter(|e| {
+            (!e.fanned_out && followed_users.contains(&e.actor_identity))
+                || (e.event_type == "video_claimed" && followed_topics.contains(&e.topic_id))
+        })
+        .collect();
+    for event in &pulled {
+        if visible_to(ctx, &caller, event) {
+            push_feed_entry(ctx, &caller, event);
+        }
+    }
+
+    let cursor = FeedCursor { identity: caller.clone(), refreshed_at: now };
+    if ctx.db.feed_cursor().identity().find(caller).is_some() {
+        ctx.db.feed_cursor().identity().update(cursor);
+    } else {
+        ctx.db
+            .feed_cursor()
+            .try_insert(cursor)
+            .map_err(|e| format!("Insert failed: {e}"))?;
+    }
+
+    Ok(())
+}
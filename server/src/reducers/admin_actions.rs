@@ -0,0 +1,195 @@
+use spacetimedb::{reducer, ReducerContext, Table};
+use crate::tables::*;
+use crate::reducers::audit::record_audit;
+
+/// How long a proposal stays confirmable.
+const CONFIRM_WINDOW_MICROS: u64 = 24 * 60 * 60 * 1_000_000;
+
+fn now_micros(ctx: &ReducerContext) -> u64 {
+    ctx.timestamp.to_micros_since_unix_epoch() as u64
+}
+
+fn caller_str(ctx: &ReducerContext) -> String {
+    ctx.sender().to_hex().to_string()
+}
+
+fn is_caller_admin(ctx: &ReducerContext) -> bool {
+    let identity = caller_str(ctx);
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(identity)
+        .map(|u| u.is_admin)
+        .unwrap_or(false)
+}
+
+/// Record a destructive operation that a second, different admin must confirm
+/// via `confirm_admin_action` before the module executes it.
+/// Public so the gated reducers (set_admin, clear_all_blocks, …) can call it.
+pub fn propose_admin_action(
+    ctx: &ReducerContext,
+    action: &str,
+    payload: serde_json::Value,
+) -> Result<u64, String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can propose destructive actions".to_string());
+    }
+
+    let caller = caller_str(ctx);
+    let now = now_micros(ctx);
+    let payload_json = payload.to_string();
+
+    let duplicate = ctx.db.pending_admin_action().iter().any(|a| {
+        a.status == "pending"
+            && a.expires_at > now
+            && a.action == action
+            && a.payload_json == payload_json
+    });
+    if duplicate {
+        return Err("An identical action is already awaiting confirmation".to_string());
+    }
+
+    let pending = ctx
+        .db
+        .pending_admin_action()
+        .try_insert(PendingAdminAction {
+            id: 0,
+            action: action.to_string(),
+            payload_json,
+            proposed_by: caller.clone(),
+            status: "pending".to_string(),
+            resolved_by: String::new(),
+            created_at: now,
+            expires_at: now + CONFIRM_WINDOW_MICROS,
+            resolved_at: 0,
+        })
+        .map_err(|e| format!("Insert failed: {e}"))?;
+
+    record_audit(
+        ctx,
+        "propose_admin_action",
+        "pending_admin_action",
+        &pending.id.to_string(),
+        None,
+        Some(serde_json::json!({ "action": action, "payload": payload })),
+    )?;
+
+    log::info!(
+        "Admin {} proposed '{}' (pending action {})",
+        &caller[..12.min(caller.len())],
+        action,
+        pending.id
+    );
+
+    Ok(pending.id)
+}
+
+fn payload_str(payload: &serde_json::Value, key: &str) -> Result<String, String> {
+    payload[key]
+        .as_str()
+        .map(|s| s.to_string())
+        .ok_or_else(|| format!("Corrupted payload: missing '{key}'"))
+}
+
+fn execute(ctx: &ReducerContext, action: &PendingAdminAction) -> Result<(), String> {
+    let payload: serde_json::Value = serde_json::from_str(&action.payload_json)
+        .map_err(|e| format!("Corrupted payload_json: {e}"))?;
+
+    match action.action.as_str() {
+        "clear_all_blocks" => crate::reducers::seed::execute_clear_all_blocks(ctx),
+        "clear_all_users" => crate::reducers::dev::execute_clear_all_users(ctx),
+        "set_admin" => {
+            let target_identity = payload_str(&payload, "target_identity")?;
+            let is_admin = payload["is_admin"]
+                .as_bool()
+                .ok_or("Corrupted payload: missing 'is_admin'")?;
+            crate::reducers::contest::execute_set_admin(ctx, &target_identity, is_admin)
+        }
+        "finalize_contest" => {
+            let contest_id = payload["contest_id"]
+                .as_u64()
+                .ok_or("Corrupted payload: missing 'contest_id'")?;
+            crate::reducers::contest::execute_finalize_contest(ctx, contest_id)
+        }
+        other => Err(format!("Unknown admin action '{other}'")),
+    }
+}
+
+// ─── confirm_admin_action (admin) ────────────────────────────────────────────
+
+#[reducer]
+pub fn confirm_admin_action(ctx: &ReducerContext, action_id: u64) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can confirm destructive actions".to_string());
+    }
+
+    let caller = caller_str(ctx);
+    let action = ctx
+        .db
+        .pending_admin_action()
+        .id()
+        .find(action_id)
+        .ok_or("Pending action not found")?;
+
+    if action.status != "pending" {
+        return Err("Action has already been resolved".to_string());
+    }
+    if action.proposed_by == caller {
+        return Err("A different admin must confirm this action".to_string());
+    }
+
+    let now = now_micros(ctx);
+    if now > action.expires_at {
+        return Err("Confirmation window has expired — propose the action again".to_string());
+    }
+
+    ctx.db.pending_admin_action().id().update(PendingAdminAction {
+        status: "executed".to_string(),
+        resolved_by: caller,
+        resolved_at: now,
+        ..action.clone()
+    });
+
+    execute(ctx, &action)?;
+
+    log::info!("Pending admin action {} ('{}') confirmed and executed", action_id, action.action);
+    Ok(())
+}
+
+// ─── cancel_admin_action (admin) ─────────────────────────────────────────────
+
+#[reducer]
+pub fn cancel_admin_action(ctx: &ReducerContext, action_id: u64) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can cancel destructive actions".to_string());
+    }
+
+    let action = ctx
+        .db
+        .pending_admin_action()
+        .id()
+        .find(action_id)
+        .ok_or("Pending action not found")?;
+
+    if action.status != "pending" {
+        return Err("Action has already been resolved".to_string());
+    }
+
+    ctx.db.pending_admin_action().id().update(PendingAdminAction {
+        status: "cancelled".to_string(),
+        resolved_by: caller_str(ctx),
+        resolved_at: now_micros(ctx),
+        ..action.clone()
+    });
+
+    record_audit(
+        ctx,
+        "cancel_admin_action",
+        "pending_admin_action",
+        &action_id.to_string(),
+        Some(serde_json::json!({ "action": action.action, "status": "pending" })),
+        Some(serde_json::json!({ "status": "cancelled" })),
+    )?;
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use spacetimedb::{reducer, ReducerContext, Table};
 use crate::tables::*;
+use crate::reducers::audit::record_audit;
 
 fn now_micros(ctx: &ReducerContext) -> u64 {
     ctx.timestamp.to_micros_since_unix_epoch() as u64
@@ -42,7 +43,7 @@ pub fn place_ad(
     let now = now_micros(ctx);
     let expires = now + duration_days * 86_400_000_000;
 
-    ctx.db.ad_placement().try_insert(AdPlacement {
+    let ad = ctx.db.ad_placement().try_insert(AdPlacement {
         id: 0,
         topic_id,
         block_ids_json: block_ids_json.clone(),
@@ -54,6 +55,21 @@ pub fn place_ad(
         expires_at: expires,
     }).map_err(|e| format!("Insert failed: {e}"))?;
 
+    record_audit(
+        ctx,
+        "place_ad",
+        "ad_placement",
+        &ad.id.to_string(),
+        None,
+        Some(serde_json::json!({
+            "topic_id": topic_id,
+            "block_ids": block_ids,
+            "ad_image_url": ad_image_url,
+            "ad_link_url": ad_link_url,
+            "expires_at": expires,
+        })),
+    )?;
+
     // Mark blocks as ad status
     for &bid in &block_ids {
         if let Some(block) = ctx.db.block().id().find(bid) {
@@ -102,6 +118,24 @@ pub fn remove_ad(ctx: &ReducerContext, ad_id: u64) -> Result<(), String> {
     }
 
     ctx.db.ad_placement().id().delete(ad_id);
+
+    record_audit(
+        ctx,
+        "remove_ad",
+        "ad_placement",
+        &ad_id.to_string(),
+        Some(serde_json::json!({
+            "topic_id": ad.topic_id,
+            "block_ids": block_ids,
+            "ad_image_url": ad.ad_image_url,
+            "ad_link_url": ad.ad_link_url,
+            "owner_identity": ad.owner_identity,
+            "paid": ad.paid,
+            "expires_at": ad.expires_at,
+        })),
+        None,
+    )?;
+
     Ok(())
 }
 
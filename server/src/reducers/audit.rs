@@ -0,0 +1,166 @@
+use spacetimedb::{reducer, view, ReducerContext, Table, ViewContext};
+use crate::tables::*;
+
+const MAX_QUERY_RESULTS: u32 = 500;
+
+fn now_micros(ctx: &ReducerContext) -> u64 {
+    ctx.timestamp.to_micros_since_unix_epoch() as u64
+}
+
+fn caller_str(ctx: &ReducerContext) -> String {
+    ctx.sender().to_hex().to_string()
+}
+
+fn is_caller_admin(ctx: &ReducerContext) -> bool {
+    let identity = caller_str(ctx);
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(identity)
+        .map(|u| u.is_admin)
+        .unwrap_or(false)
+}
+
+fn snapshot(value: Option<serde_json::Value>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Append an audit entry for a privileged action performed by the caller.
+/// Called from every admin / moderator reducer; returns an error so a failed
+/// write rolls back the action it describes.
+pub fn record_audit(
+    ctx: &ReducerContext,
+    action: &str,
+    target_type: &str,
+    target_id: &str,
+    before: Option<serde_json::Value>,
+    after: Option<serde_json::Value>,
+) -> Result<(), String> {
+    ctx.db
+        .audit_log()
+        .try_insert(AuditLog {
+            id: 0,
+            actor_identity: caller_str(ctx),
+            action: action.to_string(),
+            target_type: target_type.to_string(),
+            target_id: target_id.to_string(),
+            before_json: snapshot(before),
+            after_json: snapshot(after),
+            created_at: now_micros(ctx),
+        })
+        .map_err(|e| format!("Audit log insert failed: {e}"))?;
+
+    Ok(())
+}
+
+/// Replace the caller's query result rows with the given audit entries (newest first).
+fn publish_query_results(
+    ctx: &ReducerContext,
+    mut entries: Vec<AuditLog>,
+    limit: u32,
+) -> Result<(), String> {
+    let caller = caller_str(ctx);
+
+    let stale_ids: Vec<u64> = ctx
+        .db
+        .audit_log_query_result()
+        .viewer_identity()
+        .filter(&caller)
+        .map(|r| r.id)
+        .collect();
+    for id in stale_ids {
+        ctx.db.audit_log_query_result().id().delete(id);
+    }
+
+    entries.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+    let limit = limit.clamp(1, MAX_QUERY_RESULTS) as usize;
+
+    for entry in entries.into_iter().take(limit) {
+        ctx.db
+            .audit_log_query_result()
+            .try_insert(AuditLogQueryResult {
+                id: 0,
+                viewer_identity: caller.clone(),
+                audit_log_id: entry.id,
+                actor_identity: entry.actor_identity,
+                action: entry.action,
+                target_type: entry.target_type,
+                target_id: entry.target_id,
+                before_json: entry.before_json,
+                after_json: entry.after_json,
+                created_at: entry.created_at,
+            })
+            .map_err(|e| format!("Query result insert failed: {e}"))?;
+    }
+
+    Ok(())
+}
+
+/// The caller's own audit query results. The backing table is private so one
+/// admin's results (and the log entries they contain) are never broadcast to
+/// other clients.
+#[view(accessor = my_audit_log_query_result, public)]
+pub fn my_audit_log_query_result(ctx: &ViewContext) -> Vec<AuditLogQueryResult> {
+    let caller = ctx.sender().to_hex().to_string();
+    ctx.db
+        .audit_log_query_result()
+        .viewer_identity()
+        .filter(&caller)
+        .collect()
+}
+
+// ─── query_audit_log_by_actor (admin) ────────────────────────────────────────
+
+#[reducer]
+pub fn query_audit_log_by_actor(
+    ctx: &ReducerContext,
+    actor_identity: String,
+    limit: u32,
+) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can query the audit log".to_string());
+    }
+
+    let entries: Vec<AuditLog> = ctx
+        .db
+        .audit_log()
+        .iter()
+        .filter(|e| e.actor_identity == actor_identity)
+        .collect();
+
+    publish_query_results(ctx, entries, limit)
+}
+
+// ─── query_audit_log_by_target (admin) ───────────────────────────────────────
+
+#[reducer]
+pub fn query_audit_log_by_target(
+    ctx: &ReducerContext,
+    target_type: String,
+    target_id: String,
+    limit: u32,
+) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can query the audit log".to_string());
+    }
+
+    let entries: Vec<AuditLog> = ctx
+        .db
+        .audit_log()
+        .iter()
+        .filter(|e| e.target_type == target_type && e.target_id == target_id)
+        .collect();
+
+    publish_query_results(ctx, entries, limit)
+}
+
+// ─── clear_audit_log_query (admin) ───────────────────────────────────────────
+
+#[reducer]
+pub fn clear_audit_log_query(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can query the audit log".to_string());
+    }
+
+    publish_query_results(ctx, Vec::new(), 0)
+}
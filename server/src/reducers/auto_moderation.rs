@@ -0,0 +1,291 @@
+use spacetimedb::{reducer, ReducerContext, Table};
+use crate::tables::*;
+use crate::reducers::audit::record_audit;
+use crate::reducers::claim::{adjust_topic_video_count, rebalance_topic_blocks};
+use crate::reducers::moderation::{
+    block_hide_reason, comment_hide_reason, set_block_hide_reason, set_comment_hide_reason,
+};
+use std::collections::HashSet;
+
+/// Weight of a reporter with no history (percent).
+const BASE_WEIGHT: u64 = 100;
+/// Reporters need this many resolved reports before their track record counts.
+const MIN_HISTORY: u64 = 3;
+const MAX_WEIGHT: u64 = 150;
+
+fn now_micros(ctx: &ReducerContext) -> u64 {
+    ctx.timestamp.to_micros_since_unix_epoch() as u64
+}
+
+fn caller_str(ctx: &ReducerContext) -> String {
+    ctx.sender().to_hex().to_string()
+}
+
+fn is_caller_admin(ctx: &ReducerContext) -> bool {
+    let identity = caller_str(ctx);
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(identity)
+        .map(|u| u.is_admin)
+        .unwrap_or(false)
+}
+
+fn default_config() -> AutoModerationConfig {
+    AutoModerationConfig {
+        id: 0,
+        enabled: true,
+        block_min_reporters: 3,
+        block_min_weight: 300,
+        comment_min_reporters: 3,
+        comment_min_weight: 250,
+        new_account_hours: 72,
+        updated_by: String::new(),
+        updated_at: 0,
+    }
+}
+
+fn config(ctx: &ReducerContext) -> AutoModerationConfig {
+    ctx.db
+        .auto_moderation_config()
+        .id()
+        .find(0)
+        .unwrap_or_else(default_config)
+}
+
+/// How much a report from `reporter` counts toward auto-hiding content by `reported`.
+/// Sanctioned reporters and reporters the author has blocked count for nothing,
+/// new accounts count a quarter, and reporters with a history are scaled by how
+/// often their past reports were upheld (50%–150%).
+fn reporter_weight(ctx: &ReducerContext, reporter: &str, reported: &str, config: &AutoModerationConfig) -> u64 {
+    if crate::reducers::sanctions::is_suspended(ctx, reporter)
+        || crate::reducers::sanctions::is_shadow_banned(ctx, reporter)
+    {
+        return 0;
+    }
+
+    let blocked_by_author = ctx
+        .db
+        .user_block()
+        .blocker_identity()
+        .filter(reported)
+        .any(|b| b.blocked_identity == reporter);
+    if blocked_by_author {
+        return 0;
+    }
+
+    let Some(profile) = ctx.db.user_profile().identity().find(reporter.to_string()) else {
+        return 0;
+    };
+    let account_age = now_micros(ctx).saturating_sub(profile.created_at);
+    if account_age < config.new_account_hours.saturating_mul(3_600_000_000) {
+        return BASE_WEIGHT / 4;
+    }
+
+    // "reviewed" closes a report without acting on it, so only "upheld" counts in
+    // the reporter's favour.
+    let (mut upheld, mut not_upheld) = (0u64, 0u64);
+    for report in ctx.db.user_report().reporter_identity().filter(reporter) {
+        match report.status.as_str() {
+            "upheld" => upheld += 1,
+            "reviewed" | "dismissed" => not_upheld += 1,
+            _ => {}
+        }
+    }
+    let resolved = upheld + not_upheld;
+    if resolved < MIN_HISTORY {
+        return BASE_WEIGHT;
+    }
+
+    (BASE_WEIGHT / 2 + BASE_WEIGHT * upheld / resolved).min(MAX_WEIGHT)
+}
+
+/// Hide a block or comment once its open reports pass the configured
+/// distinct-reporter and weight thresholds. Called after every content report.
+pub fn evaluate_queue_item(ctx: &ReducerContext, item: &ModerationQueueItem) -> Result<(), String> {
+    let config = config(ctx);
+    if !config.enabled || item.auto_hidden || item.status != "open" {
+        return Ok(());
+    }
+
+    let (min_reporters, min_weight) = match item.target_type.as_str() {
+        "block" => (config.block_min_reporters, config.block_min_weight),
+        "comment" => (config.comment_min_reporters, config.comment_min_weight),
+        _ => return Ok(()),
+    };
+
+    let reporters: HashSet<String> = ctx
+        .db
+        .user_report()
+        .iter()
+        .filter(|r| r.queue_item_id == item.id && (r.status == "pending" || r.status == "escalated"))
+        .map(|r| r.reporter_identity)
+        .collect();
+    if (reporters.len() as u64) < min_reporters {
+        return Ok(());
+    }
+
+    let weight: u64 = reporters
+        .iter()
+        .map(|r| reporter_weight(ctx, r, &item.reported_identity, &config))
+        .sum();
+    if weight < min_weight {
+        return Ok(());
+    }
+
+    // Only hide content that is currently public; anything already hidden
+    // (shadow ban, topic ban, moderator action) is left as is.
+    match item.target_type.as_str() {
+        "block" => {
+            let Some(block) = ctx.db.block().id().find(item.block_id) else {
+                return Ok(());
+            };
+            if block.status != "claimed" || block.hidden {
+                return Ok(());
+            }
+            let topic_id = block.topic_id;
+            set_block_hide_reason(ctx, block.id, Some("auto_moderation"))?;
+            ctx.db.block().id().update(Block {
+                status: "hidden".to_string(),
+                hidden: true,
+                ..block
+            });
+            adjust_topic_video_count(ctx, topic_id, -1)?;
+            rebalance_topic_blocks(ctx, topic_id)?;
+        }
+        _ => {
+            let Some(comment) = ctx.db.comment().id().find(item.comment_id) else {
+                return Ok(());
+            };
+            if comment.hidden {
+                return Ok(());
+            }
+            set_comment_hide_reason(ctx, comment.id, Some("auto_moderation"))?;
+            ctx.db.comment().id().update(Comment { hidden: true, ..comment });
+        }
+    }
+
+    ctx.db.moderation_queue_item().id().update(ModerationQueueItem {
+        auto_hidden: true,
+        ..item.clone()
+    });
+
+    log::info!(
+        "Auto-moderation hid {} for queue item {} ({} reporters, weight {})",
+        item.target_type,
+        item.id,
+        reporters.len(),
+        weight
+    );
+
+    Ok(())
+}
+
+/// Undo an auto-hide (or release a comment held by the keyword filter) when
+/// moderators close the item without acting on the content.
+pub fn restore_auto_hidden_content(ctx: &ReducerContext, item: &ModerationQueueItem) -> Result<(), String> {
+    if !item.auto_hidden {
+        return Ok(());
+    }
+
+    match item.target_type.as_str() {
+        "block" => {
+            if let Some(block) = ctx.db.block().id().find(item.block_id) {
+                if block.status == "hidden" && block_hide_reason(ctx, block.id).as_deref() == Some("auto_moderation") {
+                    let topic_id = block.topic_id;
+                    set_block_hide_reason(ctx, block.id, None)?;
+                    ctx.db.block().id().update(Block {
+                        status: "claimed".to_string(),
+                        hidden: false,
+                        ..block
+                    });
+                    adjust_topic_video_count(ctx, topic_id, 1)?;
+                    rebalance_topic_blocks(ctx, topic_id)?;
+                }
+            }
+        }
+        "comment" => {
+            if let Some(comment) = ctx.db.comment().id().find(item.comment_id) {
+                let reason = comment_hide_reason(ctx, comment.id);
+                if matches!(reason.as_deref(), Some("auto_moderation") | Some("filter_hold")) {
+                    set_comment_hide_reason(ctx, comment.id, None)?;
+                    ctx.db.comment().id().update(Comment { hidden: false, ..comment });
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+// ─── set_auto_moderation_config (admin) ──────────────────────────────────────
+
+#[reducer]
+pub fn set_auto_moderation_config(
+    ctx: &ReducerContext,
+    enabled: bool,
+    block_min_reporters: u64,
+    block_min_weight: u64,
+    comment_min_reporters: u64,
+    comment_min_weight: u64,
+    new_account_hours: u64,
+) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can configure auto-moderation".to_string());
+    }
+
+    if block_min_reporters < 2 || comment_min_reporters < 2 {
+        return Err("At least 2 distinct reporters are required".to_string());
+    }
+    if block_min_weight == 0 || comment_min_weight == 0 {
+        return Err("Weight thresholds must be greater than 0".to_string());
+    }
+
+    let before = config(ctx);
+    let after = AutoModerationConfig {
+        id: 0,
+        enabled,
+        block_min_reporters,
+        block_min_weight,
+        comment_min_reporters,
+        comment_min_weight,
+        new_account_hours,
+        updated_by: caller_str(ctx),
+        updated_at: now_micros(ctx),
+    };
+
+    if ctx.db.auto_moderation_config().id().find(0).is_some() {
+        ctx.db.auto_moderation_config().id().update(after);
+    } else {
+        ctx.db
+            .auto_moderation_config()
+            .try_insert(after)
+            .map_err(|e| format!("Insert failed: {e}"))?;
+    }
+
+    record_audit(
+        ctx,
+        "set_auto_moderation_config",
+        "auto_moderation_config",
+        "0",
+        Some(serde_json::json!({
+            "enabled": before.enabled,
+            "block_min_reporters": before.block_min_reporters,
+            "block_min_weight": before.block_min_weight,
+            "comment_min_reporters": before.comment_min_reporters,
+            "comment_min_weight": before.comment_min_weight,
+            "new_account_hours": before.new_account_hours,
+        })),
+        Some(serde_json::json!({
+            "enabled": enabled,
+            "block_min_reporters": block_min_reporters,
+            "block_min_weight": block_min_weight,
+            "comment_min_reporters": comment_min_reporters,
+            "comment_min_weight": comment_min_weight,
+            "new_account_hours": new_account_hours,
+        })),
+    )?;
+
+    Ok(())
+}
@@ -1,9 +1,70 @@
-use spacetimedb::{reducer, ReducerContext, Table};
+use spacetimedb::{reducer, view, Query, ReducerContext, Table, ViewContext};
 use crate::tables::*;
 use crate::reducers::topic::{spiral_coords, block_score};
+use crate::reducers::audit::record_audit;
+use crate::reducers::counters::{adjust_likes_received, remove_block_from_counters};
+
+/// A topic's blocks in spiral order: visible claimed blocks by score, then
+/// hidden ones (shadow-banned, or taken off the grid by moderation) so they
+/// keep a position of their own past the end of the visible spiral.
+pub fn ranked_topic_blocks(ctx: &ReducerContext, topic_id: u64) -> Vec<Block> {
+    let mut blocks: Vec<Block> = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|b| b.topic_id == topic_id && (b.status == "claimed" || b.status == "hidden"))
+        .collect();
+
+    blocks.sort_by(|a, b| {
+        let a_visible = a.status == "claimed" && !a.hidden;
+        let b_visible = b.status == "claimed" && !b.hidden;
+        b_visible.cmp(&a_visible).then(block_score(b).cmp(&block_score(a)))
+    });
+    blocks
+}
+
+/// Reassign spiral positions to the first `limit` ranked blocks of a topic.
+pub fn reposition_blocks(ctx: &ReducerContext, ranked: &[Block], limit: usize) -> Result<(), String> {
+    for (i, block) in ranked.iter().take(limit).enumerate() {
+        let (new_x, new_y) = spiral_coords(i as u64);
+        if block.x != new_x || block.y != new_y {
+            ctx.db.block().id().delete(block.id);
+            ctx.db.block().try_insert(Block {
+                x: new_x,
+                y: new_y,
+                ..block.clone()
+            }).map_err(|e| format!("Rebalance insert failed: {e}"))?;
+        }
+    }
+    Ok(())
+}
+
+/// Re-sort a topic's blocks by score and reassign spiral positions so the
+/// visible grid stays compact. Used whenever blocks join or leave the grid.
+pub fn rebalance_topic_blocks(ctx: &ReducerContext, topic_id: u64) -> Result<(), String> {
+    let ranked = ranked_topic_blocks(ctx, topic_id);
+    reposition_blocks(ctx, &ranked, ranked.len())
+}
+
+/// Apply a signed delta to a topic's video_count.
+pub fn adjust_topic_video_count(ctx: &ReducerContext, topic_id: u64, delta: i64) -> Result<(), String> {
+    if let Some(topic) = ctx.db.topic().id().find(topic_id) {
+        let new_count = if delta > 0 {
+            topic.video_count.saturating_add(delta as u64)
+        } else {
+            topic.video_count.saturating_sub((-delta) as u64)
+        };
+        ctx.db.topic().id().delete(topic_id);
+        ctx.db.topic().try_insert(Topic {
+            video_count: new_count,
+            ..topic
+        }).map_err(|e| format!("Topic update failed: {e}"))?;
+    }
+    Ok(())
+}
 
 /// Unclaim a block — removes it from the grid.
-/// Decrements the topic's video_count and rebalances remaining blocks.
+/// For a visible block, decrements the topic's video_count and rebalances remaining blocks.
 #[reducer]
 pub fn unclaim_block(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
@@ -25,46 +86,26 @@ pub fn unclaim_block(ctx: &ReducerContext, block_id: u64) -> Result<(), String>
     let topic_id = block.topic_id;
 
     ctx.db.block().id().delete(block_id);
+    crate::reducers::moderation::clear_block_hide_reason(ctx, block_id);
+    remove_block_from_counters(ctx, &block);
+    crate::reducers::activity::remove_block_activity(ctx, block_id);
 
-    // Decrement video_count so the display reflects the actual number of live videos.
-    if let Some(topic) = ctx.db.topic().id().find(topic_id) {
-        let new_count = topic.video_count.saturating_sub(1);
-        ctx.db.topic().id().delete(topic_id);
-        ctx.db.topic().try_insert(Topic {
-            video_count: new_count,
-            ..topic
-        }).map_err(|e| format!("Topic update failed: {e}"))?;
+    // Decrement video_count so the display reflects the actual number of live videos
+    // (hidden blocks were already taken off the count).
+    if block.status != "claimed" {
+        return Ok(());
     }
+    adjust_topic_video_count(ctx, topic_id, -1)?;
 
     // Rebalance remaining claimed blocks so spiral positions stay compact.
-    let mut claimed: Vec<Block> = ctx
-        .db
-        .block()
-        .iter()
-        .filter(|b| b.topic_id == topic_id && b.status == "claimed")
-        .collect();
-
-    claimed.sort_by(|a, b| block_score(b).cmp(&block_score(a)));
-
-    for (i, block) in claimed.iter().enumerate() {
-        let (new_x, new_y) = spiral_coords(i as u64);
-        if block.x != new_x || block.y != new_y {
-            ctx.db.block().id().delete(block.id);
-            ctx.db.block().try_insert(Block {
-                x: new_x,
-                y: new_y,
-                ..block.clone()
-            }).map_err(|e| format!("Rebalance insert failed: {e}"))?;
-        }
-    }
-
-    Ok(())
+    rebalance_topic_blocks(ctx, topic_id)
 }
 
 /// Remove a block from a topic — topic owner, moderator, or admin only.
 #[reducer]
 pub fn mod_remove_block(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let block = ctx
         .db
@@ -92,42 +133,46 @@ pub fn mod_remove_block(ctx: &ReducerContext, block_id: u64) -> Result<(), Strin
         return Err("Not authorized — must be topic owner, moderator, or admin".to_string());
     }
 
+    remove_block_as_moderator(ctx, block)
+}
+
+/// Delete a block on behalf of a moderator, audit it, and rebalance the topic.
+/// Callers must have checked moderation rights. Also used when resolving reports.
+pub fn remove_block_as_moderator(ctx: &ReducerContext, block: Block) -> Result<(), String> {
+    let block_id = block.id;
+    let topic_id = block.topic_id;
+
     // Delete the block
     ctx.db.block().id().delete(block_id);
-
-    // Decrement video_count
-    if let Some(t) = ctx.db.topic().id().find(topic_id) {
-        let new_count = t.video_count.saturating_sub(1);
-        ctx.db.topic().id().delete(topic_id);
-        ctx.db.topic().try_insert(Topic {
-            video_count: new_count,
-            ..t
-        }).map_err(|e| format!("Topic update failed: {e}"))?;
+    crate::reducers::moderation::clear_block_hide_reason(ctx, block_id);
+    remove_block_from_counters(ctx, &block);
+    crate::reducers::activity::remove_block_activity(ctx, block_id);
+
+    record_audit(
+        ctx,
+        "mod_remove_block",
+        "block",
+        &block_id.to_string(),
+        Some(serde_json::json!({
+            "topic_id": block.topic_id,
+            "video_id": block.video_id,
+            "platform": block.platform,
+            "owner_identity": block.owner_identity,
+            "likes": block.likes,
+            "dislikes": block.dislikes,
+            "status": block.status,
+        })),
+        None,
+    )?;
+
+    // Decrement video_count (hidden blocks were already taken off the count)
+    if block.status != "claimed" {
+        return Ok(());
     }
+    adjust_topic_video_count(ctx, topic_id, -1)?;
 
     // Rebalance remaining blocks
-    let mut claimed: Vec<Block> = ctx
-        .db
-        .block()
-        .iter()
-        .filter(|b| b.topic_id == topic_id && b.status == "claimed")
-        .collect();
-
-    claimed.sort_by(|a, b| block_score(b).cmp(&block_score(a)));
-
-    for (i, blk) in claimed.iter().enumerate() {
-        let (new_x, new_y) = spiral_coords(i as u64);
-        if blk.x != new_x || blk.y != new_y {
-            ctx.db.block().id().delete(blk.id);
-            ctx.db.block().try_insert(Block {
-                x: new_x,
-                y: new_y,
-                ..blk.clone()
-            }).map_err(|e| format!("Rebalance insert failed: {e}"))?;
-        }
-    }
-
-    Ok(())
+    rebalance_topic_blocks(ctx, topic_id)
 }
 
 /// Edit a block — swap the video URL. Only the block owner can do this.
@@ -143,6 +188,7 @@ pub fn edit_block(
     new_yt_likes: u64,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let block = ctx.db.block().id().find(block_id).ok_or("Block not found")?;
 
@@ -152,6 +198,7 @@ pub fn edit_block(
     if block.status != "claimed" {
         return Err("Block is not claimed".to_string());
     }
+    crate::reducers::moderation::ensure_not_topic_banned(ctx, block.topic_id, &caller)?;
     if new_video_id.trim().is_empty() {
         return Err("video_id cannot be empty".to_string());
     }
@@ -179,6 +226,8 @@ pub fn edit_block(
         .map(|d| d.id).collect();
     for id in dislike_ids { ctx.db.dislike_record().id().delete(id); }
 
+    adjust_likes_received(ctx, &block.owner_identity, -(block.likes as i64));
+
     // Update topic totals
     if let Some(topic) = ctx.db.topic().id().find(block.topic_id) {
         ctx.db.topic().id().delete(block.topic_id);
@@ -203,17 +252,14 @@ pub fn edit_block(
     }).map_err(|e| format!("Block update failed: {e}"))?;
 
     // Rebalance
-    let mut remaining: Vec<Block> = ctx.db.block().iter()
-        .filter(|b| b.topic_id == block.topic_id && b.status == "claimed")
-        .collect();
-    remaining.sort_by(|a, b| block_score(b).cmp(&block_score(a)));
-    for (i, rb) in remaining.iter().enumerate() {
-        let (nx, ny) = spiral_coords(i as u64);
-        if rb.x != nx || rb.y != ny {
-            ctx.db.block().id().delete(rb.id);
-            let _ = ctx.db.block().try_insert(Block { x: nx, y: ny, ..rb.clone() });
-        }
-    }
+    rebalance_topic_blocks(ctx, block.topic_id)
+}
 
-    Ok(())
+/// Blocks the caller may see: everything not hidden, plus the caller's own
+/// hidden blocks. Clients subscribe to this instead of the block table so
+/// shadow-banned videos never reach other viewers.
+#[view(accessor = visible_block, public)]
+pub fn visible_block(ctx: &ViewContext) -> impl Query<Block> {
+    let viewer = ctx.sender().to_hex().to_string();
+    ctx.from.block().r#where(|b| b.hidden.eq(false).or(b.owner_identity.eq(viewer.clone())))
 }
@@ -1,5 +1,13 @@
-use spacetimedb::{reducer, ReducerContext, Table};
+use spacetimedb::{reducer, view, Query, ReducerContext, Table, ViewContext};
 use crate::tables::*;
+use crate::reducers::activity::{record_activity, remove_comment_activity};
+use crate::reducers::filter::filter_text;
+use crate::reducers::moderation::{
+    clear_comment_hide_reason, ensure_not_topic_banned, hold_comment_for_review, is_blocked, is_muted,
+    set_comment_hide_reason,
+};
+use crate::reducers::notifications::notify_activity;
+use crate::reducers::sanctions::new_content_hide_reason;
 
 fn now_micros(ctx: &ReducerContext) -> u64 {
     ctx.timestamp.to_micros_since_unix_epoch() as u64
@@ -38,23 +46,19 @@ fn insert_notification(
     block_id: u64,
     comment_id: u64,
 ) {
-    // Never notify yourself
-    if recipient_identity == actor_identity {
-        return;
-    }
-    let _ = ctx.db.notification().try_insert(Notification {
-        id: 0,
-        recipient_identity,
-        actor_identity,
-        actor_name,
-        notification_type: notification_type.to_string(),
+    notify_activity(ctx, Notification {
         block_id,
         comment_id,
-        is_read: false,
-        created_at: now_micros(ctx),
+        ..Notification::new(ctx, &recipient_identity, &actor_identity, &actor_name, notification_type)
     });
 }
 
+/// Hide reason for a new comment: shadow bans win (held text from a
+/// shadow-banned author is never shown to anyone else anyway), then filter holds.
+fn new_comment_hide_reason(ctx: &ReducerContext, held: bool) -> Option<&'static str> {
+    new_content_hide_reason(ctx).or(if held { Some("filter_hold") } else { None })
+}
+
 // ─── add_comment ─────────────────────────────────────────────────────────────
 
 #[reducer]
@@ -65,11 +69,13 @@ pub fn add_comment(
     parent_comment_id: Option<u64>,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let block = ctx.db.block().id().find(block_id).ok_or("Block not found")?;
     if block.status != "claimed" {
         return Err("Block has no video".to_string());
     }
+    ensure_not_topic_banned(ctx, block.topic_id, &caller)?;
 
     let trimmed = text.trim().to_string();
     if trimmed.is_empty() {
@@ -78,6 +84,8 @@ pub fn add_comment(
     if trimmed.len() > 280 {
         return Err("Comment too long (max 280 chars)".to_string());
     }
+    let (trimmed, held) = filter_text(ctx, &trimmed, block.topic_id)?;
+    let hide_reason = new_comment_hide_reason(ctx, held);
 
     // Validate parent exists if this is a reply
     if let Some(parent_id) = parent_comment_id {
@@ -91,9 +99,12 @@ pub fn add_comment(
         if parent.block_id != block_id {
             return Err("Parent comment belongs to a different block".to_string());
         }
+        if is_blocked(ctx, &caller, &parent.user_identity) {
+            return Err("Cannot reply to a blocked user".to_string());
+        }
 
         // Insert the reply
-        let comment_id = ctx
+        let reply = ctx
             .db
             .comment()
             .try_insert(Comment {
@@ -109,9 +120,11 @@ pub fn add_comment(
                 replies_count: 0,
                 reposts_count: 0,
                 edited_at: 0,
+                hidden: hide_reason.is_some(),
             })
-            .map_err(|e| format!("Insert failed: {e}"))?
-            .id;
+            .map_err(|e| format!("Insert failed: {e}"))?;
+        let comment_id = reply.id;
+        set_comment_hide_reason(ctx, comment_id, hide_reason)?;
 
         // Increment parent's reply count
         let updated_parent = Comment {
@@ -120,19 +133,30 @@ pub fn add_comment(
         };
         ctx.db.comment().id().update(updated_parent.clone());
 
-        // Notify the parent comment author
-        insert_notification(
-            ctx,
-            updated_parent.user_identity,
-            caller,
-            caller_name(ctx),
-            "comment_reply",
-            block_id,
-            comment_id,
-        );
+        add_to_comment_views(ctx, &reply);
+
+        if !reply.hidden {
+            record_activity(ctx, &caller, "comment_posted", block_id, comment_id, block.topic_id, &updated_parent.user_identity)?;
+        }
+
+        if hide_reason == Some("filter_hold") {
+            hold_comment_for_review(ctx, &reply, block.topic_id)?;
+        } else {
+            // Notify the parent comment author; replies group on the parent.
+            insert_notification(
+                ctx,
+                updated_parent.user_identity,
+                caller,
+                caller_name(ctx),
+                "comment_reply",
+                block_id,
+                parent_id,
+            );
+        }
     } else {
         // Top-level comment
-        ctx.db
+        let comment = ctx
+            .db
             .comment()
             .try_insert(Comment {
                 id: 0,
@@ -147,8 +171,20 @@ pub fn add_comment(
                 replies_count: 0,
                 reposts_count: 0,
                 edited_at: 0,
+                hidden: hide_reason.is_some(),
             })
             .map_err(|e| format!("Insert failed: {e}"))?;
+        set_comment_hide_reason(ctx, comment.id, hide_reason)?;
+
+        add_to_comment_views(ctx, &comment);
+
+        if !comment.hidden {
+            record_activity(ctx, &comment.user_identity, "comment_posted", block_id, comment.id, block.topic_id, &block.owner_identity)?;
+        }
+
+        if hide_reason == Some("filter_hold") {
+            hold_comment_for_review(ctx, &comment, block.topic_id)?;
+        }
     }
 
     Ok(())
@@ -164,11 +200,13 @@ pub fn repost_comment(
     text: String,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let block = ctx.db.block().id().find(block_id).ok_or("Block not found")?;
     if block.status != "claimed" {
         return Err("Block has no video".to_string());
     }
+    ensure_not_topic_banned(ctx, block.topic_id, &caller)?;
 
     let original = ctx
         .db
@@ -177,6 +215,10 @@ pub fn repost_comment(
         .find(original_comment_id)
         .ok_or("Original comment not found")?;
 
+    if is_blocked(ctx, &caller, &original.user_identity) {
+        return Err("Cannot repost a blocked user's comment".to_string());
+    }
+
     let trimmed = text.trim().to_string();
     if trimmed.is_empty() {
         return Err("Repost quote cannot be empty".to_string());
@@ -184,8 +226,10 @@ pub fn repost_comment(
     if trimmed.len() > 280 {
         return Err("Quote text too long (max 280 chars)".to_string());
     }
+    let (trimmed, held) = filter_text(ctx, &trimmed, block.topic_id)?;
+    let hide_reason = new_comment_hide_reason(ctx, held);
 
-    let comment_id = ctx
+    let repost = ctx
         .db
         .comment()
         .try_insert(Comment {
@@ -201,9 +245,11 @@ pub fn repost_comment(
             replies_count: 0,
             reposts_count: 0,
             edited_at: 0,
+            hidden: hide_reason.is_some(),
         })
-        .map_err(|e| format!("Insert failed: {e}"))?
-        .id;
+        .map_err(|e| format!("Insert failed: {e}"))?;
+    let comment_id = repost.id;
+    set_comment_hide_reason(ctx, comment_id, hide_reason)?;
 
     // Increment original's repost count
     let updated_original = Comment {
@@ -212,16 +258,26 @@ pub fn repost_comment(
     };
     ctx.db.comment().id().update(updated_original);
 
-    // Notify original author
-    insert_notification(
-        ctx,
-        original.user_identity,
-        caller,
-        caller_name(ctx),
-        "comment_repost",
-        block_id,
-        comment_id,
-    );
+    add_to_comment_views(ctx, &repost);
+
+    if !repost.hidden {
+        record_activity(ctx, &caller, "comment_reposted", block_id, comment_id, block.topic_id, &original.user_identity)?;
+    }
+
+    if hide_reason == Some("filter_hold") {
+        hold_comment_for_review(ctx, &repost, block.topic_id)?;
+    } else {
+        // Notify original author; reposts group on the original.
+        insert_notification(
+            ctx,
+            original.user_identity,
+            caller,
+            caller_name(ctx),
+            "comment_repost",
+            block_id,
+            original.id,
+        );
+    }
 
     Ok(())
 }
@@ -231,6 +287,7 @@ pub fn repost_comment(
 #[reducer]
 pub fn like_comment(ctx: &ReducerContext, comment_id: u64) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let comment = ctx
         .db
@@ -239,6 +296,13 @@ pub fn like_comment(ctx: &ReducerContext, comment_id: u64) -> Result<(), String>
         .find(comment_id)
         .ok_or("Comment not found")?;
 
+    if let Some(block) = ctx.db.block().id().find(comment.block_id) {
+        ensure_not_topic_banned(ctx, block.topic_id, &caller)?;
+    }
+    if is_blocked(ctx, &caller, &comment.user_identity) {
+        return Err("Cannot like a blocked user's comment".to_string());
+    }
+
     // Idempotent: skip if already liked
     let already_liked = ctx
         .db
@@ -323,6 +387,7 @@ pub fn edit_comment(
     new_text: String,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let comment = ctx
         .db
@@ -334,6 +399,16 @@ pub fn edit_comment(
     if comment.user_identity != caller {
         return Err("Not authorized to edit this comment".to_string());
     }
+    let topic_id = ctx
+        .db
+        .block()
+        .id()
+        .find(comment.block_id)
+        .map(|b| b.topic_id)
+        .unwrap_or(0);
+    if topic_id != 0 {
+        ensure_not_topic_banned(ctx, topic_id, &caller)?;
+    }
 
     let trimmed = new_text.trim().to_string();
     if trimmed.is_empty() {
@@ -342,13 +417,22 @@ pub fn edit_comment(
     if trimmed.len() > 280 {
         return Err("Comment too long (max 280 chars)".to_string());
     }
+    let (trimmed, held) = filter_text(ctx, &trimmed, topic_id)?;
 
+    // Only visible comments get held; anything already hidden stays as it is.
+    let hold = held && !comment.hidden;
     let updated = Comment {
         text: trimmed,
         edited_at: now_micros(ctx),
+        hidden: hold || comment.hidden,
         ..comment
     };
-    ctx.db.comment().id().update(updated);
+    ctx.db.comment().id().update(updated.clone());
+
+    if hold {
+        set_comment_hide_reason(ctx, comment_id, Some("filter_hold"))?;
+        hold_comment_for_review(ctx, &updated, topic_id)?;
+    }
 
     Ok(())
 }
@@ -370,6 +454,15 @@ pub fn delete_comment(ctx: &ReducerContext, comment_id: u64) -> Result<(), Strin
         return Err("Not authorized".to_string());
     }
 
+    delete_comment_cascade(ctx, &comment);
+    Ok(())
+}
+
+/// Delete a comment together with its likes, its direct replies (and their likes),
+/// and fix up the parent's reply count. Public so moderation can remove content in bulk.
+pub fn delete_comment_cascade(ctx: &ReducerContext, comment: &Comment) {
+    let comment_id = comment.id;
+
     // Cascade: delete all likes on this comment
     let like_ids: Vec<u64> = ctx
         .db
@@ -390,7 +483,7 @@ pub fn delete_comment(ctx: &ReducerContext, comment_id: u64) -> Result<(), Strin
         .filter(|c| c.parent_comment_id == Some(comment_id))
         .map(|c| c.id)
         .collect();
-    for rid in reply_ids {
+    for &rid in &reply_ids {
         // Also delete likes on each reply
         let child_like_ids: Vec<u64> = ctx
             .db
@@ -403,6 +496,8 @@ pub fn delete_comment(ctx: &ReducerContext, comment_id: u64) -> Result<(), Strin
             ctx.db.comment_like().id().delete(lid);
         }
         ctx.db.comment().id().delete(rid);
+        clear_comment_hide_reason(ctx, rid);
+        remove_comment_activity(ctx, comment.block_id, rid);
     }
 
     // Update parent's reply count if this was a reply
@@ -417,9 +512,122 @@ pub fn delete_comment(ctx: &ReducerContext, comment_id: u64) -> Result<(), Strin
     }
 
     ctx.db.comment().id().delete(comment_id);
+    clear_comment_hide_reason(ctx, comment_id);
+    remove_comment_activity(ctx, comment.block_id, comment_id);
+
+    let entry_ids: Vec<u64> = ctx
+        .db
+        .comment_view_entry()
+        .iter()
+        .filter(|e| e.comment_id == comment_id || reply_ids.contains(&e.comment_id))
+        .map(|e| e.id)
+        .collect();
+    for id in entry_ids {
+        ctx.db.comment_view_entry().id().delete(id);
+    }
+}
+
+// ─── comment views ───────────────────────────────────────────────────────────
+
+/// Replace `viewer`'s comment view with `block_id`'s comments, leaving out
+/// authors the viewer has muted. Public so mute changes can rebuild it.
+pub fn publish_comment_view(ctx: &ReducerContext, viewer: &str, block_id: u64) {
+    let stale_ids: Vec<u64> = ctx
+        .db
+        .comment_view_entry()
+        .viewer_identity()
+        .filter(viewer)
+        .map(|e| e.id)
+        .collect();
+    for id in stale_ids {
+        ctx.db.comment_view_entry().id().delete(id);
+    }
+
+    let comments: Vec<Comment> = ctx
+        .db
+        .comment()
+        .iter()
+        .filter(|c| c.block_id == block_id && !is_muted(ctx, viewer, &c.user_identity))
+        .collect();
+    for comment in comments {
+        let _ = ctx.db.comment_view_entry().try_insert(CommentViewEntry {
+            id: 0,
+            viewer_identity: viewer.to_string(),
+            block_id,
+            comment_id: comment.id,
+        });
+    }
+
+    let view = CommentView {
+        viewer_identity: viewer.to_string(),
+        block_id,
+        loaded_at: now_micros(ctx),
+    };
+    if ctx.db.comment_view().viewer_identity().find(viewer.to_string()).is_some() {
+        ctx.db.comment_view().viewer_identity().update(view);
+    } else {
+        let _ = ctx.db.comment_view().try_insert(view);
+    }
+}
+
+/// Add a new comment to every open view of its block whose viewer hasn't muted the author.
+fn add_to_comment_views(ctx: &ReducerContext, comment: &Comment) {
+    let viewers: Vec<String> = ctx
+        .db
+        .comment_view()
+        .iter()
+        .filter(|v| v.block_id == comment.block_id && !is_muted(ctx, &v.viewer_identity, &comment.user_identity))
+        .map(|v| v.viewer_identity)
+        .collect();
+    for viewer in viewers {
+        let _ = ctx.db.comment_view_entry().try_insert(CommentViewEntry {
+            id: 0,
+            viewer_identity: viewer,
+            block_id: comment.block_id,
+            comment_id: comment.id,
+        });
+    }
+}
+
+#[reducer]
+pub fn load_comment_view(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
+    ctx.db.block().id().find(block_id).ok_or("Block not found")?;
+    publish_comment_view(ctx, &caller_str(ctx), block_id);
     Ok(())
 }
 
+#[reducer]
+pub fn clear_comment_view(ctx: &ReducerContext) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    let entry_ids: Vec<u64> = ctx
+        .db
+        .comment_view_entry()
+        .viewer_identity()
+        .filter(&caller)
+        .map(|e| e.id)
+        .collect();
+    for id in entry_ids {
+        ctx.db.comment_view_entry().id().delete(id);
+    }
+    ctx.db.comment_view().viewer_identity().delete(caller);
+    Ok(())
+}
+
+/// Comments the caller may see: everything not hidden, plus the caller's own
+/// hidden comments, so authors can't tell their comments are hidden.
+#[view(accessor = visible_comment, public)]
+pub fn visible_comment(ctx: &ViewContext) -> impl Query<Comment> {
+    let viewer = ctx.sender().to_hex().to_string();
+    ctx.from.comment().r#where(|c| c.hidden.eq(false).or(c.user_identity.eq(viewer.clone())))
+}
+
+/// The caller's own comment view entries.
+#[view(accessor = my_comment_view_entry, public)]
+pub fn my_comment_view_entry(ctx: &ViewContext) -> Vec<CommentViewEntry> {
+    let caller = ctx.sender().to_hex().to_string();
+    ctx.db.comment_view_entry().viewer_identity().filter(&caller).collect()
+}
+
 // ─── mark_notification_read ───────────────────────────────────────────────────
 
 #[reducer]
@@ -458,8 +666,9 @@ pub fn mark_all_notifications_read(ctx: &ReducerContext) -> Result<(), String> {
     let to_update: Vec<Notification> = ctx
         .db
         .notification()
-        .iter()
-        .filter(|n| n.recipient_identity == caller && !n.is_read)
+        .recipient_identity()
+        .filter(caller.as_str())
+        .filter(|n| !n.is_read)
         .collect();
 
     for notif in to_update {
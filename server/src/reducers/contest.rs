@@ -1,5 +1,12 @@
 use spacetimedb::{reducer, ReducerContext, Table};
 use crate::tables::*;
+use crate::reducers::audit::record_audit;
+use crate::reducers::admin_actions::propose_admin_action;
+use crate::reducers::counters::adjust_follow_counts;
+use crate::reducers::filter::filter_text_or_reject;
+
+/// Prize pools above this (in cents) need a second admin to confirm finalization.
+const FINALIZE_CONFIRMATION_THRESHOLD: u64 = 100_000;
 
 fn now_micros(ctx: &ReducerContext) -> u64 {
     ctx.timestamp.to_micros_since_unix_epoch() as u64
@@ -37,6 +44,9 @@ pub fn create_contest(
     Ok(())
 }
 
+/// Finalize a contest. Contests whose prize pool exceeds
+/// FINALIZE_CONFIRMATION_THRESHOLD are only proposed here and run once a
+/// second admin confirms via `confirm_admin_action`.
 #[reducer]
 pub fn finalize_contest(ctx: &ReducerContext, contest_id: u64) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
@@ -56,6 +66,28 @@ pub fn finalize_contest(ctx: &ReducerContext, contest_id: u64) -> Result<(), Str
         return Err("Contest is not active".to_string());
     }
 
+    if contest.prize_pool > FINALIZE_CONFIRMATION_THRESHOLD {
+        propose_admin_action(ctx, "finalize_contest", serde_json::json!({ "contest_id": contest_id }))?;
+        return Ok(());
+    }
+
+    execute_finalize_contest(ctx, contest_id)
+}
+
+/// Rank the top blocks and record winners. Called directly by `finalize_contest`
+/// for small prize pools, or by `confirm_admin_action` for large ones.
+pub fn execute_finalize_contest(ctx: &ReducerContext, contest_id: u64) -> Result<(), String> {
+    let contest = ctx
+        .db
+        .contest()
+        .id()
+        .find(contest_id)
+        .ok_or("Contest not found")?;
+
+    if contest.status != "active" {
+        return Err("Contest is not active".to_string());
+    }
+
     ctx.db.contest().id().delete(contest_id);
     ctx.db.contest().try_insert(Contest {
         status: "finalizing".to_string(),
@@ -96,9 +128,29 @@ pub fn finalize_contest(ctx: &ReducerContext, contest_id: u64) -> Result<(), Str
     ctx.db.contest().id().delete(contest_id);
     ctx.db.contest().try_insert(Contest {
         status: "completed".to_string(),
-        ..contest
+        ..contest.clone()
     }).map_err(|e| format!("Insert failed: {e}"))?;
 
+    let winners: Vec<serde_json::Value> = claimed_blocks
+        .iter()
+        .take(top_count)
+        .enumerate()
+        .map(|(i, b)| serde_json::json!({
+            "rank": i + 1,
+            "block_id": b.id,
+            "owner_identity": b.owner_identity,
+            "prize_amount": prize_per_winner,
+        }))
+        .collect();
+    record_audit(
+        ctx,
+        "finalize_contest",
+        "contest",
+        &contest_id.to_string(),
+        Some(serde_json::json!({ "status": contest.status, "prize_pool": contest.prize_pool })),
+        Some(serde_json::json!({ "status": "completed", "winners": winners })),
+    )?;
+
     log::info!(
         "Contest {} finalized with {} winners",
         contest_id,
@@ -142,11 +194,17 @@ pub fn register_user(
         social_youtube: None,
         social_tiktok: None,
         social_instagram: None,
+        is_private: false,
+        follower_count: 0,
+        following_count: 0,
+        topic_follow_count: 0,
+        total_likes_received: 0,
+        videos_posted: 0,
     }).map_err(|e| format!("Insert failed: {e}"))?;
 
     ctx.db.credit_transaction_log().try_insert(CreditTransactionLog {
         id: 0,
-        user_identity: caller,
+        user_identity: caller.clone(),
         tx_type: "signup_bonus".to_string(),
         amount: SIGNUP_CREDITS as i64,
         balance_after: SIGNUP_CREDITS,
@@ -155,6 +213,8 @@ pub fn register_user(
         created_at: now,
     }).map_err(|e| format!("Credit log insert failed: {e}"))?;
 
+    crate::reducers::notifications::create_default_preferences(ctx, &caller)?;
+
     Ok(())
 }
 
@@ -166,6 +226,7 @@ pub fn update_profile(
     email: String,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let user = ctx
         .db
@@ -200,6 +261,7 @@ pub fn update_profile_details(
     social_instagram: String,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let user = ctx
         .db
@@ -219,6 +281,14 @@ pub fn update_profile_details(
         return Err("Website URL too long (max 200 chars)".to_string());
     }
 
+    let bio = filter_text_or_reject(ctx, &bio, 0)?;
+    let location = filter_text_or_reject(ctx, &location, 0)?;
+    let website_url = filter_text_or_reject(ctx, &website_url, 0)?;
+    let social_x = filter_text_or_reject(ctx, &social_x, 0)?;
+    let social_youtube = filter_text_or_reject(ctx, &social_youtube, 0)?;
+    let social_tiktok = filter_text_or_reject(ctx, &social_tiktok, 0)?;
+    let social_instagram = filter_text_or_reject(ctx, &social_instagram, 0)?;
+
     fn opt(s: String) -> Option<String> {
         if s.is_empty() { None } else { Some(s) }
     }
@@ -345,6 +415,9 @@ pub fn server_delete_user(ctx: &ReducerContext, clerk_user_id: String) -> Result
             stripe_account_id: String::new(),
             credits: 0,
             is_admin: false,
+            follower_count: 0,
+            following_count: 0,
+            topic_follow_count: 0,
             ..user
         }).map_err(|e| format!("Insert failed: {e}"))?;
     }
@@ -352,20 +425,32 @@ pub fn server_delete_user(ctx: &ReducerContext, clerk_user_id: String) -> Result
     // Cascade: clean up all related records for this identity
 
     // User follows (both directions)
-    let follow_ids: Vec<u64> = ctx.db.user_follow().iter()
-        .filter(|f| f.follower_identity == identity || f.following_identity == identity)
-        .map(|f| f.id).collect();
-    for id in follow_ids { ctx.db.user_follow().id().delete(id); }
+    let follows: Vec<UserFollow> = ctx.db.user_follow().follower_identity().filter(&identity)
+        .chain(ctx.db.user_follow().following_identity().filter(&identity))
+        .collect();
+    for f in follows {
+        ctx.db.user_follow().id().delete(f.id);
+        // The deleted profile was zeroed above; this fixes the other side.
+        adjust_follow_counts(ctx, &f.follower_identity, &f.following_identity, -1);
+    }
+
+    // Follow requests (both directions)
+    let request_ids: Vec<u64> = ctx.db.follow_request().iter()
+        .filter(|r| r.requester_identity == identity || r.target_identity == identity)
+        .map(|r| r.id).collect();
+    for id in request_ids { ctx.db.follow_request().id().delete(id); }
 
     // Topic follows
-    let topic_follow_ids: Vec<u64> = ctx.db.topic_follow().iter()
-        .filter(|f| f.follower_identity == identity)
-        .map(|f| f.id).collect();
-    for id in topic_follow_ids { ctx.db.topic_follow().id().delete(id); }
+    let topic_follows: Vec<TopicFollow> = ctx.db.topic_follow().follower_identity().filter(&identity)
+        .collect();
+    for f in topic_follows {
+        ctx.db.topic_follow().id().delete(f.id);
+        crate::reducers::topic::adjust_topic_follower_count(ctx, f.topic_id, -1)?;
+    }
 
     // User blocks (both directions)
-    let block_ids: Vec<u64> = ctx.db.user_block().iter()
-        .filter(|b| b.blocker_identity == identity || b.blocked_identity == identity)
+    let block_ids: Vec<u64> = ctx.db.user_block().blocker_identity().filter(&identity)
+        .chain(ctx.db.user_block().blocked_identity().filter(&identity))
         .map(|b| b.id).collect();
     for id in block_ids { ctx.db.user_block().id().delete(id); }
 
@@ -373,13 +458,43 @@ pub fn server_delete_user(ctx: &ReducerContext, clerk_user_id: String) -> Result
     let mute_ids: Vec<u64> = ctx.db.user_mute().iter()
         .filter(|m| m.muter_identity == identity || m.muted_identity == identity)
         .map(|m| m.id).collect();
-    for id in mute_ids { ctx.db.user_mute().id().delete(id); }
+    for &id in &mute_ids { ctx.db.user_mute().id().delete(id); }
+    let mute_id_set: std::collections::HashSet<u64> = mute_ids.iter().copied().collect();
+    let mute_expiry_ids: Vec<u64> = ctx.db.user_mute_expiry().iter()
+        .filter(|s| mute_id_set.contains(&s.mute_id))
+        .map(|s| s.scheduled_id).collect();
+    for id in mute_expiry_ids { ctx.db.user_mute_expiry().scheduled_id().delete(id); }
+
+    // Comment view
+    let view_entry_ids: Vec<u64> = ctx.db.comment_view_entry().viewer_identity()
+        .filter(&identity)
+        .map(|e| e.id).collect();
+    for id in view_entry_ids { ctx.db.comment_view_entry().id().delete(id); }
+    ctx.db.comment_view().viewer_identity().delete(identity.clone());
+
+    // Activity events, feed rows and feed cursor
+    crate::reducers::activity::remove_user_activity(ctx, &identity);
+
+    // Suggestions for and of this user
+    let suggestion_ids: Vec<u64> = ctx.db.suggestion().iter()
+        .filter(|s| s.identity == identity || s.suggested_identity == identity)
+        .map(|s| s.id).collect();
+    for id in suggestion_ids { ctx.db.suggestion().id().delete(id); }
+    let dismissed_ids: Vec<u64> = ctx.db.dismissed_suggestion().iter()
+        .filter(|d| d.identity == identity || d.suggested_identity == identity)
+        .map(|d| d.id).collect();
+    for id in dismissed_ids { ctx.db.dismissed_suggestion().id().delete(id); }
+    ctx.db.suggestion_manual_refresh().identity().delete(identity.clone());
 
     // Notifications (as recipient)
-    let notif_ids: Vec<u64> = ctx.db.notification().iter()
-        .filter(|n| n.recipient_identity == identity)
+    let notif_ids: Vec<u64> = ctx.db.notification().recipient_identity().filter(identity.as_str())
         .map(|n| n.id).collect();
-    for id in notif_ids { ctx.db.notification().id().delete(id); }
+    crate::reducers::notifications::delete_notifications(ctx, &notif_ids);
+
+    // Notification preferences
+    let pref_ids: Vec<u64> = ctx.db.notification_preference().identity().filter(&identity)
+        .map(|p| p.id).collect();
+    for id in pref_ids { ctx.db.notification_preference().id().delete(id); }
 
     // Topic moderator applications
     let mod_app_ids: Vec<u64> = ctx.db.topic_moderator_application().iter()
@@ -399,6 +514,20 @@ pub fn server_delete_user(ctx: &ReducerContext, clerk_user_id: String) -> Result
         .map(|b| b.id).collect();
     for id in ban_ids { ctx.db.topic_ban().id().delete(id); }
 
+    // Topic ban appeals
+    let appeal_ids: Vec<u64> = ctx.db.topic_ban_appeal().iter()
+        .filter(|a| a.appellant_identity == identity)
+        .map(|a| a.id).collect();
+    for id in appeal_ids { ctx.db.topic_ban_appeal().id().delete(id); }
+
+    // Group conversations — leave each one (hands off admin, deletes empty groups)
+    crate::reducers::messages::remove_user_from_groups(ctx, &identity);
+    ctx.db.conversation_read_state().identity().delete(identity.as_str());
+    let reaction_ids: Vec<u64> = ctx.db.message_reaction().iter()
+        .filter(|r| r.identity == identity)
+        .map(|r| r.id).collect();
+    for id in reaction_ids { ctx.db.message_reaction().id().delete(id); }
+
     // Direct messages — mark as deleted rather than removing (preserves other user's view)
     let msg_ids: Vec<u64> = ctx.db.direct_message().iter()
         .filter(|m| m.sender_identity == identity || m.recipient_identity == identity)
@@ -442,6 +571,8 @@ pub fn server_delete_user(ctx: &ReducerContext, clerk_user_id: String) -> Result
     Ok(())
 }
 
+/// Propose granting or revoking admin status. Takes effect only after a
+/// second admin confirms via `confirm_admin_action`.
 #[reducer]
 pub fn set_admin(
     ctx: &ReducerContext,
@@ -461,15 +592,52 @@ pub fn set_admin(
         .find(target_identity.clone())
         .ok_or("Target user not found")?;
 
+    if target.is_admin == is_admin {
+        return Err("User already has this admin status".to_string());
+    }
+
+    propose_admin_action(
+        ctx,
+        "set_admin",
+        serde_json::json!({ "target_identity": target_identity, "is_admin": is_admin }),
+    )?;
+
+    Ok(())
+}
+
+/// Apply an admin status change. Called by `confirm_admin_action` once two admins agree.
+pub fn execute_set_admin(
+    ctx: &ReducerContext,
+    target_identity: &str,
+    is_admin: bool,
+) -> Result<(), String> {
+    let target = ctx
+        .db
+        .user_profile()
+        .identity()
+        .find(target_identity.to_string())
+        .ok_or("Target user not found")?;
+
+    let was_admin = target.is_admin;
+
     ctx.db
         .user_profile()
         .identity()
-        .delete(target_identity);
+        .delete(target_identity.to_string());
     ctx.db.user_profile().try_insert(UserProfile {
         is_admin,
         ..target
     }).map_err(|e| format!("Insert failed: {e}"))?;
 
+    record_audit(
+        ctx,
+        "set_admin",
+        "user",
+        target_identity,
+        Some(serde_json::json!({ "is_admin": was_admin })),
+        Some(serde_json::json!({ "is_admin": is_admin })),
+    )?;
+
     Ok(())
 }
 
@@ -528,6 +696,7 @@ pub fn spend_credits(
     }
 
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let user = ctx
         .db
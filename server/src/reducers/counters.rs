@@ -0,0 +1,166 @@
+use spacetimedb::{reducer, ReducerContext, Table};
+use crate::tables::*;
+use crate::reducers::audit::record_audit;
+use std::collections::HashMap;
+
+fn caller_str(ctx: &ReducerContext) -> String {
+    ctx.sender().to_hex().to_string()
+}
+
+fn is_caller_admin(ctx: &ReducerContext) -> bool {
+    let identity = caller_str(ctx);
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(identity)
+        .map(|u| u.is_admin)
+        .unwrap_or(false)
+}
+
+fn apply(value: u64, delta: i64) -> u64 {
+    if delta >= 0 {
+        value.saturating_add(delta as u64)
+    } else {
+        value.saturating_sub(delta.unsigned_abs())
+    }
+}
+
+/// Blocks that count toward videos_posted / total_likes_received: live or
+/// moderator-hidden videos, not empty or ad slots.
+fn counts_as_video(block: &Block) -> bool {
+    block.status == "claimed" || block.status == "hidden"
+}
+
+fn update_profile(ctx: &ReducerContext, identity: &str, f: impl FnOnce(UserProfile) -> UserProfile) {
+    if let Some(profile) = ctx.db.user_profile().identity().find(identity.to_string()) {
+        ctx.db.user_profile().identity().update(f(profile));
+    }
+}
+
+/// A follow edge was added (+1) or removed (-1).
+pub fn adjust_follow_counts(ctx: &ReducerContext, follower: &str, following: &str, delta: i64) {
+    update_profile(ctx, follower, |p| UserProfile {
+        following_count: apply(p.following_count, delta),
+        ..p
+    });
+    update_profile(ctx, following, |p| UserProfile {
+        follower_count: apply(p.follower_count, delta),
+        ..p
+    });
+}
+
+pub fn adjust_topic_follow_count(ctx: &ReducerContext, identity: &str, delta: i64) {
+    update_profile(ctx, identity, |p| UserProfile {
+        topic_follow_count: apply(p.topic_follow_count, delta),
+        ..p
+    });
+}
+
+pub fn adjust_likes_received(ctx: &ReducerContext, owner: &str, delta: i64) {
+    update_profile(ctx, owner, |p| UserProfile {
+        total_likes_received: apply(p.total_likes_received, delta),
+        ..p
+    });
+}
+
+pub fn adjust_videos_posted(ctx: &ReducerContext, owner: &str, delta: i64) {
+    update_profile(ctx, owner, |p| UserProfile {
+        videos_posted: apply(p.videos_posted, delta),
+        ..p
+    });
+}
+
+/// Take a deleted block (and the likes on it) off its owner's counters.
+pub fn remove_block_from_counters(ctx: &ReducerContext, block: &Block) {
+    if !counts_as_video(block) {
+        return;
+    }
+    update_profile(ctx, &block.owner_identity, |p| UserProfile {
+        videos_posted: p.videos_posted.saturating_sub(1),
+        total_likes_received: p.total_likes_received.saturating_sub(block.likes),
+        ..p
+    });
+}
+
+// ─── recompute_profile_counters (admin) ──────────────────────────────────────
+
+#[derive(Default, Clone, Copy, PartialEq)]
+struct Counters {
+    follower_count: u64,
+    following_count: u64,
+    topic_follow_count: u64,
+    total_likes_received: u64,
+    videos_posted: u64,
+}
+
+impl Counters {
+    fn of(p: &UserProfile) -> Self {
+        Counters {
+            follower_count: p.follower_count,
+            following_count: p.following_count,
+            topic_follow_count: p.topic_follow_count,
+            total_likes_received: p.total_likes_received,
+            videos_posted: p.videos_posted,
+        }
+    }
+}
+
+/// Recompute counters from the source tables. `identity` = "" repairs every
+/// user; otherwise just that one. Only rows that drifted are rewritten.
+#[reducer]
+pub fn recompute_profile_counters(ctx: &ReducerContext, identity: String) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can recompute profile counters".to_string());
+    }
+    if !identity.is_empty() && ctx.db.user_profile().identity().find(identity.clone()).is_none() {
+        return Err("User not found".to_string());
+    }
+    let wanted = |id: &str| identity.is_empty() || id == identity;
+
+    let mut actual: HashMap<String, Counters> = HashMap::new();
+    for f in ctx.db.user_follow().iter() {
+        if wanted(&f.follower_identity) {
+            actual.entry(f.follower_identity.clone()).or_default().following_count += 1;
+        }
+        if wanted(&f.following_identity) {
+            actual.entry(f.following_identity).or_default().follower_count += 1;
+        }
+    }
+    for f in ctx.db.topic_follow().iter().filter(|f| wanted(&f.follower_identity)) {
+        actual.entry(f.follower_identity).or_default().topic_follow_count += 1;
+    }
+    for b in ctx.db.block().iter().filter(|b| counts_as_video(b) && wanted(&b.owner_identity)) {
+        let c = actual.entry(b.owner_identity).or_default();
+        c.videos_posted += 1;
+        c.total_likes_received += b.likes;
+    }
+
+    let profiles: Vec<UserProfile> = ctx.db.user_profile().iter().filter(|p| wanted(&p.identity)).collect();
+    let mut repaired = Vec::new();
+    for profile in profiles {
+        let after = actual.get(&profile.identity).copied().unwrap_or_default();
+        if Counters::of(&profile) == after {
+            continue;
+        }
+        repaired.push(profile.identity.clone());
+        ctx.db.user_profile().identity().update(UserProfile {
+            follower_count: after.follower_count,
+            following_count: after.following_count,
+            topic_follow_count: after.topic_follow_count,
+            total_likes_received: after.total_likes_received,
+            videos_posted: after.videos_posted,
+            ..profile
+        });
+    }
+
+    record_audit(
+        ctx,
+        "recompute_profile_counters",
+        "user_profile",
+        if identity.is_empty() { "*" } else { &identity },
+        None,
+        Some(serde_json::json!({ "repaired": repaired })),
+    )?;
+
+    Ok(())
+}
@@ -1,15 +1,23 @@
 use spacetimedb::{reducer, ReducerContext, Table};
 use crate::tables::*;
 use crate::reducers::topic::spiral_coords;
+use crate::reducers::audit::record_audit;
+use crate::reducers::admin_actions::propose_admin_action;
 
 fn now_micros(ctx: &ReducerContext) -> u64 {
     ctx.timestamp.to_micros_since_unix_epoch() as u64
 }
 
-/// Dev utility — wipes all user_profile and clerk_identity_map rows.
-/// Security relies on SPACETIMEDB_SERVER_TOKEN being kept secret.
+/// Dev utility — proposes wiping all user_profile and clerk_identity_map rows.
+/// Must be called by an admin and confirmed by a second admin via `confirm_admin_action`.
 #[reducer]
 pub fn dev_clear_all_users(ctx: &ReducerContext) -> Result<(), String> {
+    propose_admin_action(ctx, "clear_all_users", serde_json::json!({}))?;
+    Ok(())
+}
+
+/// Delete every user profile and Clerk mapping. Called by `confirm_admin_action`.
+pub fn execute_clear_all_users(ctx: &ReducerContext) -> Result<(), String> {
     let identities: Vec<String> = ctx
         .db
         .user_profile()
@@ -17,6 +25,7 @@ pub fn dev_clear_all_users(ctx: &ReducerContext) -> Result<(), String> {
         .map(|u| u.identity.clone())
         .collect();
 
+    let user_count = identities.len();
     for id in identities {
         ctx.db.user_profile().identity().delete(id);
     }
@@ -32,28 +41,16 @@ pub fn dev_clear_all_users(ctx: &ReducerContext) -> Result<(), String> {
         ctx.db.clerk_identity_map().clerk_user_id().delete(id);
     }
 
-    log::info!("[dev] All user profiles cleared");
-    Ok(())
-}
-
-/// Dev utility — sets a user as admin by identity without requiring the caller to be admin.
-/// Security relies on SPACETIMEDB_SERVER_TOKEN being kept secret.
-#[reducer]
-pub fn dev_set_admin(ctx: &ReducerContext, identity: String, is_admin: bool) -> Result<(), String> {
-    let user = ctx
-        .db
-        .user_profile()
-        .identity()
-        .find(identity.clone())
-        .ok_or_else(|| format!("User not found: {}", identity))?;
-
-    ctx.db.user_profile().identity().delete(identity.clone());
-    ctx.db.user_profile().try_insert(UserProfile {
-        is_admin,
-        ..user
-    }).map_err(|e| format!("Insert failed: {e}"))?;
+    record_audit(
+        ctx,
+        "dev_clear_all_users",
+        "user",
+        "*",
+        Some(serde_json::json!({ "user_count": user_count })),
+        Some(serde_json::json!({ "user_count": 0 })),
+    )?;
 
-    log::info!("[dev] Set is_admin={} for {}", is_admin, identity.chars().take(16).collect::<String>());
+    log::info!("[dev] All user profiles cleared");
     Ok(())
 }
 
@@ -99,6 +96,7 @@ pub fn dev_seed_topic(
         total_views: 0,
         is_active: true,
         created_at: now,
+        follower_count: 0,
     }).map_err(|e| format!("Topic insert failed: {e}"))?;
 
     // Sample YouTube video IDs for seeding
@@ -130,6 +128,7 @@ pub fn dev_seed_topic(
             ad_image_url: String::new(),
             ad_link_url: String::new(),
             claimed_at: now,
+            hidden: false,
         }).map_err(|e| format!("Block insert failed at {i}: {e}"))?;
     }
 
@@ -0,0 +1,511 @@
+use spacetimedb::{reducer, ReducerContext, Table};
+use regex::{Regex, RegexBuilder};
+use crate::tables::*;
+use crate::reducers::audit::record_audit;
+use crate::reducers::topic::can_moderate_topic;
+
+const MATCH_MODES: &[&str] = &["exact", "regex"];
+const GLOBAL_ACTIONS: &[&str] = &["reject", "hold", "mask"];
+const OVERRIDE_ACTIONS: &[&str] = &["reject", "hold", "mask", "allow"];
+const MAX_TERM_LEN: usize = 200;
+/// Compiled-size cap so a moderator-supplied pattern can't blow up a reducer.
+const REGEX_SIZE_LIMIT: usize = 1 << 16;
+
+fn now_micros(ctx: &ReducerContext) -> u64 {
+    ctx.timestamp.to_micros_since_unix_epoch() as u64
+}
+
+fn caller_str(ctx: &ReducerContext) -> String {
+    ctx.sender().to_hex().to_string()
+}
+
+fn is_caller_admin(ctx: &ReducerContext) -> bool {
+    let identity = caller_str(ctx);
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(identity)
+        .map(|u| u.is_admin)
+        .unwrap_or(false)
+}
+
+// ─── matcher ─────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TermRule {
+    pub term: String,
+    pub match_mode: String,
+    pub action: String,
+}
+
+/// Outcome for a piece of text. Masked terms are already replaced in the text.
+#[derive(Debug, PartialEq)]
+pub enum FilterVerdict {
+    Allow(String),
+    Hold(String),
+    Reject(String),
+}
+
+fn rule_key(rule: &TermRule) -> (String, String) {
+    (rule.term.to_lowercase(), rule.match_mode.clone())
+}
+
+/// Apply topic overrides on top of the global rules. An override replaces the
+/// global hold or mask rule with the same term and mode ("allow" drops it
+/// entirely); global rejects always stand.
+fn merge_overrides(global: Vec<TermRule>, overrides: Vec<TermRule>) -> Vec<TermRule> {
+    let mut rules: Vec<TermRule> = global
+        .into_iter()
+        .filter(|g| g.action == "reject" || !overrides.iter().any(|o| rule_key(o) == rule_key(g)))
+        .collect();
+    rules.extend(overrides.into_iter().filter(|o| o.action != "allow"));
+    rules
+}
+
+fn compile(rule: &TermRule) -> Result<Regex, regex::Error> {
+    let pattern = if rule.match_mode == "regex" {
+        rule.term.clone()
+    } else {
+        regex::escape(rule.term.trim())
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(true)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+}
+
+/// Byte ranges of every match. Exact terms only match as whole words: a match
+/// edge that is alphanumeric must not touch another alphanumeric character.
+fn find_matches(text: &str, rule: &TermRule, re: &Regex) -> Vec<(usize, usize)> {
+    re.find_iter(text)
+        .filter(|m| !m.as_str().is_empty())
+        .filter(|m| {
+            if rule.match_mode == "regex" {
+                return true;
+            }
+            let starts_word = m.as_str().chars().next().map(|c| c.is_alphanumeric()).unwrap_or(false);
+            let ends_word = m.as_str().chars().last().map(|c| c.is_alphanumeric()).unwrap_or(false);
+            let before = text[..m.start()].chars().last().map(|c| c.is_alphanumeric()).unwrap_or(false);
+            let after = text[m.end()..].chars().next().map(|c| c.is_alphanumeric()).unwrap_or(false);
+            let joined_before = starts_word && before;
+            let joined_after = ends_word && after;
+            !joined_before && !joined_after
+        })
+        .map(|m| (m.start(), m.end()))
+        .collect()
+}
+
+/// Run `text` through `rules`. Reject wins over hold; masking is applied to the
+/// returned text either way. Rules whose pattern doesn't compile are skipped.
+fn evaluate(text: &str, rules: &[TermRule]) -> FilterVerdict {
+    let mut masked: Vec<(usize, usize)> = Vec::new();
+    let mut hold = false;
+
+    for rule in rules {
+        let Ok(re) = compile(rule) else {
+            continue;
+        };
+        let matches = find_matches(text, rule, &re);
+        if matches.is_empty() {
+            continue;
+        }
+        match rule.action.as_str() {
+            "reject" => return FilterVerdict::Reject(rule.term.clone()),
+            "hold" => hold = true,
+            "mask" => masked.extend(matches),
+            _ => {}
+        }
+    }
+
+    let mut out = String::with_capacity(text.len());
+    for (i, c) in text.char_indices() {
+        if masked.iter().any(|&(start, end)| i >= start && i < end) {
+            out.push('*');
+        } else {
+            out.push(c);
+        }
+    }
+
+    if hold {
+        FilterVerdict::Hold(out)
+    } else {
+        FilterVerdict::Allow(out)
+    }
+}
+
+fn to_rule(term: BlockedTerm) -> TermRule {
+    TermRule {
+        term: term.term,
+        match_mode: term.match_mode,
+        action: term.action,
+    }
+}
+
+/// Filter user text against the global terms plus `topic_id`'s overrides
+/// (0 = global only). Returns the (masked) text and whether it must be hidden
+/// pending review. A rejected text becomes an error naming no term, so the
+/// list can't be probed one word at a time.
+pub fn filter_text(ctx: &ReducerContext, text: &str, topic_id: u64) -> Result<(String, bool), String> {
+    let global: Vec<TermRule> = ctx
+        .db
+        .blocked_term()
+        .iter()
+        .filter(|t| t.topic_id == 0)
+        .map(to_rule)
+        .collect();
+    let overrides: Vec<TermRule> = if topic_id == 0 {
+        Vec::new()
+    } else {
+        ctx.db
+            .blocked_term()
+            .iter()
+            .filter(|t| t.topic_id == topic_id)
+            .map(to_rule)
+            .collect()
+    };
+
+    match evaluate(text, &merge_overrides(global, overrides)) {
+        FilterVerdict::Reject(_) => Err("Your text contains a blocked word or link".to_string()),
+        FilterVerdict::Hold(text) => Ok((text, true)),
+        FilterVerdict::Allow(text) => Ok((text, false)),
+    }
+}
+
+/// For text that can't be hidden pending review (messages, topic titles,
+/// profiles): "hold" terms reject like "reject" terms. Returns the masked text.
+pub fn filter_text_or_reject(ctx: &ReducerContext, text: &str, topic_id: u64) -> Result<String, String> {
+    match filter_text(ctx, text, topic_id)? {
+        (text, false) => Ok(text),
+        (_, true) => Err("Your text contains a word or link that needs moderator review — please rephrase".to_string()),
+    }
+}
+
+fn validate_term(term: &str, match_mode: &str, action: &str, actions: &[&str]) -> Result<(), String> {
+    if term.is_empty() {
+        return Err("Term cannot be empty".to_string());
+    }
+    if term.len() > MAX_TERM_LEN {
+        return Err(format!("Term too long (max {MAX_TERM_LEN} chars)"));
+    }
+    if !MATCH_MODES.contains(&match_mode) {
+        return Err(format!("Invalid match mode. Must be one of: {}", MATCH_MODES.join(", ")));
+    }
+    if !actions.contains(&action) {
+        return Err(format!("Invalid action. Must be one of: {}", actions.join(", ")));
+    }
+    let rule = TermRule {
+        term: term.to_string(),
+        match_mode: match_mode.to_string(),
+        action: action.to_string(),
+    };
+    compile(&rule).map_err(|e| format!("Invalid pattern: {e}"))?;
+    Ok(())
+}
+
+// ─── add_blocked_term (admin) ────────────────────────────────────────────────
+
+#[reducer]
+pub fn add_blocked_term(
+    ctx: &ReducerContext,
+    term: String,
+    match_mode: String,
+    action: String,
+) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can manage blocked terms".to_string());
+    }
+
+    let term = term.trim().to_string();
+    validate_term(&term, &match_mode, &action, GLOBAL_ACTIONS)?;
+
+    let lower = term.to_lowercase();
+    let duplicate = ctx
+        .db
+        .blocked_term()
+        .iter()
+        .any(|t| t.topic_id == 0 && t.match_mode == match_mode && t.term.to_lowercase() == lower);
+    if duplicate {
+        return Err("Term is already blocked".to_string());
+    }
+
+    let row = ctx
+        .db
+        .blocked_term()
+        .try_insert(BlockedTerm {
+            id: 0,
+            term: term.clone(),
+            match_mode: match_mode.clone(),
+            action: action.clone(),
+            topic_id: 0,
+            created_by: caller_str(ctx),
+            created_at: now_micros(ctx),
+        })
+        .map_err(|e| format!("Insert failed: {e}"))?;
+
+    record_audit(
+        ctx,
+        "add_blocked_term",
+        "blocked_term",
+        &row.id.to_string(),
+        None,
+        Some(serde_json::json!({ "term": term, "match_mode": match_mode, "action": action })),
+    )?;
+
+    Ok(())
+}
+
+// ─── set_topic_term_override (topic moderators) ─────────────────────────────
+
+/// Add or change a topic-scoped term. Overrides a global term with the same
+/// text and mode inside the topic; "allow" switches it off there.
+#[reducer]
+pub fn set_topic_term_override(
+    ctx: &ReducerContext,
+    topic_id: u64,
+    term: String,
+    match_mode: String,
+    action: String,
+) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+
+    ctx.db.topic().id().find(topic_id).ok_or("Topic not found")?;
+    if !can_moderate_topic(ctx, &caller, topic_id) {
+        return Err("Not authorized — must be topic owner, moderator, or admin".to_string());
+    }
+
+    let term = term.trim().to_string();
+    validate_term(&term, &match_mode, &action, OVERRIDE_ACTIONS)?;
+
+    let lower = term.to_lowercase();
+    let existing = ctx
+        .db
+        .blocked_term()
+        .iter()
+        .find(|t| t.topic_id == topic_id && t.match_mode == match_mode && t.term.to_lowercase() == lower);
+
+    let (row, before) = match existing {
+        Some(existing) => {
+            let before = serde_json::json!({ "action": existing.action });
+            let updated = BlockedTerm {
+                action: action.clone(),
+                created_by: caller,
+                created_at: now_micros(ctx),
+                ..existing
+            };
+            ctx.db.blocked_term().id().update(updated.clone());
+            (updated, Some(before))
+        }
+        None => {
+            let row = ctx
+                .db
+                .blocked_term()
+                .try_insert(BlockedTerm {
+                    id: 0,
+                    term: term.clone(),
+                    match_mode: match_mode.clone(),
+                    action: action.clone(),
+                    topic_id,
+                    created_by: caller,
+                    created_at: now_micros(ctx),
+                })
+                .map_err(|e| format!("Insert failed: {e}"))?;
+            (row, None)
+        }
+    };
+
+    record_audit(
+        ctx,
+        "set_topic_term_override",
+        "blocked_term",
+        &row.id.to_string(),
+        before,
+        Some(serde_json::json!({
+            "topic_id": topic_id,
+            "term": term,
+            "match_mode": match_mode,
+            "action": action,
+        })),
+    )?;
+
+    Ok(())
+}
+
+// ─── remove_blocked_term ─────────────────────────────────────────────────────
+
+/// Admins remove global terms; topic moderators remove their topic's overrides.
+#[reducer]
+pub fn remove_blocked_term(ctx: &ReducerContext, term_id: u64) -> Result<(), String> {
+    let caller = caller_str(ctx);
+
+    let term = ctx
+        .db
+        .blocked_term()
+        .id()
+        .find(term_id)
+        .ok_or("Term not found")?;
+
+    let authorized = if term.topic_id == 0 {
+        is_caller_admin(ctx)
+    } else {
+        can_moderate_topic(ctx, &caller, term.topic_id)
+    };
+    if !authorized {
+        return Err("Not authorized to remove this term".to_string());
+    }
+
+    ctx.db.blocked_term().id().delete(term_id);
+
+    record_audit(
+        ctx,
+        "remove_blocked_term",
+        "blocked_term",
+        &term_id.to_string(),
+        Some(serde_json::json!({
+            "topic_id": term.topic_id,
+            "term": term.term,
+            "match_mode": term.match_mode,
+            "action": term.action,
+        })),
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(term: &str, match_mode: &str, action: &str) -> TermRule {
+        TermRule {
+            term: term.to_string(),
+            match_mode: match_mode.to_string(),
+            action: action.to_string(),
+        }
+    }
+
+    #[test]
+    fn exact_match_is_case_insensitive_and_whole_word() {
+        let rules = [rule("spam", "exact", "reject")];
+
+        assert_eq!(evaluate("Buy SPAM now", &rules), FilterVerdict::Reject("spam".to_string()));
+        assert_eq!(evaluate("spam!", &rules), FilterVerdict::Reject("spam".to_string()));
+        assert_eq!(
+            evaluate("spammer and antispam", &rules),
+            FilterVerdict::Allow("spammer and antispam".to_string())
+        );
+    }
+
+    #[test]
+    fn exact_match_handles_links_and_special_characters() {
+        let rules = [rule("bit.ly/", "exact", "reject")];
+
+        assert_eq!(
+            evaluate("see https://bit.ly/xyz", &rules),
+            FilterVerdict::Reject("bit.ly/".to_string())
+        );
+        assert_eq!(evaluate("bitsly/xyz", &rules), FilterVerdict::Allow("bitsly/xyz".to_string()));
+    }
+
+    #[test]
+    fn regex_mode_matches_patterns() {
+        let rules = [rule(r"free\s+v-?bucks", "regex", "hold")];
+
+        assert_eq!(
+            evaluate("get FREE   vbucks here", &rules),
+            FilterVerdict::Hold("get FREE   vbucks here".to_string())
+        );
+        assert_eq!(evaluate("free bucks", &rules), FilterVerdict::Allow("free bucks".to_string()));
+    }
+
+    #[test]
+    fn mask_replaces_each_matched_character() {
+        let rules = [rule("darn", "exact", "mask")];
+
+        assert_eq!(
+            evaluate("Darn it, darn", &rules),
+            FilterVerdict::Allow("**** it, ****".to_string())
+        );
+    }
+
+    #[test]
+    fn mask_counts_characters_not_bytes() {
+        let rules = [rule("café", "exact", "mask")];
+
+        assert_eq!(evaluate("le café ok", &rules), FilterVerdict::Allow("le **** ok".to_string()));
+    }
+
+    #[test]
+    fn reject_takes_precedence_over_hold_and_mask() {
+        let rules = [
+            rule("darn", "exact", "mask"),
+            rule("scam", "exact", "hold"),
+            rule("casino", "exact", "reject"),
+        ];
+
+        assert_eq!(
+            evaluate("darn scam casino", &rules),
+            FilterVerdict::Reject("casino".to_string())
+        );
+        assert_eq!(
+            evaluate("darn scam", &rules),
+            FilterVerdict::Hold("**** scam".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_regex_is_skipped() {
+        let rules = [rule("(unclosed", "regex", "reject")];
+
+        assert_eq!(evaluate("(unclosed", &rules), FilterVerdict::Allow("(unclosed".to_string()));
+    }
+
+    #[test]
+    fn topic_override_replaces_or_disables_global_rule() {
+        let global = vec![
+            rule("heck", "exact", "hold"),
+            rule("darn", "exact", "mask"),
+        ];
+        let overrides = vec![
+            rule("Heck", "exact", "allow"),
+            rule("darn", "exact", "hold"),
+            rule("spoiler", "exact", "mask"),
+        ];
+
+        let merged = merge_overrides(global, overrides);
+
+        assert_eq!(
+            merged,
+            vec![rule("darn", "exact", "hold"), rule("spoiler", "exact", "mask")]
+        );
+        assert_eq!(
+            evaluate("heck spoiler", &merged),
+            FilterVerdict::Allow("heck *******".to_string())
+        );
+    }
+
+    #[test]
+    fn topic_override_cannot_lift_global_reject() {
+        let global = vec![rule("crypto", "exact", "reject")];
+        let overrides = vec![rule("crypto", "exact", "allow")];
+
+        let merged = merge_overrides(global, overrides);
+
+        assert_eq!(merged, vec![rule("crypto", "exact", "reject")]);
+        assert_eq!(
+            evaluate("crypto", &merged),
+            FilterVerdict::Reject("crypto".to_string())
+        );
+    }
+
+    #[test]
+    fn override_only_matches_same_mode() {
+        let global = vec![rule("crypto", "exact", "reject")];
+        let overrides = vec![rule("crypto", "regex", "allow")];
+
+        let merged = merge_overrides(global, overrides);
+
+        assert_eq!(merged, vec![rule("crypto", "exact", "reject")]);
+    }
+}
@@ -1,5 +1,12 @@
-use spacetimedb::{reducer, ReducerContext, Table};
+use spacetimedb::{reducer, ReducerContext, ScheduleAt, Table};
 use crate::tables::*;
+use crate::reducers::activity::{record_activity, retract_activity};
+use crate::reducers::counters::{adjust_follow_counts, adjust_topic_follow_count};
+use crate::reducers::notifications::notify_activity;
+use crate::reducers::topic::adjust_topic_follower_count;
+
+/// Followers notified per fan_out_topic_video run.
+const TOPIC_FANOUT_BATCH: usize = 500;
 
 fn now_micros(ctx: &ReducerContext) -> u64 {
     ctx.timestamp.to_micros_since_unix_epoch() as u64
@@ -9,12 +16,11 @@ fn caller_str(ctx: &ReducerContext) -> String {
     ctx.sender().to_hex().to_string()
 }
 
-fn caller_name(ctx: &ReducerContext) -> String {
-    let identity = caller_str(ctx);
+fn display_name(ctx: &ReducerContext, identity: &str) -> String {
     ctx.db
         .user_profile()
         .identity()
-        .find(identity)
+        .find(identity.to_string())
         .map(|u| u.display_name)
         .unwrap_or_else(|| "Anonymous".to_string())
 }
@@ -27,6 +33,7 @@ pub fn follow_user(
     target_identity: String,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     if caller == target_identity {
         return Err("Cannot follow yourself".to_string());
@@ -37,7 +44,8 @@ pub fn follow_user(
     }
 
     // Verify target exists
-    ctx.db
+    let target = ctx
+        .db
         .user_profile()
         .identity()
         .find(target_identity.clone())
@@ -47,46 +55,51 @@ pub fn follow_user(
     let already = ctx
         .db
         .user_follow()
-        .iter()
-        .any(|f| f.follower_identity == caller && f.following_identity == target_identity);
+        .follower_identity()
+        .filter(&caller)
+        .any(|f| f.following_identity == target_identity);
     if already {
         return Err("Already following this user".to_string());
     }
 
+    if target.is_private {
+        return request_follow(ctx, &caller, &target_identity);
+    }
+
+    insert_follow(ctx, &caller, &target_identity)
+}
+
+/// Create the follow edge and its side effects: activity event, `new_follow`
+/// notification, and upgrading a pending DM request once the follow is mutual.
+fn insert_follow(ctx: &ReducerContext, follower: &str, target_identity: &str) -> Result<(), String> {
     ctx.db
         .user_follow()
         .try_insert(UserFollow {
             id: 0,
-            follower_identity: caller.clone(),
-            following_identity: target_identity.clone(),
+            follower_identity: follower.to_string(),
+            following_identity: target_identity.to_string(),
             created_at: now_micros(ctx),
         })
         .map_err(|e| format!("Insert failed: {e}"))?;
 
+    adjust_follow_counts(ctx, follower, target_identity, 1);
+    record_activity(ctx, follower, "user_followed", 0, 0, 0, target_identity)?;
+
     // Notify the target
-    let actor_name = caller_name(ctx);
-    let _ = ctx.db.notification().try_insert(Notification {
-        id: 0,
-        recipient_identity: target_identity.clone(),
-        actor_identity: caller.clone(),
-        actor_name,
-        notification_type: "new_follow".to_string(),
-        block_id: 0,
-        comment_id: 0,
-        is_read: false,
-        created_at: now_micros(ctx),
-    });
+    let actor_name = display_name(ctx, follower);
+    notify_activity(ctx, Notification::new(ctx, target_identity, follower, &actor_name, "new_follow"));
 
     // Check if this creates a mutual follow — if so, auto-upgrade any pending conversation
     let is_mutual = ctx
         .db
         .user_follow()
-        .iter()
-        .any(|f| f.follower_identity == target_identity && f.following_identity == caller);
+        .follower_identity()
+        .filter(target_identity)
+        .any(|f| f.following_identity == follower);
 
     if is_mutual {
         // Find any request_pending conversation between these two users
-        let (pa, pb) = canonical_pair(&caller, &target_identity);
+        let (pa, pb) = canonical_pair(follower, target_identity);
         if let Some(conv) = ctx
             .db
             .conversation()
@@ -104,6 +117,171 @@ pub fn follow_user(
     Ok(())
 }
 
+// ─── follow requests (private accounts) ──────────────────────────────────────
+
+fn pending_request(ctx: &ReducerContext, requester: &str, target_identity: &str) -> Option<FollowRequest> {
+    ctx.db
+        .follow_request()
+        .target_identity()
+        .filter(target_identity)
+        .find(|r| r.requester_identity == requester && r.status == "pending")
+}
+
+/// Ask a private account for permission to follow. A rejected request can be
+/// sent again; a pending one can't be duplicated.
+fn request_follow(ctx: &ReducerContext, requester: &str, target_identity: &str) -> Result<(), String> {
+    if pending_request(ctx, requester, target_identity).is_some() {
+        return Err("Follow request already sent".to_string());
+    }
+
+    ctx.db
+        .follow_request()
+        .try_insert(FollowRequest {
+            id: 0,
+            requester_identity: requester.to_string(),
+            target_identity: target_identity.to_string(),
+            status: "pending".to_string(),
+            created_at: now_micros(ctx),
+            responded_at: 0,
+        })
+        .map_err(|e| format!("Insert failed: {e}"))?;
+
+    let actor_name = display_name(ctx, requester);
+    notify_activity(ctx, Notification::new(ctx, target_identity, requester, &actor_name, "follow_request"));
+
+    Ok(())
+}
+
+/// Approve a pending request: the requester starts following, exactly as if
+/// the account were public.
+fn approve_request(ctx: &ReducerContext, request: FollowRequest) -> Result<(), String> {
+    let requester = request.requester_identity.clone();
+    let target_identity = request.target_identity.clone();
+    ctx.db.follow_request().id().update(FollowRequest {
+        status: "approved".to_string(),
+        responded_at: now_micros(ctx),
+        ..request
+    });
+
+    let actor_name = display_name(ctx, &target_identity);
+    notify_activity(
+        ctx,
+        Notification::new(ctx, &requester, &target_identity, &actor_name, "follow_request_approved"),
+    );
+
+    let already = ctx
+        .db
+        .user_follow()
+        .follower_identity()
+        .filter(&requester)
+        .any(|f| f.following_identity == target_identity);
+    if already {
+        return Ok(());
+    }
+    insert_follow(ctx, &requester, &target_identity)
+}
+
+fn find_own_request(ctx: &ReducerContext, request_id: u64) -> Result<FollowRequest, String> {
+    let request = ctx
+        .db
+        .follow_request()
+        .id()
+        .find(request_id)
+        .ok_or("Follow request not found")?;
+    if request.target_identity != caller_str(ctx) {
+        return Err("Not authorized".to_string());
+    }
+    if request.status != "pending" {
+        return Err("Follow request has already been answered".to_string());
+    }
+    Ok(request)
+}
+
+#[reducer]
+pub fn approve_follow_request(ctx: &ReducerContext, request_id: u64) -> Result<(), String> {
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+    let request = find_own_request(ctx, request_id)?;
+    if crate::reducers::moderation::is_blocked(ctx, &request.requester_identity, &request.target_identity) {
+        return Err("Cannot approve a blocked user".to_string());
+    }
+    approve_request(ctx, request)
+}
+
+#[reducer]
+pub fn reject_follow_request(ctx: &ReducerContext, request_id: u64) -> Result<(), String> {
+    let request = find_own_request(ctx, request_id)?;
+    ctx.db.follow_request().id().update(FollowRequest {
+        status: "rejected".to_string(),
+        responded_at: now_micros(ctx),
+        ..request
+    });
+    Ok(())
+}
+
+/// Withdraw the caller's own pending request.
+#[reducer]
+pub fn cancel_follow_request(ctx: &ReducerContext, target_identity: String) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    let request = pending_request(ctx, &caller, &target_identity).ok_or("No pending follow request")?;
+    ctx.db.follow_request().id().delete(request.id);
+    Ok(())
+}
+
+/// Turn follow approval on or off for the caller. Going public approves every
+/// pending request.
+#[reducer]
+pub fn set_account_private(ctx: &ReducerContext, is_private: bool) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+    let user = ctx
+        .db
+        .user_profile()
+        .identity()
+        .find(caller.clone())
+        .ok_or("User not found")?;
+
+    ctx.db.user_profile().identity().update(UserProfile { is_private, ..user });
+
+    if !is_private {
+        let pending: Vec<FollowRequest> = ctx
+            .db
+            .follow_request()
+            .target_identity()
+            .filter(caller.as_str())
+            .filter(|r| r.status == "pending")
+            .collect();
+        for request in pending {
+            approve_request(ctx, request)?;
+        }
+    }
+
+    Ok(())
+}
+
+// ─── remove_follower ─────────────────────────────────────────────────────────
+
+/// Make someone stop following the caller without blocking them. They are not
+/// notified and can follow (or, for private accounts, request) again.
+#[reducer]
+pub fn remove_follower(ctx: &ReducerContext, follower_identity: String) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+
+    let follow = ctx
+        .db
+        .user_follow()
+        .follower_identity()
+        .filter(&follower_identity)
+        .find(|f| f.following_identity == caller)
+        .ok_or("This user doesn't follow you")?;
+
+    ctx.db.user_follow().id().delete(follow.id);
+    adjust_follow_counts(ctx, &follower_identity, &caller, -1);
+    retract_activity(ctx, &follower_identity, "user_followed", 0, &caller);
+
+    Ok(())
+}
+
 // ─── unfollow_user ───────────────────────────────────────────────────────────
 
 #[reducer]
@@ -116,11 +294,14 @@ pub fn unfollow_user(
     let follow = ctx
         .db
         .user_follow()
-        .iter()
-        .find(|f| f.follower_identity == caller && f.following_identity == target_identity)
+        .follower_identity()
+        .filter(&caller)
+        .find(|f| f.following_identity == target_identity)
         .ok_or("Not following this user")?;
 
     ctx.db.user_follow().id().delete(follow.id);
+    adjust_follow_counts(ctx, &caller, &target_identity, -1);
+    retract_activity(ctx, &caller, "user_followed", 0, &target_identity);
 
     Ok(())
 }
@@ -143,6 +324,7 @@ pub fn follow_topic(
     topic_id: u64,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     // Verify topic exists
     ctx.db
@@ -155,8 +337,9 @@ pub fn follow_topic(
     let already = ctx
         .db
         .topic_follow()
-        .iter()
-        .any(|f| f.follower_identity == caller && f.topic_id == topic_id);
+        .follower_identity()
+        .filter(&caller)
+        .any(|f| f.topic_id == topic_id);
     if already {
         return Err("Already following this topic".to_string());
     }
@@ -165,12 +348,15 @@ pub fn follow_topic(
         .topic_follow()
         .try_insert(TopicFollow {
             id: 0,
-            follower_identity: caller,
+            follower_identity: caller.clone(),
             topic_id,
             created_at: now_micros(ctx),
         })
         .map_err(|e| format!("Insert failed: {e}"))?;
 
+    adjust_topic_follow_count(ctx, &caller, 1);
+    adjust_topic_follower_count(ctx, topic_id, 1)?;
+
     Ok(())
 }
 
@@ -186,11 +372,212 @@ pub fn unfollow_topic(
     let follow = ctx
         .db
         .topic_follow()
-        .iter()
-        .find(|f| f.follower_identity == caller && f.topic_id == topic_id)
+        .follower_identity()
+        .filter(&caller)
+        .find(|f| f.topic_id == topic_id)
         .ok_or("Not following this topic")?;
 
     ctx.db.topic_follow().id().delete(follow.id);
+    adjust_topic_follow_count(ctx, &caller, -1);
+    adjust_topic_follower_count(ctx, topic_id, -1)?;
+
+    Ok(())
+}
+
+// ─── Topic video fan-out ─────────────────────────────────────────────────────
+
+/// The next TOPIC_FANOUT_BATCH followers of a topic after `after_follow_id`,
+/// and whether more remain.
+fn next_follower_batch(ctx: &ReducerContext, topic_id: u64, after_follow_id: u64) -> (Vec<TopicFollow>, bool) {
+    let mut followers: Vec<TopicFollow> = ctx
+        .db
+        .topic_follow()
+        .by_topic_and_id()
+        .filter((topic_id, after_follow_id + 1..))
+        .take(TOPIC_FANOUT_BATCH + 1)
+        .collect();
+    let has_more = followers.len() > TOPIC_FANOUT_BATCH;
+    followers.truncate(TOPIC_FANOUT_BATCH);
+    (followers, has_more)
+}
+
+/// Queue new_video_in_topic notifications for a freshly claimed block. The fan-out
+/// runs in scheduled batches so large topics don't bloat the claim transaction.
+pub fn queue_topic_video_fanout(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
+    ctx.db
+        .topic_video_fanout()
+        .try_insert(TopicVideoFanout {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(ctx.timestamp),
+            block_id,
+            after_follow_id: 0,
+        })
+        .map_err(|e| format!("Schedule insert failed: {e}"))?;
+    Ok(())
+}
+
+#[reducer]
+pub fn fan_out_topic_video(ctx: &ReducerContext, job: TopicVideoFanout) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("fan_out_topic_video may only be invoked by the scheduler".to_string());
+    }
+
+    // Stop if the video was removed or hidden before the fan-out reached everyone.
+    let Some(block) = ctx.db.block().id().find(job.block_id) else {
+        return Ok(());
+    };
+    if block.status != "claimed" || block.hidden {
+        return Ok(());
+    }
+
+    let (followers, has_more) = next_follower_batch(ctx, block.topic_id, job.after_follow_id);
+
+    for follow in &followers {
+        notify_activity(
+            ctx,
+            Notification {
+                block_id: block.id,
+                topic_id: block.topic_id,
+                ..Notification::new(
+                    ctx,
+                    &follow.follower_identity,
+                    &block.owner_identity,
+                    &block.owner_name,
+                    "new_video_in_topic",
+                )
+            },
+        );
+    }
+
+    if has_more {
+        if let Some(last) = followers.last() {
+            ctx.db
+                .topic_video_fanout()
+                .try_insert(TopicVideoFanout {
+                    scheduled_id: 0,
+                    scheduled_at: ScheduleAt::Time(ctx.timestamp),
+                    block_id: block.id,
+                    after_follow_id: last.id,
+                })
+                .map_err(|e| format!("Schedule insert failed: {e}"))?;
+        }
+    }
+
+    Ok(())
+}
+
+// ─── Topic announcements ─────────────────────────────────────────────────────
+
+/// Minimum gap between announcements in one topic.
+const ANNOUNCEMENT_COOLDOWN_MICROS: u64 = 60 * 60 * 1_000_000;
+
+/// Post an announcement to everyone following a topic. Topic owner only.
+/// Delivery goes through the same batched fan-out as new-video notifications.
+#[reducer]
+pub fn announce_to_topic_followers(ctx: &ReducerContext, topic_id: u64, text: String) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+
+    let topic = ctx.db.topic().id().find(topic_id).ok_or("Topic not found")?;
+    if topic.creator_identity != caller {
+        return Err("Only the topic owner can post announcements".to_string());
+    }
+    if !topic.is_active {
+        return Err("Topic is not active".to_string());
+    }
+
+    let trimmed = text.trim().to_string();
+    if trimmed.is_empty() {
+        return Err("Announcement cannot be empty".to_string());
+    }
+    if trimmed.len() > 500 {
+        return Err("Announcement too long (max 500 chars)".to_string());
+    }
+    let trimmed = crate::reducers::filter::filter_text_or_reject(ctx, &trimmed, topic_id)?;
+
+    let now = now_micros(ctx);
+    let recent = ctx
+        .db
+        .topic_announcement()
+        .topic_id()
+        .filter(topic_id)
+        .any(|a| now.saturating_sub(a.created_at) < ANNOUNCEMENT_COOLDOWN_MICROS);
+    if recent {
+        return Err("Only one announcement per hour is allowed".to_string());
+    }
+
+    let announcement = ctx
+        .db
+        .topic_announcement()
+        .try_insert(TopicAnnouncement {
+            id: 0,
+            topic_id,
+            author_identity: caller,
+            text: trimmed,
+            created_at: now,
+        })
+        .map_err(|e| format!("Insert failed: {e}"))?;
+
+    // Shadow-banned owners see their announcement but nobody is notified.
+    if crate::reducers::sanctions::is_shadow_banned(ctx, &announcement.author_identity) {
+        return Ok(());
+    }
+
+    ctx.db
+        .topic_announcement_fanout()
+        .try_insert(TopicAnnouncementFanout {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(ctx.timestamp),
+            announcement_id: announcement.id,
+            after_follow_id: 0,
+        })
+        .map_err(|e| format!("Schedule insert failed: {e}"))?;
+
+    Ok(())
+}
+
+#[reducer]
+pub fn fan_out_topic_announcement(ctx: &ReducerContext, job: TopicAnnouncementFanout) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("fan_out_topic_announcement may only be invoked by the scheduler".to_string());
+    }
+
+    let Some(announcement) = ctx.db.topic_announcement().id().find(job.announcement_id) else {
+        return Ok(());
+    };
+
+    let (followers, has_more) = next_follower_batch(ctx, announcement.topic_id, job.after_follow_id);
+    let actor_name = display_name(ctx, &announcement.author_identity);
+    for follow in &followers {
+        notify_activity(
+            ctx,
+            Notification {
+                topic_id: announcement.topic_id,
+                announcement_id: announcement.id,
+                ..Notification::new(
+                    ctx,
+                    &follow.follower_identity,
+                    &announcement.author_identity,
+                    &actor_name,
+                    "topic_announcement",
+                )
+            },
+        );
+    }
+
+    if has_more {
+        if let Some(last) = followers.last() {
+            ctx.db
+                .topic_announcement_fanout()
+                .try_insert(TopicAnnouncementFanout {
+                    scheduled_id: 0,
+                    scheduled_at: ScheduleAt::Time(ctx.timestamp),
+                    announcement_id: announcement.id,
+                    after_follow_id: last.id,
+                })
+                .map_err(|e| format!("Schedule insert failed: {e}"))?;
+        }
+    }
 
     Ok(())
 }
@@ -199,12 +586,14 @@ pub fn are_mutual_followers(ctx: &ReducerContext, a: &str, b: &str) -> bool {
     let a_follows_b = ctx
         .db
         .user_follow()
-        .iter()
-        .any(|f| f.follower_identity == a && f.following_identity == b);
+        .follower_identity()
+        .filter(a)
+        .any(|f| f.following_identity == b);
     let b_follows_a = ctx
         .db
         .user_follow()
-        .iter()
-        .any(|f| f.follower_identity == b && f.following_identity == a);
+        .follower_identity()
+        .filter(b)
+        .any(|f| f.following_identity == a);
     a_follows_b && b_follows_a
 }
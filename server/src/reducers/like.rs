@@ -1,5 +1,8 @@
 use spacetimedb::{reducer, ReducerContext, Table};
 use crate::tables::*;
+use crate::reducers::activity::{record_activity, retract_activity};
+use crate::reducers::counters::adjust_likes_received;
+use crate::reducers::moderation::{ensure_not_topic_banned, is_blocked};
 
 fn now_micros(ctx: &ReducerContext) -> u64 {
     ctx.timestamp.to_micros_since_unix_epoch() as u64
@@ -21,19 +24,9 @@ fn insert_video_like_notification(
     actor_identity: String,
     block_id: u64,
 ) {
-    if recipient_identity == actor_identity {
-        return;
-    }
-    let _ = ctx.db.notification().try_insert(Notification {
-        id: 0,
-        recipient_identity,
-        actor_identity: actor_identity.clone(),
-        actor_name: caller_name(ctx),
-        notification_type: "video_like".to_string(),
+    crate::reducers::notifications::notify_activity(ctx, Notification {
         block_id,
-        comment_id: 0,
-        is_read: false,
-        created_at: now_micros(ctx),
+        ..Notification::new(ctx, &recipient_identity, &actor_identity, &caller_name(ctx), "video_like")
     });
 }
 
@@ -64,6 +57,7 @@ fn update_topic_dislikes(ctx: &ReducerContext, topic_id: u64, delta: i64) {
 #[reducer]
 pub fn like_video(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let block = ctx
         .db
@@ -79,6 +73,10 @@ pub fn like_video(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
     if block.owner_identity == caller {
         return Err("Cannot like your own video".to_string());
     }
+    ensure_not_topic_banned(ctx, block.topic_id, &caller)?;
+    if is_blocked(ctx, &caller, &block.owner_identity) {
+        return Err("Cannot interact with a blocked user's video".to_string());
+    }
 
     for like in ctx.db.like_record().iter() {
         if like.block_id == block_id && like.user_identity == caller {
@@ -115,10 +113,15 @@ pub fn like_video(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
     }).map_err(|e| format!("Insert failed: {e}"))?;
 
     update_topic_likes(ctx, block.topic_id, 1);
+    adjust_likes_received(ctx, &block.owner_identity, 1);
     if dislikes_delta > 0 {
         update_topic_dislikes(ctx, block.topic_id, -1);
     }
 
+    if !block.hidden {
+        record_activity(ctx, &caller, "video_liked", block_id, 0, block.topic_id, &block.owner_identity)?;
+    }
+
     insert_video_like_notification(ctx, block.owner_identity, caller, block_id);
 
     Ok(())
@@ -154,6 +157,8 @@ pub fn unlike_video(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
     }).map_err(|e| format!("Insert failed: {e}"))?;
 
     update_topic_likes(ctx, block.topic_id, -1);
+    adjust_likes_received(ctx, &block.owner_identity, -(block.likes.min(1) as i64));
+    retract_activity(ctx, &caller, "video_liked", block_id, &block.owner_identity);
 
     Ok(())
 }
@@ -161,6 +166,7 @@ pub fn unlike_video(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
 #[reducer]
 pub fn dislike_video(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let block = ctx
         .db
@@ -176,6 +182,10 @@ pub fn dislike_video(ctx: &ReducerContext, block_id: u64) -> Result<(), String>
     if block.owner_identity == caller {
         return Err("Cannot dislike your own video".to_string());
     }
+    ensure_not_topic_banned(ctx, block.topic_id, &caller)?;
+    if is_blocked(ctx, &caller, &block.owner_identity) {
+        return Err("Cannot interact with a blocked user's video".to_string());
+    }
 
     for dislike in ctx.db.dislike_record().iter() {
         if dislike.block_id == block_id && dislike.user_identity == caller {
@@ -200,7 +210,7 @@ pub fn dislike_video(ctx: &ReducerContext, block_id: u64) -> Result<(), String>
     ctx.db.dislike_record().try_insert(DislikeRecord {
         id: 0,
         block_id,
-        user_identity: caller,
+        user_identity: caller.clone(),
         created_at: now_micros(ctx),
     }).map_err(|e| format!("Insert failed: {e}"))?;
 
@@ -214,6 +224,10 @@ pub fn dislike_video(ctx: &ReducerContext, block_id: u64) -> Result<(), String>
     update_topic_dislikes(ctx, block.topic_id, 1);
     if likes_delta > 0 {
         update_topic_likes(ctx, block.topic_id, -1);
+        adjust_likes_received(ctx, &block.owner_identity, -1);
+    }
+    if like_to_remove.is_some() {
+        retract_activity(ctx, &caller, "video_liked", block_id, &block.owner_identity);
     }
 
     Ok(())
@@ -256,6 +270,7 @@ pub fn undislike_video(ctx: &ReducerContext, block_id: u64) -> Result<(), String
 #[reducer]
 pub fn save_block(ctx: &ReducerContext, block_id: u64) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let block = ctx.db.block().id().find(block_id).ok_or("Block not found")?;
     if block.status != "claimed" {
@@ -26,7 +26,180 @@ fn find_conversation(ctx: &ReducerContext, a: &str, b: &str) -> Option<Conversat
     ctx.db
         .conversation()
         .iter()
-        .find(|c| c.participant_a == pa && c.participant_b == pb)
+        .find(|c| !c.is_group && c.participant_a == pa && c.participant_b == pb)
+}
+
+/// Trim, length-check and word-filter a message body.
+fn clean_message_text(ctx: &ReducerContext, text: &str) -> Result<String, String> {
+    let trimmed = text.trim().to_string();
+    if trimmed.is_empty() {
+        return Err("Message cannot be empty".to_string());
+    }
+    if trimmed.len() > 1000 {
+        return Err("Message too long (max 1000 chars)".to_string());
+    }
+    crate::reducers::filter::filter_text_or_reject(ctx, &trimmed, 0)
+}
+
+/// What a message shares. Stored as ids so clients always render the live
+/// block, comment or topic rather than a snapshot.
+#[derive(Clone, Copy, Default)]
+struct Attachment {
+    block_id: u64,
+    topic_id: u64,
+    comment_id: u64,
+}
+
+impl Attachment {
+    fn is_empty(&self) -> bool {
+        self.block_id == 0 && self.topic_id == 0 && self.comment_id == 0
+    }
+}
+
+/// Message text is optional when something is attached.
+fn clean_message_body(ctx: &ReducerContext, text: &str, attachment: &Attachment) -> Result<String, String> {
+    if text.trim().is_empty() && !attachment.is_empty() {
+        return Ok(String::new());
+    }
+    clean_message_text(ctx, text)
+}
+
+fn block_visible_to(ctx: &ReducerContext, block: &Block, viewer: &str) -> bool {
+    block.status == "claimed"
+        && (!block.hidden || block.owner_identity == viewer)
+        && !crate::reducers::moderation::is_blocked(ctx, viewer, &block.owner_identity)
+}
+
+fn comment_visible_to(ctx: &ReducerContext, comment: &Comment, viewer: &str) -> bool {
+    (!comment.hidden || comment.user_identity == viewer)
+        && !crate::reducers::moderation::is_blocked(ctx, viewer, &comment.user_identity)
+}
+
+/// Check that the attached objects exist and every participant (`viewers`,
+/// sender included) can see them. Fills in the topic of a shared block.
+fn resolve_attachment(ctx: &ReducerContext, attachment: Attachment, viewers: &[String]) -> Result<Attachment, String> {
+    if attachment.is_empty() {
+        return Ok(attachment);
+    }
+    if attachment.comment_id != 0 && attachment.block_id == 0 {
+        return Err("A shared comment needs its block_id".to_string());
+    }
+
+    let topic_id = if attachment.block_id != 0 {
+        let block = ctx
+            .db
+            .block()
+            .id()
+            .find(attachment.block_id)
+            .ok_or("Shared video not found")?;
+        if attachment.topic_id != 0 && attachment.topic_id != block.topic_id {
+            return Err("Shared video is not in that topic".to_string());
+        }
+        if viewers.iter().any(|v| !block_visible_to(ctx, &block, v)) {
+            return Err("That video isn't available to everyone in this conversation".to_string());
+        }
+        if attachment.comment_id != 0 {
+            let comment = ctx
+                .db
+                .comment()
+                .id()
+                .find(attachment.comment_id)
+                .ok_or("Shared comment not found")?;
+            if comment.block_id != block.id {
+                return Err("Shared comment is not on that video".to_string());
+            }
+            if viewers.iter().any(|v| !comment_visible_to(ctx, &comment, v)) {
+                return Err("That comment isn't available to everyone in this conversation".to_string());
+            }
+        }
+        block.topic_id
+    } else {
+        attachment.topic_id
+    };
+
+    let topic = ctx.db.topic().id().find(topic_id).ok_or("Shared topic not found")?;
+    if !topic.is_active {
+        return Err("Shared topic is not active".to_string());
+    }
+
+    Ok(Attachment { topic_id, ..attachment })
+}
+
+fn is_participant(ctx: &ReducerContext, conv: &Conversation, identity: &str) -> bool {
+    if conv.is_group {
+        find_participant(ctx, conv.id, identity).is_some()
+    } else {
+        conv.participant_a == identity || conv.participant_b == identity
+    }
+}
+
+fn latest_message_id(ctx: &ReducerContext, conversation_id: u64) -> u64 {
+    ctx.db
+        .direct_message()
+        .conversation_id()
+        .filter(conversation_id)
+        .map(|m| m.id)
+        .max()
+        .unwrap_or(0)
+}
+
+fn find_read_state(ctx: &ReducerContext, conversation_id: u64, identity: &str) -> Option<ConversationReadState> {
+    ctx.db
+        .conversation_read_state()
+        .conversation_id()
+        .filter(conversation_id)
+        .find(|r| r.identity == identity)
+}
+
+/// Insert or update a read state row (`id == 0` means it doesn't exist yet).
+fn save_read_state(ctx: &ReducerContext, state: ConversationReadState) -> Result<(), String> {
+    if state.id == 0 {
+        ctx.db
+            .conversation_read_state()
+            .try_insert(state)
+            .map_err(|e| format!("Insert failed: {e}"))?;
+    } else {
+        ctx.db.conversation_read_state().id().update(state);
+    }
+    Ok(())
+}
+
+fn new_read_state(conversation_id: u64, identity: &str) -> ConversationReadState {
+    ConversationReadState {
+        id: 0,
+        conversation_id,
+        identity: identity.to_string(),
+        last_read_message_id: 0,
+        last_read_at: 0,
+        hidden_through_message_id: 0,
+    }
+}
+
+/// Move `identity`'s read cursor forward to `message_id`. Never moves it back.
+fn advance_read_cursor(ctx: &ReducerContext, conversation_id: u64, identity: &str, message_id: u64) -> Result<(), String> {
+    let state = find_read_state(ctx, conversation_id, identity).unwrap_or_else(|| new_read_state(conversation_id, identity));
+    if state.id != 0 && state.last_read_message_id >= message_id {
+        return Ok(());
+    }
+    save_read_state(ctx, ConversationReadState {
+        last_read_message_id: message_id,
+        last_read_at: now_micros(ctx),
+        ..state
+    })
+}
+
+fn clear_read_cursor(ctx: &ReducerContext, conversation_id: u64, identity: &str) {
+    let ids: Vec<u64> = ctx
+        .db
+        .conversation_read_state()
+        .conversation_id()
+        .filter(conversation_id)
+        .filter(|r| r.identity == identity)
+        .map(|r| r.id)
+        .collect();
+    for id in ids {
+        ctx.db.conversation_read_state().id().delete(id);
+    }
 }
 
 // ─── send_message ─────────────────────────────────────────────────────────────
@@ -36,8 +209,32 @@ pub fn send_message(
     ctx: &ReducerContext,
     recipient_identity: String,
     text: String,
+) -> Result<(), String> {
+    send_direct(ctx, recipient_identity, text, Attachment::default())
+}
+
+/// Share a video (`block_id`, optionally one of its comments via `comment_id`)
+/// or a topic (`topic_id`) in a 1:1 conversation. `text` may be empty.
+#[reducer]
+pub fn send_message_with_attachment(
+    ctx: &ReducerContext,
+    recipient_identity: String,
+    text: String,
+    block_id: u64,
+    topic_id: u64,
+    comment_id: u64,
+) -> Result<(), String> {
+    send_direct(ctx, recipient_identity, text, Attachment { block_id, topic_id, comment_id })
+}
+
+fn send_direct(
+    ctx: &ReducerContext,
+    recipient_identity: String,
+    text: String,
+    attachment: Attachment,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     if caller == recipient_identity {
         return Err("Cannot send a message to yourself".to_string());
@@ -47,13 +244,7 @@ pub fn send_message(
         return Err("Cannot message a blocked user".to_string());
     }
 
-    let trimmed = text.trim().to_string();
-    if trimmed.is_empty() {
-        return Err("Message cannot be empty".to_string());
-    }
-    if trimmed.len() > 1000 {
-        return Err("Message too long (max 1000 chars)".to_string());
-    }
+    let trimmed = clean_message_body(ctx, &text, &attachment)?;
 
     // Verify recipient exists
     ctx.db
@@ -62,6 +253,8 @@ pub fn send_message(
         .find(recipient_identity.clone())
         .ok_or("Recipient not found")?;
 
+    let attachment = resolve_attachment(ctx, attachment, &[caller.clone(), recipient_identity.clone()])?;
+
     let now = now_micros(ctx);
     let mutual = are_mutual_followers(ctx, &caller, &recipient_identity);
 
@@ -121,13 +314,16 @@ pub fn send_message(
                     request_recipient,
                     created_at: now,
                     updated_at: now,
+                    is_group: false,
+                    title: None,
                 })
                 .map_err(|e| format!("Conversation insert failed: {e}"))?
         }
     };
 
     // Insert the message
-    ctx.db
+    let msg = ctx
+        .db
         .direct_message()
         .try_insert(DirectMessage {
             id: 0,
@@ -138,26 +334,24 @@ pub fn send_message(
             is_read: false,
             is_deleted: false,
             created_at: now,
+            edited_at: 0,
+            attachment_block_id: attachment.block_id,
+            attachment_topic_id: attachment.topic_id,
+            attachment_comment_id: attachment.comment_id,
         })
         .map_err(|e| format!("Message insert failed: {e}"))?;
+    advance_read_cursor(ctx, conv.id, &caller, msg.id)?;
 
-    // Notify the recipient
+    // Notify the recipient (the message itself is still delivered if they muted the sender)
     let actor_name = caller_name(ctx);
     let notif_type = if conv.status == "request_pending" {
         "message_request"
     } else {
         "new_message"
     };
-    let _ = ctx.db.notification().try_insert(Notification {
-        id: 0,
-        recipient_identity,
-        actor_identity: caller,
-        actor_name,
-        notification_type: notif_type.to_string(),
-        block_id: 0,
-        comment_id: 0,
-        is_read: false,
-        created_at: now,
+    crate::reducers::notifications::notify_activity(ctx, Notification {
+        conversation_id: conv.id,
+        ..Notification::new(ctx, &recipient_identity, &caller, &actor_name, notif_type)
     });
 
     Ok(())
@@ -171,6 +365,7 @@ pub fn accept_message_request(
     conversation_id: u64,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let conv = ctx
         .db
@@ -230,6 +425,46 @@ pub fn decline_message_request(
 
 // ─── delete_conversation ─────────────────────────────────────────────────────
 
+fn participant_identities(ctx: &ReducerContext, conv: &Conversation) -> Vec<String> {
+    if conv.is_group {
+        group_members(ctx, conv.id).into_iter().map(|p| p.identity).collect()
+    } else {
+        vec![conv.participant_a.clone(), conv.participant_b.clone()]
+    }
+}
+
+/// Hard-delete the messages every participant has cleared with delete_conversation.
+fn purge_cleared_messages(ctx: &ReducerContext, conv: &Conversation) {
+    let cleared_by_all = participant_identities(ctx, conv)
+        .iter()
+        .map(|identity| {
+            find_read_state(ctx, conv.id, identity)
+                .map(|r| r.hidden_through_message_id)
+                .unwrap_or(0)
+        })
+        .min()
+        .unwrap_or(0);
+    if cleared_by_all == 0 {
+        return;
+    }
+
+    let ids: Vec<u64> = ctx
+        .db
+        .direct_message()
+        .conversation_id()
+        .filter(conv.id)
+        .filter(|m| m.id <= cleared_by_all)
+        .map(|m| m.id)
+        .collect();
+    for id in ids {
+        ctx.db.message_reaction().message_id().delete(id);
+        ctx.db.direct_message().id().delete(id);
+    }
+}
+
+/// Clear a conversation from the caller's view only. Messages up to now are
+/// hidden for the caller; others keep them until they clear them too, at which
+/// point they are deleted for good. New messages show up again as usual.
 #[reducer]
 pub fn delete_conversation(
     ctx: &ReducerContext,
@@ -244,80 +479,701 @@ pub fn delete_conversation(
         .find(conversation_id)
         .ok_or("Conversation not found")?;
 
-    // Verify caller is a participant
-    if conv.participant_a != caller && conv.participant_b != caller {
+    if !is_participant(ctx, &conv, &caller) {
         return Err("Not authorized".to_string());
     }
 
-    // Soft-delete all messages in this conversation
-    let msgs: Vec<DirectMessage> = ctx
+    let latest = latest_message_id(ctx, conversation_id);
+    let state = find_read_state(ctx, conversation_id, &caller).unwrap_or_else(|| new_read_state(conversation_id, &caller));
+    save_read_state(ctx, ConversationReadState {
+        last_read_message_id: state.last_read_message_id.max(latest),
+        last_read_at: now_micros(ctx),
+        hidden_through_message_id: latest,
+        ..state
+    })?;
+
+    purge_cleared_messages(ctx, &conv);
+
+    Ok(())
+}
+
+// ─── mark_message_read ────────────────────────────────────────────────────────
+
+/// Mark everything up to and including `message_id` as read for the caller.
+#[reducer]
+pub fn mark_message_read(ctx: &ReducerContext, message_id: u64) -> Result<(), String> {
+    let caller = caller_str(ctx);
+
+    let msg = ctx
         .db
         .direct_message()
-        .iter()
-        .filter(|m| m.conversation_id == conversation_id && !m.is_deleted)
-        .collect();
+        .id()
+        .find(message_id)
+        .ok_or("Message not found")?;
 
-    for msg in msgs {
+    if msg.conversation_id == 0 {
+        if msg.recipient_identity != caller {
+            return Err("Not authorized".to_string());
+        }
         ctx.db.direct_message().id().update(DirectMessage {
-            is_deleted: true,
+            is_read: true,
             ..msg
         });
+        return Ok(());
     }
 
-    Ok(())
+    let conv = ctx
+        .db
+        .conversation()
+        .id()
+        .find(msg.conversation_id)
+        .ok_or("Conversation not found")?;
+
+    if !is_participant(ctx, &conv, &caller) {
+        return Err("Not authorized".to_string());
+    }
+
+    advance_read_cursor(ctx, conv.id, &caller, message_id)
 }
 
-// ─── mark_message_read ────────────────────────────────────────────────────────
+// ─── mark_conversation_read ───────────────────────────────────────────────────
 
 #[reducer]
-pub fn mark_message_read(ctx: &ReducerContext, message_id: u64) -> Result<(), String> {
+pub fn mark_conversation_read(ctx: &ReducerContext, conversation_id: u64) -> Result<(), String> {
     let caller = caller_str(ctx);
 
+    let conv = ctx
+        .db
+        .conversation()
+        .id()
+        .find(conversation_id)
+        .ok_or("Conversation not found")?;
+
+    if !is_participant(ctx, &conv, &caller) {
+        return Err("Not authorized".to_string());
+    }
+
+    advance_read_cursor(ctx, conversation_id, &caller, latest_message_id(ctx, conversation_id))
+}
+
+// ─── mark_all_messages_read ───────────────────────────────────────────────────
+
+/// 1:1 variant of mark_conversation_read, addressed by the other participant.
+#[reducer]
+pub fn mark_all_messages_read(
+    ctx: &ReducerContext,
+    other_identity: String,
+) -> Result<(), String> {
+    let caller = caller_str(ctx);
+
+    // Messages from before conversations existed (conversation_id = 0) still
+    // carry their own is_read flag.
+    let legacy_unread: Vec<DirectMessage> = ctx
+        .db
+        .direct_message()
+        .conversation_id()
+        .filter(0u64)
+        .filter(|m| m.recipient_identity == caller && m.sender_identity == other_identity && !m.is_read)
+        .collect();
+    for msg in legacy_unread {
+        ctx.db.direct_message().id().update(DirectMessage {
+            is_read: true,
+            ..msg
+        });
+    }
+
+    match find_conversation(ctx, &caller, &other_identity) {
+        Some(conv) => advance_read_cursor(ctx, conv.id, &caller, latest_message_id(ctx, conv.id)),
+        None => Ok(()),
+    }
+}
+
+// ─── edit_message / unsend_message ────────────────────────────────────────────
+
+/// How long after sending a message can still be edited.
+const MESSAGE_EDIT_WINDOW_MICROS: u64 = 15 * 60 * 1_000_000;
+
+/// A live message the caller sent.
+fn own_message(ctx: &ReducerContext, message_id: u64) -> Result<DirectMessage, String> {
     let msg = ctx
         .db
         .direct_message()
         .id()
         .find(message_id)
         .ok_or("Message not found")?;
-
-    if msg.recipient_identity != caller {
+    if msg.sender_identity != caller_str(ctx) {
         return Err("Not authorized".to_string());
     }
+    if msg.is_deleted {
+        return Err("Message was deleted".to_string());
+    }
+    Ok(msg)
+}
+
+#[reducer]
+pub fn edit_message(ctx: &ReducerContext, message_id: u64, text: String) -> Result<(), String> {
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+    let msg = own_message(ctx, message_id)?;
+
+    let now = now_micros(ctx);
+    if now.saturating_sub(msg.created_at) > MESSAGE_EDIT_WINDOW_MICROS {
+        return Err("Messages can only be edited for 15 minutes after sending".to_string());
+    }
+    let attached = Attachment {
+        block_id: msg.attachment_block_id,
+        topic_id: msg.attachment_topic_id,
+        comment_id: msg.attachment_comment_id,
+    };
+    let trimmed = clean_message_body(ctx, &text, &attached)?;
+    if trimmed == msg.text {
+        return Ok(());
+    }
 
     ctx.db.direct_message().id().update(DirectMessage {
-        is_read: true,
+        text: trimmed,
+        edited_at: now,
         ..msg
     });
 
     Ok(())
 }
 
-// ─── mark_all_messages_read ───────────────────────────────────────────────────
+/// Remove a message for everyone in the conversation. The row stays as a
+/// tombstone (no text, is_deleted) so read cursors and ordering are unaffected.
+#[reducer]
+pub fn unsend_message(ctx: &ReducerContext, message_id: u64) -> Result<(), String> {
+    let msg = own_message(ctx, message_id)?;
+
+    ctx.db.message_reaction().message_id().delete(message_id);
+    ctx.db.direct_message().id().update(DirectMessage {
+        text: String::new(),
+        is_deleted: true,
+        attachment_block_id: 0,
+        attachment_topic_id: 0,
+        attachment_comment_id: 0,
+        ..msg
+    });
 
+    Ok(())
+}
+
+// ─── react_to_message ────────────────────────────────────────────────────────
+
+/// Longest reaction kept, in chars; long enough for family and skin-tone ZWJ sequences.
+const MAX_REACTION_CHARS: usize = 16;
+
+/// Code points that can start an emoji (the Extended_Pictographic blocks in use).
+fn is_pictograph(c: char) -> bool {
+    matches!(c as u32,
+        0x00A9 | 0x00AE | 0x203C | 0x2049 | 0x2122 | 0x2139
+        | 0x2194..=0x21AA | 0x231A..=0x23FF | 0x24C2 | 0x25AA..=0x25FE
+        | 0x2600..=0x27BF | 0x2934..=0x2935 | 0x2B05..=0x2B55
+        | 0x3030 | 0x303D | 0x3297 | 0x3299
+        | 0x1F000..=0x1F1E5 | 0x1F200..=0x1F3FA | 0x1F400..=0x1FAFF)
+}
+
+fn is_regional_indicator(c: &char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(c)
+}
+
+/// A reaction is exactly one emoji: a flag, a keycap, or pictographs joined by
+/// ZWJ, each optionally followed by a variation selector and a skin tone, with
+/// an optional tag sequence (subdivision flags). Anything else — text, several
+/// emoji in a row — is rejected.
+fn is_valid_reaction(emoji: &str) -> bool {
+    let chars: Vec<char> = emoji.chars().collect();
+    if chars.is_empty() || chars.len() > MAX_REACTION_CHARS {
+        return false;
+    }
+    if chars.len() == 2 && chars.iter().all(is_regional_indicator) {
+        return true;
+    }
+    if let [key, .., '\u{20E3}'] = chars.as_slice() {
+        let keycap = (key.is_ascii_digit() || *key == '#' || *key == '*')
+            && (chars.len() == 2 || (chars.len() == 3 && chars[1] == '\u{FE0F}'));
+        return keycap;
+    }
+
+    let mut rest = chars.into_iter().peekable();
+    loop {
+        if !rest.next().is_some_and(is_pictograph) {
+            return false;
+        }
+        rest.next_if_eq(&'\u{FE0F}');
+        rest.next_if(|c| ('\u{1F3FB}'..='\u{1F3FF}').contains(c));
+        if rest.next_if_eq(&'\u{200D}').is_none() {
+            break;
+        }
+    }
+    if rest.peek().is_some_and(|c| ('\u{E0020}'..='\u{E007E}').contains(c)) {
+        while rest.next_if(|c| ('\u{E0020}'..='\u{E007E}').contains(c)).is_some() {}
+        if rest.next_if_eq(&'\u{E007F}').is_none() {
+            return false;
+        }
+    }
+    rest.next().is_none()
+}
+
+/// Set the caller's reaction to a message, replacing any previous one.
+/// An empty `emoji` removes it.
 #[reducer]
-pub fn mark_all_messages_read(
+pub fn react_to_message(ctx: &ReducerContext, message_id: u64, emoji: String) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+
+    let msg = ctx
+        .db
+        .direct_message()
+        .id()
+        .find(message_id)
+        .ok_or("Message not found")?;
+    if msg.is_deleted {
+        return Err("Message was deleted".to_string());
+    }
+    let conv = ctx
+        .db
+        .conversation()
+        .id()
+        .find(msg.conversation_id)
+        .ok_or("Conversation not found")?;
+    if !is_participant(ctx, &conv, &caller) {
+        return Err("Not authorized".to_string());
+    }
+    if crate::reducers::moderation::is_blocked(ctx, &caller, &msg.sender_identity) {
+        return Err("Cannot react to a blocked user".to_string());
+    }
+
+    let existing = ctx
+        .db
+        .message_reaction()
+        .message_id()
+        .filter(message_id)
+        .find(|r| r.identity == caller);
+
+    let emoji = emoji.trim().to_string();
+    if emoji.is_empty() {
+        if let Some(reaction) = existing {
+            ctx.db.message_reaction().id().delete(reaction.id);
+        }
+        return Ok(());
+    }
+    if !is_valid_reaction(&emoji) {
+        return Err("Reaction must be a single emoji".to_string());
+    }
+
+    match existing {
+        Some(reaction) if reaction.emoji == emoji => {}
+        Some(reaction) => {
+            ctx.db.message_reaction().id().update(MessageReaction {
+                emoji,
+                created_at: now_micros(ctx),
+                ..reaction
+            });
+        }
+        None => {
+            ctx.db
+                .message_reaction()
+                .try_insert(MessageReaction {
+                    id: 0,
+                    message_id,
+                    identity: caller,
+                    emoji,
+                    created_at: now_micros(ctx),
+                })
+                .map_err(|e| format!("Insert failed: {e}"))?;
+        }
+    }
+
+    Ok(())
+}
+
+// ─── group conversations ─────────────────────────────────────────────────────
+
+/// Members per group, including the creator.
+const MAX_GROUP_MEMBERS: usize = 32;
+
+fn group_members(ctx: &ReducerContext, conversation_id: u64) -> Vec<ConversationParticipant> {
+    ctx.db
+        .conversation_participant()
+        .conversation_id()
+        .filter(conversation_id)
+        .collect()
+}
+
+fn find_participant(ctx: &ReducerContext, conversation_id: u64, identity: &str) -> Option<ConversationParticipant> {
+    ctx.db
+        .conversation_participant()
+        .conversation_id()
+        .filter(conversation_id)
+        .find(|p| p.identity == identity)
+}
+
+fn find_group(ctx: &ReducerContext, conversation_id: u64) -> Result<Conversation, String> {
+    let conv = ctx
+        .db
+        .conversation()
+        .id()
+        .find(conversation_id)
+        .ok_or("Conversation not found")?;
+    if !conv.is_group {
+        return Err("Not a group conversation".to_string());
+    }
+    Ok(conv)
+}
+
+/// The caller's membership row, or an error if they aren't in the group
+/// (or, with `admin`, aren't one of its admins).
+fn require_member(ctx: &ReducerContext, conversation_id: u64, admin: bool) -> Result<ConversationParticipant, String> {
+    let member = find_participant(ctx, conversation_id, &caller_str(ctx)).ok_or("Not authorized")?;
+    if admin && !member.is_admin {
+        return Err("Only group admins can do that".to_string());
+    }
+    Ok(member)
+}
+
+fn clean_group_title(ctx: &ReducerContext, title: &str) -> Result<String, String> {
+    let trimmed = title.trim().to_string();
+    if trimmed.is_empty() {
+        return Err("Group name cannot be empty".to_string());
+    }
+    if trimmed.len() > 80 {
+        return Err("Group name too long (max 80 chars)".to_string());
+    }
+    crate::reducers::filter::filter_text_or_reject(ctx, &trimmed, 0)
+}
+
+/// Check that `invitee` can be added by `inviter` to a group with `members`:
+/// they exist, follow the inviter, and no block exists between them and anyone present.
+fn check_invitee(ctx: &ReducerContext, inviter: &str, invitee: &str, members: &[String]) -> Result<(), String> {
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(invitee.to_string())
+        .ok_or("User not found")?;
+    let follows_inviter = ctx
+        .db
+        .user_follow()
+        .following_identity()
+        .filter(inviter)
+        .any(|f| f.follower_identity == invitee);
+    if !follows_inviter {
+        return Err("You can only add people who follow you".to_string());
+    }
+    if members.iter().any(|m| crate::reducers::moderation::is_blocked(ctx, m, invitee)) {
+        return Err("Cannot add a user who is blocked by, or has blocked, a group member".to_string());
+    }
+    Ok(())
+}
+
+fn add_participant(ctx: &ReducerContext, conversation_id: u64, identity: &str, is_admin: bool) -> Result<(), String> {
+    ctx.db
+        .conversation_participant()
+        .try_insert(ConversationParticipant {
+            id: 0,
+            conversation_id,
+            identity: identity.to_string(),
+            is_admin,
+            joined_at: now_micros(ctx),
+        })
+        .map_err(|e| format!("Insert failed: {e}"))?;
+    Ok(())
+}
+
+/// Remove a member. The last member out deletes the group and its messages;
+/// if the last admin leaves, the longest-standing member is promoted.
+/// Messages everyone remaining has cleared are then purged.
+fn drop_participant(ctx: &ReducerContext, member: ConversationParticipant) {
+    let conversation_id = member.conversation_id;
+    ctx.db.conversation_participant().id().delete(member.id);
+    clear_read_cursor(ctx, conversation_id, &member.identity);
+
+    let mut remaining = group_members(ctx, conversation_id);
+    if remaining.is_empty() {
+        let msg_ids: Vec<u64> = ctx
+            .db
+            .direct_message()
+            .conversation_id()
+            .filter(conversation_id)
+            .map(|m| m.id)
+            .collect();
+        for id in msg_ids {
+            ctx.db.message_reaction().message_id().delete(id);
+        }
+        ctx.db.direct_message().conversation_id().delete(conversation_id);
+        ctx.db.conversation_read_state().conversation_id().delete(conversation_id);
+        ctx.db.conversation().id().delete(conversation_id);
+        return;
+    }
+
+    if member.is_admin && !remaining.iter().any(|p| p.is_admin) {
+        remaining.sort_by_key(|p| (p.joined_at, p.id));
+        let successor = remaining.swap_remove(0);
+        ctx.db.conversation_participant().id().update(ConversationParticipant {
+            is_admin: true,
+            ..successor
+        });
+    }
+
+    // The leaver's marker no longer holds anything back.
+    if let Some(conv) = ctx.db.conversation().id().find(conversation_id) {
+        purge_cleared_messages(ctx, &conv);
+    }
+}
+
+/// Take a user out of every group they belong to (account deletion).
+pub fn remove_user_from_groups(ctx: &ReducerContext, identity: &str) {
+    let memberships: Vec<ConversationParticipant> =
+        ctx.db.conversation_participant().identity().filter(identity).collect();
+    for member in memberships {
+        drop_participant(ctx, member);
+    }
+}
+
+fn touch_conversation(ctx: &ReducerContext, conv: Conversation) {
+    ctx.db.conversation().id().update(Conversation {
+        updated_at: now_micros(ctx),
+        ..conv
+    });
+}
+
+#[reducer]
+pub fn create_group_conversation(
     ctx: &ReducerContext,
-    other_identity: String,
+    title: String,
+    member_identities: Vec<String>,
+) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+
+    let title = clean_group_title(ctx, &title)?;
+
+    let mut members: Vec<String> = vec![caller.clone()];
+    for identity in member_identities {
+        if !members.contains(&identity) {
+            members.push(identity);
+        }
+    }
+    if members.len() < 3 {
+        return Err("A group needs at least two other members".to_string());
+    }
+    if members.len() > MAX_GROUP_MEMBERS {
+        return Err(format!("Groups are limited to {MAX_GROUP_MEMBERS} members"));
+    }
+    for (i, identity) in members.iter().enumerate().skip(1) {
+        check_invitee(ctx, &caller, identity, &members[..i])?;
+    }
+
+    let now = now_micros(ctx);
+    let conv = ctx
+        .db
+        .conversation()
+        .try_insert(Conversation {
+            id: 0,
+            participant_a: String::new(),
+            participant_b: String::new(),
+            status: "active".to_string(),
+            request_recipient: String::new(),
+            created_at: now,
+            updated_at: now,
+            is_group: true,
+            title: Some(title),
+        })
+        .map_err(|e| format!("Conversation insert failed: {e}"))?;
+
+    for identity in &members {
+        add_participant(ctx, conv.id, identity, *identity == caller)?;
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn invite_to_conversation(
+    ctx: &ReducerContext,
+    conversation_id: u64,
+    identity: String,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+
+    let conv = find_group(ctx, conversation_id)?;
+    require_member(ctx, conversation_id, true)?;
 
-    let to_update: Vec<DirectMessage> = ctx
+    let members: Vec<String> = group_members(ctx, conversation_id).into_iter().map(|p| p.identity).collect();
+    if members.contains(&identity) {
+        return Err("Already a member".to_string());
+    }
+    if members.len() >= MAX_GROUP_MEMBERS {
+        return Err(format!("Groups are limited to {MAX_GROUP_MEMBERS} members"));
+    }
+    check_invitee(ctx, &caller, &identity, &members)?;
+
+    add_participant(ctx, conversation_id, &identity, false)?;
+    touch_conversation(ctx, conv);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn leave_conversation(ctx: &ReducerContext, conversation_id: u64) -> Result<(), String> {
+    find_group(ctx, conversation_id)?;
+    let member = require_member(ctx, conversation_id, false)?;
+    drop_participant(ctx, member);
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_from_conversation(
+    ctx: &ReducerContext,
+    conversation_id: u64,
+    identity: String,
+) -> Result<(), String> {
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+    find_group(ctx, conversation_id)?;
+    require_member(ctx, conversation_id, true)?;
+    let member = find_participant(ctx, conversation_id, &identity).ok_or("Not a member")?;
+    if member.identity == caller_str(ctx) {
+        return Err("Use leave_conversation to leave a group".to_string());
+    }
+    drop_participant(ctx, member);
+    Ok(())
+}
+
+#[reducer]
+pub fn rename_conversation(ctx: &ReducerContext, conversation_id: u64, title: String) -> Result<(), String> {
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+    let conv = find_group(ctx, conversation_id)?;
+    require_member(ctx, conversation_id, true)?;
+    let title = clean_group_title(ctx, &title)?;
+    ctx.db.conversation().id().update(Conversation {
+        title: Some(title),
+        updated_at: now_micros(ctx),
+        ..conv
+    });
+    Ok(())
+}
+
+/// Grant or revoke admin rights. A group always keeps at least one admin.
+#[reducer]
+pub fn set_conversation_admin(
+    ctx: &ReducerContext,
+    conversation_id: u64,
+    identity: String,
+    is_admin: bool,
+) -> Result<(), String> {
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+    find_group(ctx, conversation_id)?;
+    require_member(ctx, conversation_id, true)?;
+    let member = find_participant(ctx, conversation_id, &identity).ok_or("Not a member")?;
+    if member.is_admin == is_admin {
+        return Ok(());
+    }
+    if !is_admin {
+        let admins = group_members(ctx, conversation_id).iter().filter(|p| p.is_admin).count();
+        if admins <= 1 {
+            return Err("A group needs at least one admin".to_string());
+        }
+    }
+    ctx.db.conversation_participant().id().update(ConversationParticipant {
+        is_admin,
+        ..member
+    });
+    Ok(())
+}
+
+#[reducer]
+pub fn send_group_message(ctx: &ReducerContext, conversation_id: u64, text: String) -> Result<(), String> {
+    send_to_group(ctx, conversation_id, text, Attachment::default())
+}
+
+/// Group form of send_message_with_attachment; every member must be able to see the attachment.
+#[reducer]
+pub fn send_group_message_with_attachment(
+    ctx: &ReducerContext,
+    conversation_id: u64,
+    text: String,
+    block_id: u64,
+    topic_id: u64,
+    comment_id: u64,
+) -> Result<(), String> {
+    send_to_group(ctx, conversation_id, text, Attachment { block_id, topic_id, comment_id })
+}
+
+fn send_to_group(ctx: &ReducerContext, conversation_id: u64, text: String, attachment: Attachment) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+
+    let conv = find_group(ctx, conversation_id)?;
+    require_member(ctx, conversation_id, false)?;
+    let trimmed = clean_message_body(ctx, &text, &attachment)?;
+
+    let members: Vec<String> = group_members(ctx, conversation_id).into_iter().map(|p| p.identity).collect();
+    let others: Vec<String> = members.iter().filter(|identity| **identity != caller).cloned().collect();
+    if others.iter().any(|other| crate::reducers::moderation::is_blocked(ctx, &caller, other)) {
+        return Err("Cannot message a group that includes a blocked user".to_string());
+    }
+    let attachment = resolve_attachment(ctx, attachment, &members)?;
+
+    let now = now_micros(ctx);
+    let msg = ctx
         .db
         .direct_message()
-        .iter()
-        .filter(|m| {
-            m.recipient_identity == caller
-                && m.sender_identity == other_identity
-                && !m.is_read
+        .try_insert(DirectMessage {
+            id: 0,
+            conversation_id,
+            sender_identity: caller.clone(),
+            recipient_identity: String::new(),
+            text: trimmed,
+            is_read: false,
+            is_deleted: false,
+            created_at: now,
+            edited_at: 0,
+            attachment_block_id: attachment.block_id,
+            attachment_topic_id: attachment.topic_id,
+            attachment_comment_id: attachment.comment_id,
         })
-        .collect();
+        .map_err(|e| format!("Message insert failed: {e}"))?;
+    touch_conversation(ctx, conv);
 
-    for msg in to_update {
-        ctx.db.direct_message().id().update(DirectMessage {
-            is_read: true,
-            ..msg
+    // The sender has read their own message.
+    advance_read_cursor(ctx, conversation_id, &caller, msg.id)?;
+
+    let actor_name = caller_name(ctx);
+    for recipient in others {
+        crate::reducers::notifications::notify_activity(ctx, Notification {
+            conversation_id,
+            ..Notification::new(ctx, &recipient, &caller, &actor_name, "new_message")
         });
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid_reaction;
+
+    #[test]
+    fn accepts_single_and_composed_emoji() {
+        assert!(is_valid_reaction("👍"));
+        assert!(is_valid_reaction("❤️"));
+        assert!(is_valid_reaction("👍🏽"));
+        assert!(is_valid_reaction("👩‍👩‍👧"));
+        assert!(is_valid_reaction("🏳️‍🌈"));
+        assert!(is_valid_reaction("🇺🇸"));
+        assert!(is_valid_reaction("1️⃣"));
+        assert!(is_valid_reaction("🏴󠁧󠁢󠁳󠁣󠁴󠁿"));
+    }
+
+    #[test]
+    fn rejects_text_and_several_emoji() {
+        assert!(!is_valid_reaction(""));
+        assert!(!is_valid_reaction("ok"));
+        assert!(!is_valid_reaction("é"));
+        assert!(!is_valid_reaction("中文"));
+        assert!(!is_valid_reaction("👍 👍"));
+        assert!(!is_valid_reaction("👍👍"));
+        assert!(!is_valid_reaction("🇺🇸🇬"));
+        assert!(!is_valid_reaction("😀😀😀😀😀😀😀😀😀"));
+    }
+}
@@ -11,3 +11,12 @@ pub mod messages;
 pub mod follow;
 pub mod moderation;
 pub mod api_keys;
+pub mod audit;
+pub mod admin_actions;
+pub mod sanctions;
+pub mod auto_moderation;
+pub mod filter;
+pub mod notifications;
+pub mod activity;
+pub mod suggestions;
+pub mod counters;
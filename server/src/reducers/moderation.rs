@@ -1,5 +1,15 @@
-use spacetimedb::{reducer, ReducerContext, Table};
+use spacetimedb::{reducer, ReducerContext, ScheduleAt, Table, Timestamp};
 use crate::tables::*;
+use crate::reducers::audit::record_audit;
+use crate::reducers::comment::{delete_comment_cascade, publish_comment_view};
+use crate::reducers::claim::{adjust_topic_video_count, rebalance_topic_blocks, remove_block_as_moderator};
+use crate::reducers::topic::can_moderate_topic;
+use crate::reducers::follow::canonical_pair;
+use crate::reducers::notifications::notify;
+use crate::reducers::auto_moderation::{evaluate_queue_item, restore_auto_hidden_content};
+use crate::reducers::suggestions::remove_user_suggestion;
+use crate::reducers::counters::{adjust_follow_counts, remove_block_from_counters};
+use std::collections::HashSet;
 
 fn now_micros(ctx: &ReducerContext) -> u64 {
     ctx.timestamp.to_micros_since_unix_epoch() as u64
@@ -19,13 +29,79 @@ fn is_caller_admin(ctx: &ReducerContext) -> bool {
         .unwrap_or(false)
 }
 
+// ─── Hide reasons ───────────────────────────────────────────────────────────
+
+fn hide_reason(ctx: &ReducerContext, block_id: u64, comment_id: u64) -> Option<String> {
+    if block_id != 0 {
+        ctx.db.content_hide().block_id().filter(block_id).next().map(|h| h.reason)
+    } else {
+        ctx.db.content_hide().comment_id().filter(comment_id).next().map(|h| h.reason)
+    }
+}
+
+fn clear_hide_reason(ctx: &ReducerContext, block_id: u64, comment_id: u64) {
+    let existing: Vec<u64> = if block_id != 0 {
+        ctx.db.content_hide().block_id().filter(block_id).map(|h| h.id).collect()
+    } else {
+        ctx.db.content_hide().comment_id().filter(comment_id).map(|h| h.id).collect()
+    };
+    for id in existing {
+        ctx.db.content_hide().id().delete(id);
+    }
+}
+
+fn set_hide_reason(ctx: &ReducerContext, block_id: u64, comment_id: u64, reason: Option<&str>) -> Result<(), String> {
+    clear_hide_reason(ctx, block_id, comment_id);
+    if let Some(reason) = reason {
+        ctx.db
+            .content_hide()
+            .try_insert(ContentHide {
+                id: 0,
+                block_id,
+                comment_id,
+                reason: reason.to_string(),
+                created_at: now_micros(ctx),
+            })
+            .map_err(|e| format!("Hide reason insert failed: {e}"))?;
+    }
+    Ok(())
+}
+
+/// Why a hidden block is hidden (None if it isn't).
+pub fn block_hide_reason(ctx: &ReducerContext, block_id: u64) -> Option<String> {
+    hide_reason(ctx, block_id, 0)
+}
+
+/// Why a hidden comment is hidden (None if it isn't).
+pub fn comment_hide_reason(ctx: &ReducerContext, comment_id: u64) -> Option<String> {
+    hide_reason(ctx, 0, comment_id)
+}
+
+/// Record (Some) or clear (None) why a block is hidden. Callers set `Block.hidden` to match.
+pub fn set_block_hide_reason(ctx: &ReducerContext, block_id: u64, reason: Option<&str>) -> Result<(), String> {
+    set_hide_reason(ctx, block_id, 0, reason)
+}
+
+/// Record (Some) or clear (None) why a comment is hidden. Callers set `Comment.hidden` to match.
+pub fn set_comment_hide_reason(ctx: &ReducerContext, comment_id: u64, reason: Option<&str>) -> Result<(), String> {
+    set_hide_reason(ctx, 0, comment_id, reason)
+}
+
+/// Drop a deleted block's hide reason.
+pub fn clear_block_hide_reason(ctx: &ReducerContext, block_id: u64) {
+    clear_hide_reason(ctx, block_id, 0);
+}
+
+/// Drop a deleted comment's hide reason.
+pub fn clear_comment_hide_reason(ctx: &ReducerContext, comment_id: u64) {
+    clear_hide_reason(ctx, 0, comment_id);
+}
+
 /// Check if either user has blocked the other.
-/// Public so follow.rs and messages.rs can use it as a guard.
+/// Public so interaction reducers and notification inserts can use it as a guard.
 pub fn is_blocked(ctx: &ReducerContext, a: &str, b: &str) -> bool {
-    ctx.db.user_block().iter().any(|bl| {
-        (bl.blocker_identity == a && bl.blocked_identity == b)
-            || (bl.blocker_identity == b && bl.blocked_identity == a)
-    })
+    ctx.db.user_block().blocker_identity().filter(a).any(|bl| bl.blocked_identity == b)
+        || ctx.db.user_block().blocker_identity().filter(b).any(|bl| bl.blocked_identity == a)
 }
 
 // ─── block_user ──────────────────────────────────────────────────────────────
@@ -49,9 +125,12 @@ pub fn block_user(
         .ok_or("User not found")?;
 
     // Check not already blocked
-    let already = ctx.db.user_block().iter().any(|bl| {
-        bl.blocker_identity == caller && bl.blocked_identity == target_identity
-    });
+    let already = ctx
+        .db
+        .user_block()
+        .blocker_identity()
+        .filter(&caller)
+        .any(|bl| bl.blocked_identity == target_identity);
     if already {
         return Err("User is already blocked".to_string());
     }
@@ -67,22 +146,61 @@ pub fn block_user(
         .map_err(|e| format!("Insert failed: {e}"))?;
 
     // Remove follows in both directions
-    let follows_to_remove: Vec<u64> = ctx
+    let follows_to_remove: Vec<UserFollow> = ctx
         .db
         .user_follow()
+        .follower_identity()
+        .filter(&caller)
+        .filter(|f| f.following_identity == target_identity)
+        .chain(
+            ctx.db
+                .user_follow()
+                .follower_identity()
+                .filter(&target_identity)
+                .filter(|f| f.following_identity == caller),
+        )
+        .collect();
+
+    let removed_count = follows_to_remove.len();
+    for follow in follows_to_remove {
+        ctx.db.user_follow().id().delete(follow.id);
+        adjust_follow_counts(ctx, &follow.follower_identity, &follow.following_identity, -1);
+    }
+
+    // Drop follow requests in both directions
+    let requests_to_remove: Vec<u64> = ctx
+        .db
+        .follow_request()
         .iter()
-        .filter(|f| {
-            (f.follower_identity == caller && f.following_identity == target_identity)
-                || (f.follower_identity == target_identity && f.following_identity == caller)
+        .filter(|r| {
+            (r.requester_identity == caller && r.target_identity == target_identity)
+                || (r.requester_identity == target_identity && r.target_identity == caller)
         })
-        .map(|f| f.id)
+        .map(|r| r.id)
         .collect();
+    for id in requests_to_remove {
+        ctx.db.follow_request().id().delete(id);
+    }
 
-    let removed_count = follows_to_remove.len();
-    for id in follows_to_remove {
-        ctx.db.user_follow().id().delete(id);
+    // Close any pending message request between the pair
+    let (pa, pb) = canonical_pair(&caller, &target_identity);
+    let pending: Vec<Conversation> = ctx
+        .db
+        .conversation()
+        .iter()
+        .filter(|c| c.participant_a == pa && c.participant_b == pb && c.status == "request_pending")
+        .collect();
+    for conv in pending {
+        ctx.db.conversation().id().update(Conversation {
+            status: "request_declined".to_string(),
+            updated_at: now_micros(ctx),
+            ..conv
+        });
     }
 
+    remove_user_suggestion(ctx, &caller, &target_identity);
+    remove_user_suggestion(ctx, &target_identity, &caller);
+
     log::info!(
         "User {} blocked {}. Removed {} follow relationships.",
         &caller[..12.min(caller.len())],
@@ -105,8 +223,9 @@ pub fn unblock_user(
     let block = ctx
         .db
         .user_block()
-        .iter()
-        .find(|bl| bl.blocker_identity == caller && bl.blocked_identity == target_identity)
+        .blocker_identity()
+        .filter(&caller)
+        .find(|bl| bl.blocked_identity == target_identity)
         .ok_or("User is not blocked")?;
 
     ctx.db.user_block().id().delete(block.id);
@@ -116,10 +235,64 @@ pub fn unblock_user(
 
 // ─── mute_user ───────────────────────────────────────────────────────────────
 
+/// Whether `muter` currently has `muted` muted. Mutes past their expiry that the
+/// scheduler hasn't removed yet don't count.
+pub fn is_muted(ctx: &ReducerContext, muter: &str, muted: &str) -> bool {
+    let now = now_micros(ctx);
+    ctx.db
+        .user_mute()
+        .muter_identity()
+        .filter(muter)
+        .any(|m| m.muted_identity == muted && m.expires_at.map(|t| t > now).unwrap_or(true))
+}
+
+/// Flag (or unflag) the muter's existing notifications from the muted user and
+/// rebuild their open comment view.
+fn apply_mute_to_views(ctx: &ReducerContext, muter: &str, muted: &str, is_muted: bool) {
+    let notifications: Vec<Notification> = ctx
+        .db
+        .notification()
+        .recipient_identity()
+        .filter(muter)
+        .filter(|n| n.actor_identity == muted && n.actor_muted != is_muted)
+        .collect();
+    for notification in notifications {
+        ctx.db.notification().id().update(Notification {
+            actor_muted: is_muted,
+            ..notification
+        });
+    }
+
+    if let Some(view) = ctx.db.comment_view().viewer_identity().find(muter.to_string()) {
+        publish_comment_view(ctx, muter, view.block_id);
+    }
+}
+
+/// Delete a mute and its expiry schedule, then restore the muted user's content.
+fn end_mute(ctx: &ReducerContext, mute: &UserMute) {
+    ctx.db.user_mute().id().delete(mute.id);
+
+    let schedule_ids: Vec<u64> = ctx
+        .db
+        .user_mute_expiry()
+        .iter()
+        .filter(|s| s.mute_id == mute.id)
+        .map(|s| s.scheduled_id)
+        .collect();
+    for id in schedule_ids {
+        ctx.db.user_mute_expiry().scheduled_id().delete(id);
+    }
+
+    apply_mute_to_views(ctx, &mute.muter_identity, &mute.muted_identity, false);
+}
+
+/// Mute a user for `duration_hours` (0 = until unmuted). Their comments leave the
+/// caller's comment view and they stop generating notifications for the caller.
 #[reducer]
 pub fn mute_user(
     ctx: &ReducerContext,
     target_identity: String,
+    duration_hours: u64,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
 
@@ -133,23 +306,55 @@ pub fn mute_user(
         .find(target_identity.clone())
         .ok_or("User not found")?;
 
-    let already = ctx.db.user_mute().iter().any(|m| {
-        m.muter_identity == caller && m.muted_identity == target_identity
-    });
-    if already {
+    if is_muted(ctx, &caller, &target_identity) {
         return Err("User is already muted".to_string());
     }
 
-    ctx.db
+    // Clear out a lapsed mute the scheduler hasn't removed yet
+    let stale: Vec<UserMute> = ctx
+        .db
+        .user_mute()
+        .muter_identity()
+        .filter(&caller)
+        .filter(|m| m.muted_identity == target_identity)
+        .collect();
+    for mute in &stale {
+        end_mute(ctx, mute);
+    }
+
+    let now = now_micros(ctx);
+    let expires_at = if duration_hours == 0 {
+        None
+    } else {
+        Some(now.saturating_add(duration_hours.saturating_mul(3_600_000_000)))
+    };
+
+    let mute = ctx
+        .db
         .user_mute()
         .try_insert(UserMute {
             id: 0,
-            muter_identity: caller,
-            muted_identity: target_identity,
-            created_at: now_micros(ctx),
+            muter_identity: caller.clone(),
+            muted_identity: target_identity.clone(),
+            created_at: now,
+            expires_at,
         })
         .map_err(|e| format!("Insert failed: {e}"))?;
 
+    if let Some(expires_at) = expires_at {
+        ctx.db
+            .user_mute_expiry()
+            .try_insert(UserMuteExpiry {
+                scheduled_id: 0,
+                scheduled_at: ScheduleAt::Time(Timestamp::from_micros_since_unix_epoch(expires_at as i64)),
+                mute_id: mute.id,
+            })
+            .map_err(|e| format!("Mute expiry schedule failed: {e}"))?;
+    }
+
+    apply_mute_to_views(ctx, &caller, &target_identity, true);
+    remove_user_suggestion(ctx, &caller, &target_identity);
+
     Ok(())
 }
 
@@ -165,11 +370,238 @@ pub fn unmute_user(
     let mute = ctx
         .db
         .user_mute()
-        .iter()
-        .find(|m| m.muter_identity == caller && m.muted_identity == target_identity)
+        .muter_identity()
+        .filter(&caller)
+        .find(|m| m.muted_identity == target_identity)
         .ok_or("User is not muted")?;
 
-    ctx.db.user_mute().id().delete(mute.id);
+    end_mute(ctx, &mute);
+
+    Ok(())
+}
+
+// ─── expire_user_mute (scheduled) ────────────────────────────────────────────
+
+/// Ends a time-limited mute once its `expires_at` has passed.
+#[reducer]
+pub fn expire_user_mute(ctx: &ReducerContext, schedule: UserMuteExpiry) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("expire_user_mute may only be invoked by the scheduler".to_string());
+    }
+
+    let Some(mute) = ctx.db.user_mute().id().find(schedule.mute_id) else {
+        return Ok(());
+    };
+    if mute.expires_at.map(|t| t > now_micros(ctx)).unwrap_or(true) {
+        return Ok(());
+    }
+
+    end_mute(ctx, &mute);
+    Ok(())
+}
+
+// ─── moderation queue ───────────────────────────────────────────────────────
+
+/// Everyone who should see a topic's moderation queue: owner + active moderators.
+fn topic_moderator_identities(ctx: &ReducerContext, topic_id: u64) -> HashSet<String> {
+    let mut identities: HashSet<String> = ctx
+        .db
+        .topic_moderator()
+        .iter()
+        .filter(|m| m.topic_id == topic_id && m.status == "active")
+        .map(|m| m.identity)
+        .collect();
+    if let Some(topic) = ctx.db.topic().id().find(topic_id) {
+        identities.insert(topic.creator_identity);
+    }
+    identities
+}
+
+/// What a report points at. Ids that don't apply to the target type are 0.
+struct ReportTarget {
+    target_type: &'static str,
+    reported_identity: String,
+    topic_id: u64,
+    block_id: u64,
+    comment_id: u64,
+}
+
+/// "user" | "block" | "comment"; user reports leave the column unset.
+fn report_target_type(report: &UserReport) -> &str {
+    report.target_type.as_deref().unwrap_or("user")
+}
+
+/// Insert a report and group it into the open queue item for its target,
+/// creating the item (and notifying the topic's moderators) on the first report.
+fn file_report(
+    ctx: &ReducerContext,
+    target: ReportTarget,
+    reason: String,
+    description: String,
+) -> Result<(), String> {
+    let ReportTarget { target_type, reported_identity, topic_id, block_id, comment_id } = target;
+    let caller = caller_str(ctx);
+    let now = now_micros(ctx);
+
+    let duplicate = ctx.db.user_report().reporter_identity().filter(&caller).any(|r| {
+        r.status == "pending"
+            && report_target_type(&r) == target_type
+            && r.reported_identity == reported_identity
+            && r.block_id == block_id
+            && r.comment_id == comment_id
+    });
+    if duplicate {
+        return Err("You have already reported this".to_string());
+    }
+
+    let existing = ctx.db.moderation_queue_item().iter().find(|q| {
+        q.status == "open"
+            && q.target_type == target_type
+            && q.reported_identity == reported_identity
+            && q.block_id == block_id
+            && q.comment_id == comment_id
+    });
+
+    let item = match existing {
+        Some(item) => {
+            let updated = ModerationQueueItem {
+                report_count: item.report_count + 1,
+                last_reason: reason.clone(),
+                updated_at: now,
+                ..item
+            };
+            ctx.db.moderation_queue_item().id().update(updated.clone());
+            updated
+        }
+        None => {
+            let item = ctx
+                .db
+                .moderation_queue_item()
+                .try_insert(ModerationQueueItem {
+                    id: 0,
+                    target_type: target_type.to_string(),
+                    topic_id,
+                    block_id,
+                    comment_id,
+                    reported_identity: reported_identity.clone(),
+                    report_count: 1,
+                    last_reason: reason.clone(),
+                    status: "open".to_string(),
+                    resolution: String::new(),
+                    resolved_by: String::new(),
+                    created_at: now,
+                    updated_at: now,
+                    resolved_at: 0,
+                    escalated: false,
+                    auto_hidden: false,
+                })
+                .map_err(|e| format!("Queue insert failed: {e}"))?;
+
+            if topic_id != 0 {
+                for moderator in topic_moderator_identities(ctx, topic_id) {
+                    if moderator == caller {
+                        continue;
+                    }
+                    notify(ctx, Notification {
+                        block_id,
+                        comment_id,
+                        topic_id,
+                        ..Notification::new(ctx, &moderator, "", "Moderation", "content_reported")
+                    });
+                }
+            }
+            item
+        }
+    };
+
+    ctx.db
+        .user_report()
+        .try_insert(UserReport {
+            id: 0,
+            reporter_identity: caller,
+            reported_identity,
+            reason,
+            description,
+            status: "pending".to_string(),
+            reviewed_by: String::new(),
+            created_at: now,
+            reviewed_at: 0,
+            target_type: (target_type != "user").then(|| target_type.to_string()),
+            block_id,
+            comment_id,
+            topic_id,
+            queue_item_id: item.id,
+            resolution: None,
+        })
+        .map_err(|e| format!("Insert failed: {e}"))?;
+
+    evaluate_queue_item(ctx, &item)
+}
+
+/// Queue a comment that the keyword filter held back (hide reason "filter_hold").
+/// Dismissing or reviewing the item releases it via restore_auto_hidden_content.
+pub fn hold_comment_for_review(ctx: &ReducerContext, comment: &Comment, topic_id: u64) -> Result<(), String> {
+    let now = now_micros(ctx);
+
+    let existing = ctx
+        .db
+        .moderation_queue_item()
+        .iter()
+        .find(|q| q.status == "open" && q.target_type == "comment" && q.comment_id == comment.id);
+    if let Some(item) = existing {
+        ctx.db.moderation_queue_item().id().update(ModerationQueueItem {
+            auto_hidden: true,
+            updated_at: now,
+            ..item
+        });
+        return Ok(());
+    }
+
+    ctx.db
+        .moderation_queue_item()
+        .try_insert(ModerationQueueItem {
+            id: 0,
+            target_type: "comment".to_string(),
+            topic_id,
+            block_id: comment.block_id,
+            comment_id: comment.id,
+            reported_identity: comment.user_identity.clone(),
+            report_count: 0,
+            last_reason: "filter_hold".to_string(),
+            status: "open".to_string(),
+            resolution: String::new(),
+            resolved_by: String::new(),
+            created_at: now,
+            updated_at: now,
+            resolved_at: 0,
+            escalated: false,
+            auto_hidden: true,
+        })
+        .map_err(|e| format!("Queue insert failed: {e}"))?;
+
+    for moderator in topic_moderator_identities(ctx, topic_id) {
+        notify(ctx, Notification {
+            block_id: comment.block_id,
+            comment_id: comment.id,
+            topic_id,
+            ..Notification::new(ctx, &moderator, "", "Moderation", "content_held")
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_report(reason: &str, description: &str, valid_reasons: &[&str]) -> Result<(), String> {
+    if !valid_reasons.contains(&reason) {
+        return Err(format!(
+            "Invalid reason. Must be one of: {}",
+            valid_reasons.join(", ")
+        ));
+    }
+
+    if description.len() > 500 {
+        return Err("Description too long (max 500 chars)".to_string());
+    }
 
     Ok(())
 }
@@ -197,49 +629,270 @@ pub fn report_user(
         .find(target_identity.clone())
         .ok_or("User not found")?;
 
-    if !VALID_REASONS.contains(&reason.as_str()) {
-        return Err(format!(
-            "Invalid reason. Must be one of: {}",
-            VALID_REASONS.join(", ")
-        ));
+    validate_report(&reason, &description, VALID_REASONS)?;
+
+    let target = ReportTarget {
+        target_type: "user",
+        reported_identity: target_identity,
+        topic_id: 0,
+        block_id: 0,
+        comment_id: 0,
+    };
+    file_report(ctx, target, reason, description)
+}
+
+// ─── report_block ────────────────────────────────────────────────────────────
+
+const BLOCK_REPORT_REASONS: &[&str] = &["spam", "inappropriate", "violence", "copyright", "misleading", "other"];
+
+#[reducer]
+pub fn report_block(
+    ctx: &ReducerContext,
+    block_id: u64,
+    reason: String,
+    description: String,
+) -> Result<(), String> {
+    let caller = caller_str(ctx);
+
+    let block = ctx.db.block().id().find(block_id).ok_or("Block not found")?;
+    if block.status != "claimed" {
+        return Err("Block has no video".to_string());
+    }
+    if block.owner_identity == caller {
+        return Err("Cannot report your own video".to_string());
     }
 
-    if description.len() > 500 {
-        return Err("Description too long (max 500 chars)".to_string());
+    validate_report(&reason, &description, BLOCK_REPORT_REASONS)?;
+
+    let target = ReportTarget {
+        target_type: "block",
+        reported_identity: block.owner_identity,
+        topic_id: block.topic_id,
+        block_id,
+        comment_id: 0,
+    };
+    file_report(ctx, target, reason, description)
+}
+
+// ─── report_comment ──────────────────────────────────────────────────────────
+
+const COMMENT_REPORT_REASONS: &[&str] = &["spam", "harassment", "hate_speech", "misinformation", "off_topic", "other"];
+
+#[reducer]
+pub fn report_comment(
+    ctx: &ReducerContext,
+    comment_id: u64,
+    reason: String,
+    description: String,
+) -> Result<(), String> {
+    let caller = caller_str(ctx);
+
+    let comment = ctx.db.comment().id().find(comment_id).ok_or("Comment not found")?;
+    if comment.user_identity == caller {
+        return Err("Cannot report your own comment".to_string());
     }
+    let topic_id = ctx
+        .db
+        .block()
+        .id()
+        .find(comment.block_id)
+        .map(|b| b.topic_id)
+        .ok_or("Block not found")?;
 
-    ctx.db
+    validate_report(&reason, &description, COMMENT_REPORT_REASONS)?;
+
+    let target = ReportTarget {
+        target_type: "comment",
+        reported_identity: comment.user_identity,
+        topic_id,
+        block_id: comment.block_id,
+        comment_id,
+    };
+    file_report(ctx, target, reason, description)
+}
+
+// ─── resolve_moderation_item (topic moderators / admin) ─────────────────────
+
+/// Resolve a queue item and every pending report grouped into it.
+/// `action`: "remove_content" (same effect as mod_remove_block / delete_comment),
+/// "reviewed" (no content change) or "dismiss".
+#[reducer]
+pub fn resolve_moderation_item(
+    ctx: &ReducerContext,
+    item_id: u64,
+    action: String,
+) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+
+    let item = ctx
+        .db
+        .moderation_queue_item()
+        .id()
+        .find(item_id)
+        .ok_or("Queue item not found")?;
+
+    if item.status != "open" {
+        return Err("Queue item has already been resolved".to_string());
+    }
+
+    let authorized = if item.topic_id == 0 || item.escalated {
+        is_caller_admin(ctx)
+    } else {
+        can_moderate_topic(ctx, &caller, item.topic_id)
+    };
+    if !authorized {
+        return Err("Not authorized — must be topic owner, moderator, or admin".to_string());
+    }
+
+    match action.as_str() {
+        "remove_content" => match item.target_type.as_str() {
+            "block" => {
+                let block = ctx.db.block().id().find(item.block_id).ok_or("Block not found")?;
+                remove_block_as_moderator(ctx, block)?;
+            }
+            "comment" => {
+                let comment = ctx.db.comment().id().find(item.comment_id).ok_or("Comment not found")?;
+                delete_comment_cascade(ctx, &comment);
+            }
+            _ => return Err("User reports have no content to remove".to_string()),
+        },
+        "reviewed" | "dismiss" => restore_auto_hidden_content(ctx, &item)?,
+        _ => return Err("Action must be 'remove_content', 'reviewed' or 'dismiss'".to_string()),
+    }
+
+    let now = now_micros(ctx);
+    let report_status = match action.as_str() {
+        "dismiss" => "dismissed",
+        "remove_content" => "upheld",
+        _ => "reviewed",
+    };
+    let reports: Vec<UserReport> = ctx
+        .db
         .user_report()
-        .try_insert(UserReport {
-            id: 0,
-            reporter_identity: caller,
-            reported_identity: target_identity,
-            reason,
-            description,
-            status: "pending".to_string(),
-            reviewed_by: String::new(),
-            created_at: now_micros(ctx),
-            reviewed_at: 0,
-        })
-        .map_err(|e| format!("Insert failed: {e}"))?;
+        .iter()
+        .filter(|r| r.queue_item_id == item_id && (r.status == "pending" || r.status == "escalated"))
+        .collect();
+    for report in reports {
+        ctx.db.user_report().id().update(UserReport {
+            status: report_status.to_string(),
+            reviewed_by: caller.clone(),
+            reviewed_at: now,
+            resolution: Some(if action == "dismiss" { "dismissed".to_string() } else { action.clone() }),
+            ..report
+        });
+    }
+
+    ctx.db.moderation_queue_item().id().update(ModerationQueueItem {
+        status: if action == "dismiss" { "dismissed".to_string() } else { "resolved".to_string() },
+        resolution: action.clone(),
+        resolved_by: caller,
+        resolved_at: now,
+        ..item.clone()
+    });
+
+    record_audit(
+        ctx,
+        "resolve_moderation_item",
+        &item.target_type,
+        &match item.target_type.as_str() {
+            "block" => item.block_id.to_string(),
+            "comment" => item.comment_id.to_string(),
+            _ => item.reported_identity.clone(),
+        },
+        Some(serde_json::json!({
+            "queue_item_id": item_id,
+            "topic_id": item.topic_id,
+            "report_count": item.report_count,
+            "status": "open",
+        })),
+        Some(serde_json::json!({ "resolution": action })),
+    )?;
 
     Ok(())
 }
 
-// ─── review_report (admin) ───────────────────────────────────────────────────
+// ─── review_report ───────────────────────────────────────────────────────────
+
+const REPORT_ACTIONS: &[&str] = &[
+    "dismissed",
+    "reviewed",
+    "warn",
+    "hide_content",
+    "suspend",
+    "ban_from_topic",
+    "escalate",
+];
+
+/// Actions that uphold the report and add a strike to the reported user.
+const STRIKE_ACTIONS: &[&str] = &["warn", "hide_content", "suspend", "ban_from_topic"];
+
+/// Notification from the moderation team about a report. Sent without an actor
+/// so the reviewing moderator stays anonymous.
+fn insert_report_notification(
+    ctx: &ReducerContext,
+    recipient_identity: String,
+    notification_type: &str,
+    report: &UserReport,
+) {
+    notify(ctx, Notification {
+        block_id: report.block_id,
+        comment_id: report.comment_id,
+        topic_id: report.topic_id,
+        ..Notification::new(ctx, &recipient_identity, "", "Moderation", notification_type)
+    });
+}
+
+/// Hide the block or comment a report points at until a moderator restores it.
+fn hide_reported_content(ctx: &ReducerContext, report: &UserReport) -> Result<(), String> {
+    match report_target_type(report) {
+        "block" => {
+            let block = ctx.db.block().id().find(report.block_id).ok_or("Block not found")?;
+            if block.status == "hidden" && block_hide_reason(ctx, block.id).as_deref() == Some("auto_moderation") {
+                // Already off the grid; keep it hidden once the item closes
+                return set_block_hide_reason(ctx, block.id, Some("report"));
+            }
+            if block.status != "claimed" {
+                return Err("Block is not visible".to_string());
+            }
+            let topic_id = block.topic_id;
+            set_block_hide_reason(ctx, block.id, Some("report"))?;
+            ctx.db.block().id().update(Block {
+                status: "hidden".to_string(),
+                hidden: true,
+                ..block
+            });
+            adjust_topic_video_count(ctx, topic_id, -1)?;
+            rebalance_topic_blocks(ctx, topic_id)?;
+        }
+        "comment" => {
+            let comment = ctx.db.comment().id().find(report.comment_id).ok_or("Comment not found")?;
+            set_comment_hide_reason(ctx, comment.id, Some("report"))?;
+            ctx.db.comment().id().update(Comment { hidden: true, ..comment });
+        }
+        _ => return Err("User reports have no content to hide".to_string()),
+    }
+    Ok(())
+}
 
+/// Resolve a report. `duration_days` applies to "suspend" (required) and
+/// "ban_from_topic" (0 = permanent). Topic moderators may review reports in
+/// their topic except "suspend", which is site-wide; "escalate" hands the
+/// report to admins. Upheld reports add a strike (see sanctions::add_strike).
 #[reducer]
 pub fn review_report(
     ctx: &ReducerContext,
     report_id: u64,
     action: String,
+    duration_days: u64,
 ) -> Result<(), String> {
-    if !is_caller_admin(ctx) {
-        return Err("Only admins can review reports".to_string());
-    }
+    let caller = caller_str(ctx);
 
-    if action != "reviewed" && action != "dismissed" {
-        return Err("Action must be 'reviewed' or 'dismissed'".to_string());
+    if !REPORT_ACTIONS.contains(&action.as_str()) {
+        return Err(format!(
+            "Invalid action. Must be one of: {}",
+            REPORT_ACTIONS.join(", ")
+        ));
     }
 
     let report = ctx
@@ -249,36 +902,352 @@ pub fn review_report(
         .find(report_id)
         .ok_or("Report not found")?;
 
-    if report.status != "pending" {
+    let is_admin = is_caller_admin(ctx);
+    let escalated = report.status == "escalated";
+    if report.status != "pending" && !escalated {
         return Err("Report has already been reviewed".to_string());
     }
 
-    let caller = caller_str(ctx);
+    let is_topic_moderator = report.topic_id != 0 && !escalated && can_moderate_topic(ctx, &caller, report.topic_id);
+    if !is_admin && !is_topic_moderator {
+        return Err("Only admins or the topic's moderators can review this report".to_string());
+    }
+    if !is_admin {
+        crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+    }
+    if action == "suspend" && !is_admin {
+        return Err("Only admins can suspend users".to_string());
+    }
+    if action == "escalate" && is_admin {
+        return Err("Admins resolve escalated reports directly".to_string());
+    }
+    if report.reported_identity == caller {
+        return Err("Cannot review a report against yourself".to_string());
+    }
 
-    ctx.db.user_report().id().delete(report_id);
-    ctx.db
-        .user_report()
-        .try_insert(UserReport {
-            status: action,
-            reviewed_by: caller,
-            reviewed_at: now_micros(ctx),
-            ..report
+    let now = now_micros(ctx);
+    let sanction_reason = format!("Report #{report_id}: {}", report.reason);
+
+    // Apply the sanction first so a failure rolls the whole review back.
+    match action.as_str() {
+        "warn" => insert_report_notification(ctx, report.reported_identity.clone(), "moderation_warning", &report),
+        "hide_content" => {
+            hide_reported_content(ctx, &report)?;
+            insert_report_notification(ctx, report.reported_identity.clone(), "content_hidden", &report);
+        }
+        "suspend" => {
+            if duration_days == 0 {
+                return Err("Suspension length must be at least 1 day".to_string());
+            }
+            let until = now.saturating_add(duration_days.saturating_mul(24 * 60 * 60 * 1_000_000));
+            crate::reducers::sanctions::issue_user_sanction(ctx, &report.reported_identity, "suspension", until, &sanction_reason)?;
+            insert_report_notification(ctx, report.reported_identity.clone(), "account_suspended", &report);
+        }
+        "ban_from_topic" => {
+            if report.topic_id == 0 {
+                return Err("Report is not tied to a topic".to_string());
+            }
+            // apply_topic_ban sends the "topic_ban" notification itself
+            apply_topic_ban(
+                ctx,
+                report.topic_id,
+                &report.reported_identity,
+                &sanction_reason,
+                duration_days.saturating_mul(24),
+                "keep",
+            )?;
+        }
+        _ => {}
+    }
+
+    let status = match action.as_str() {
+        "dismissed" => "dismissed",
+        "escalate" => "escalated",
+        a if STRIKE_ACTIONS.contains(&a) => "upheld",
+        _ => "reviewed",
+    };
+
+    ctx.db.user_report().id().update(UserReport {
+        status: status.to_string(),
+        reviewed_by: caller.clone(),
+        reviewed_at: now,
+        resolution: Some(action.clone()),
+        ..report.clone()
+    });
+
+    insert_report_notification(
+        ctx,
+        report.reporter_identity.clone(),
+        if action == "escalate" { "report_escalated" } else { "report_resolved" },
+        &report,
+    );
+
+    if STRIKE_ACTIONS.contains(&action.as_str()) {
+        crate::reducers::sanctions::add_strike(ctx, &report.reported_identity, report_id, &action)?;
+    }
+
+    if let Some(item) = ctx.db.moderation_queue_item().id().find(report.queue_item_id) {
+        if action == "escalate" {
+            if !item.escalated {
+                ctx.db.moderation_queue_item().id().update(ModerationQueueItem {
+                    escalated: true,
+                    updated_at: now,
+                    ..item
+                });
+                let admins: Vec<String> = ctx
+                    .db
+                    .user_profile()
+                    .iter()
+                    .filter(|u| u.is_admin)
+                    .map(|u| u.identity)
+                    .collect();
+                for admin in admins {
+                    insert_report_notification(ctx, admin, "report_escalated", &report);
+                }
+            }
+        } else {
+            // Close the queue item once none of its reports are pending
+            let still_pending = ctx
+                .db
+                .user_report()
+                .iter()
+                .any(|r| r.queue_item_id == item.id && (r.status == "pending" || r.status == "escalated"));
+            if item.status == "open" && !still_pending {
+                if action == "dismissed" || action == "reviewed" {
+                    restore_auto_hidden_content(ctx, &item)?;
+                }
+                ctx.db.moderation_queue_item().id().update(ModerationQueueItem {
+                    status: if action == "dismissed" { "dismissed".to_string() } else { "resolved".to_string() },
+                    resolution: action.clone(),
+                    resolved_by: caller,
+                    resolved_at: now,
+                    ..item
+                });
+            }
+        }
+    }
+
+    record_audit(
+        ctx,
+        "review_report",
+        "user_report",
+        &report_id.to_string(),
+        Some(serde_json::json!({
+            "reported_identity": report.reported_identity,
+            "reason": report.reason,
+            "status": report.status,
+        })),
+        Some(serde_json::json!({
+            "status": status,
+            "resolution": action,
+            "duration_days": duration_days,
+        })),
+    )?;
+
+    Ok(())
+}
+
+/// Whether `identity` is currently banned from `topic_id`.
+/// Expired time-limited bans that the scheduler has not lifted yet don't count.
+pub fn is_topic_banned(ctx: &ReducerContext, topic_id: u64, identity: &str) -> bool {
+    let now = now_micros(ctx);
+    ctx.db.topic_ban().iter().any(|b| {
+        b.topic_id == topic_id
+            && b.banned_identity == identity
+            && b.expires_at.map(|t| t > now).unwrap_or(true)
+    })
+}
+
+/// Guard for topic-scoped reducers (claims, comments, likes, moderator applications).
+pub fn ensure_not_topic_banned(ctx: &ReducerContext, topic_id: u64, identity: &str) -> Result<(), String> {
+    if is_topic_banned(ctx, topic_id, identity) {
+        return Err("You are banned from this topic".to_string());
+    }
+    Ok(())
+}
+
+const BAN_CONTENT_ACTIONS: &[&str] = &["keep", "hide", "remove"];
+
+/// Hide (hide = true) or restore (hide = false) a user's blocks and comments in a topic.
+/// Only touches content hidden for `reason`, so other hide reasons are left alone.
+fn set_topic_content_hidden(
+    ctx: &ReducerContext,
+    topic_id: u64,
+    identity: &str,
+    hide: bool,
+    reason: &str,
+) -> Result<(), String> {
+    let topic_block_ids: HashSet<u64> = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|b| b.topic_id == topic_id)
+        .map(|b| b.id)
+        .collect();
+
+    let blocks: Vec<Block> = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|b| b.topic_id == topic_id && b.owner_identity == identity)
+        .filter(|b| {
+            if hide {
+                b.status == "claimed" && !b.hidden
+            } else {
+                b.status == "hidden" && block_hide_reason(ctx, b.id).as_deref() == Some(reason)
+            }
         })
-        .map_err(|e| format!("Insert failed: {e}"))?;
+        .collect();
+    let block_count = blocks.len() as i64;
+    for block in blocks {
+        set_block_hide_reason(ctx, block.id, hide.then_some(reason))?;
+        ctx.db.block().id().update(Block {
+            status: if hide { "hidden".to_string() } else { "claimed".to_string() },
+            hidden: hide,
+            ..block
+        });
+    }
+
+    let comments: Vec<Comment> = ctx
+        .db
+        .comment()
+        .iter()
+        .filter(|c| c.user_identity == identity && topic_block_ids.contains(&c.block_id))
+        .filter(|c| if hide { !c.hidden } else { comment_hide_reason(ctx, c.id).as_deref() == Some(reason) })
+        .collect();
+    for comment in comments {
+        set_comment_hide_reason(ctx, comment.id, hide.then_some(reason))?;
+        ctx.db.comment().id().update(Comment { hidden: hide, ..comment });
+    }
+
+    if block_count > 0 {
+        adjust_topic_video_count(ctx, topic_id, if hide { -block_count } else { block_count })?;
+        rebalance_topic_blocks(ctx, topic_id)?;
+    }
+
+    Ok(())
+}
+
+/// Permanently delete a user's blocks and comments in a topic.
+fn remove_topic_content(ctx: &ReducerContext, topic_id: u64, identity: &str) -> Result<(), String> {
+    let topic_block_ids: HashSet<u64> = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|b| b.topic_id == topic_id)
+        .map(|b| b.id)
+        .collect();
+
+    let comments: Vec<Comment> = ctx
+        .db
+        .comment()
+        .iter()
+        .filter(|c| c.user_identity == identity && topic_block_ids.contains(&c.block_id))
+        .collect();
+    for comment in comments {
+        // A reply may already have gone with its parent.
+        if let Some(current) = ctx.db.comment().id().find(comment.id) {
+            delete_comment_cascade(ctx, &current);
+        }
+    }
+
+    let blocks: Vec<Block> = ctx
+        .db
+        .block()
+        .iter()
+        .filter(|b| b.topic_id == topic_id && b.owner_identity == identity && b.status == "claimed")
+        .collect();
+    let block_count = blocks.len() as i64;
+    for block in &blocks {
+        ctx.db.block().id().delete(block.id);
+        clear_block_hide_reason(ctx, block.id);
+        remove_block_from_counters(ctx, block);
+        crate::reducers::activity::remove_block_activity(ctx, block.id);
+    }
+
+    if block_count > 0 {
+        adjust_topic_video_count(ctx, topic_id, -block_count)?;
+        rebalance_topic_blocks(ctx, topic_id)?;
+    }
+
+    Ok(())
+}
+
+fn insert_topic_notification(
+    ctx: &ReducerContext,
+    recipient_identity: String,
+    notification_type: &str,
+    topic_id: u64,
+) {
+    let actor_identity = caller_str(ctx);
+    let actor_name = ctx
+        .db
+        .user_profile()
+        .identity()
+        .find(actor_identity.clone())
+        .map(|u| u.display_name)
+        .unwrap_or_else(|| "Moderator".to_string());
+    notify(ctx, Notification {
+        topic_id,
+        ..Notification::new(ctx, &recipient_identity, &actor_identity, &actor_name, notification_type)
+    });
+}
+
+/// Delete a ban together with any pending expiry schedule and appeals,
+/// restoring content that was hidden by it.
+fn lift_topic_ban(ctx: &ReducerContext, ban: &TopicBan) -> Result<(), String> {
+    ctx.db.topic_ban().id().delete(ban.id);
+
+    if ban.content_action.as_deref() == Some("hide") {
+        set_topic_content_hidden(ctx, ban.topic_id, &ban.banned_identity, false, "topic_ban")?;
+    }
+
+    let schedule_ids: Vec<u64> = ctx
+        .db
+        .topic_ban_expiry()
+        .iter()
+        .filter(|s| s.ban_id == ban.id)
+        .map(|s| s.scheduled_id)
+        .collect();
+    for id in schedule_ids {
+        ctx.db.topic_ban_expiry().scheduled_id().delete(id);
+    }
+
+    let pending_appeals: Vec<TopicBanAppeal> = ctx
+        .db
+        .topic_ban_appeal()
+        .iter()
+        .filter(|a| a.ban_id == ban.id && a.status == "pending")
+        .collect();
+    for appeal in pending_appeals {
+        ctx.db.topic_ban_appeal().id().update(TopicBanAppeal {
+            status: "approved".to_string(),
+            review_note: "Ban lifted".to_string(),
+            reviewed_at: now_micros(ctx),
+            ..appeal
+        });
+    }
 
     Ok(())
 }
 
 // ─── ban_user_from_topic ────────────────────────────────────────────────────
 
+/// Ban a user from a topic. `duration_hours` = 0 bans permanently; otherwise
+/// the ban is lifted automatically by `expire_topic_ban`.
+/// `content_action` decides what happens to the user's existing blocks and
+/// comments in the topic: "keep", "hide" (restored on unban) or "remove".
 #[reducer]
 pub fn ban_user_from_topic(
     ctx: &ReducerContext,
     topic_id: u64,
     target_identity: String,
     reason: String,
+    duration_hours: u64,
+    content_action: String,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     if caller == target_identity {
         return Err("Cannot ban yourself".to_string());
@@ -287,31 +1256,88 @@ pub fn ban_user_from_topic(
     let topic = ctx.db.topic().id().find(topic_id).ok_or("Topic not found")?;
 
     // Must be topic owner, moderator, or admin
-    let is_topic_owner = topic.creator_identity == caller;
-    let is_mod = ctx.db.topic_moderator().iter().any(|m| m.topic_id == topic_id && m.identity == caller && m.status == "active");
-    if !is_topic_owner && !is_mod && !is_caller_admin(ctx) {
+    if !can_moderate_topic(ctx, &caller, topic.id) {
         return Err("Not authorized — must be topic owner, moderator, or admin".to_string());
     }
 
-    // Check not already banned
+    if reason.len() > 500 {
+        return Err("Reason too long (max 500 chars)".to_string());
+    }
+
+    let content_action = if content_action.is_empty() { "keep".to_string() } else { content_action };
+    if !BAN_CONTENT_ACTIONS.contains(&content_action.as_str()) {
+        return Err(format!(
+            "Invalid content action. Must be one of: {}",
+            BAN_CONTENT_ACTIONS.join(", ")
+        ));
+    }
+
+    apply_topic_ban(ctx, topic_id, &target_identity, &reason, duration_hours, &content_action)
+}
+
+/// Insert the ban, apply its content action, schedule expiry, notify and audit.
+/// Callers have already checked moderation rights and validated the arguments.
+fn apply_topic_ban(
+    ctx: &ReducerContext,
+    topic_id: u64,
+    target_identity: &str,
+    reason: &str,
+    duration_hours: u64,
+    content_action: &str,
+) -> Result<(), String> {
     let already = ctx.db.topic_ban().iter().any(|b| b.topic_id == topic_id && b.banned_identity == target_identity);
     if already {
         return Err("User is already banned from this topic".to_string());
     }
 
-    if reason.len() > 500 {
-        return Err("Reason too long (max 500 chars)".to_string());
-    }
+    let now = now_micros(ctx);
+    let expires_at = if duration_hours == 0 {
+        None
+    } else {
+        Some(now.saturating_add(duration_hours.saturating_mul(3_600_000_000)))
+    };
 
-    ctx.db.topic_ban().try_insert(TopicBan {
+    let ban = ctx.db.topic_ban().try_insert(TopicBan {
         id: 0,
         topic_id,
-        banned_identity: target_identity,
-        banned_by: caller,
-        reason,
-        created_at: now_micros(ctx),
+        banned_identity: target_identity.to_string(),
+        banned_by: caller_str(ctx),
+        reason: reason.to_string(),
+        created_at: now,
+        expires_at,
+        content_action: Some(content_action.to_string()),
     }).map_err(|e| format!("Ban insert failed: {e}"))?;
 
+    match content_action {
+        "hide" => set_topic_content_hidden(ctx, topic_id, target_identity, true, "topic_ban")?,
+        "remove" => remove_topic_content(ctx, topic_id, target_identity)?,
+        _ => {}
+    }
+
+    if let Some(expires_at) = expires_at {
+        ctx.db.topic_ban_expiry().try_insert(TopicBanExpiry {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(Timestamp::from_micros_since_unix_epoch(expires_at as i64)),
+            ban_id: ban.id,
+        }).map_err(|e| format!("Ban expiry schedule failed: {e}"))?;
+    }
+
+    insert_topic_notification(ctx, target_identity.to_string(), "topic_ban", topic_id);
+
+    record_audit(
+        ctx,
+        "ban_user_from_topic",
+        "user",
+        target_identity,
+        None,
+        Some(serde_json::json!({
+            "topic_id": topic_id,
+            "reason": reason,
+            "expires_at": expires_at,
+            "content_action": content_action,
+        })),
+    )?;
+
     Ok(())
 }
 
@@ -324,12 +1350,11 @@ pub fn unban_user_from_topic(
     target_identity: String,
 ) -> Result<(), String> {
     let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let topic = ctx.db.topic().id().find(topic_id).ok_or("Topic not found")?;
 
-    let is_topic_owner = topic.creator_identity == caller;
-    let is_mod = ctx.db.topic_moderator().iter().any(|m| m.topic_id == topic_id && m.identity == caller && m.status == "active");
-    if !is_topic_owner && !is_mod && !is_caller_admin(ctx) {
+    if !can_moderate_topic(ctx, &caller, topic.id) {
         return Err("Not authorized".to_string());
     }
 
@@ -337,7 +1362,153 @@ pub fn unban_user_from_topic(
         .find(|b| b.topic_id == topic_id && b.banned_identity == target_identity)
         .ok_or("User is not banned from this topic")?;
 
-    ctx.db.topic_ban().id().delete(ban.id);
+    lift_topic_ban(ctx, &ban)?;
+    insert_topic_notification(ctx, target_identity.clone(), "topic_ban_lifted", topic_id);
+
+    record_audit(
+        ctx,
+        "unban_user_from_topic",
+        "user",
+        &target_identity,
+        Some(serde_json::json!({
+            "topic_id": topic_id,
+            "banned_by": ban.banned_by,
+            "reason": ban.reason,
+            "expires_at": ban.expires_at,
+        })),
+        None,
+    )?;
+
+    Ok(())
+}
+
+// ─── expire_topic_ban (scheduled) ───────────────────────────────────────────
+
+/// Lifts a time-limited ban once its `expires_at` has passed.
+#[reducer]
+pub fn expire_topic_ban(ctx: &ReducerContext, schedule: TopicBanExpiry) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("expire_topic_ban may only be invoked by the scheduler".to_string());
+    }
+
+    let Some(ban) = ctx.db.topic_ban().id().find(schedule.ban_id) else {
+        // Already lifted manually or through an appeal.
+        return Ok(());
+    };
+
+    let now = now_micros(ctx);
+    if ban.expires_at.map(|t| t > now).unwrap_or(true) {
+        // Ban was made permanent or extended since this schedule was created.
+        return Ok(());
+    }
+
+    lift_topic_ban(ctx, &ban)?;
+    notify(ctx, Notification {
+        topic_id: ban.topic_id,
+        ..Notification::new(ctx, &ban.banned_identity, "", "System", "topic_ban_lifted")
+    });
+
+    log::info!("Topic ban {} expired (topic {})", ban.id, ban.topic_id);
+    Ok(())
+}
+
+// ─── submit_ban_appeal ──────────────────────────────────────────────────────
+
+#[reducer]
+pub fn submit_ban_appeal(
+    ctx: &ReducerContext,
+    topic_id: u64,
+    message: String,
+) -> Result<(), String> {
+    let caller = caller_str(ctx);
+
+    let ban = ctx.db.topic_ban().iter()
+        .find(|b| b.topic_id == topic_id && b.banned_identity == caller)
+        .ok_or("You are not banned from this topic")?;
+
+    let trimmed = message.trim().to_string();
+    if trimmed.is_empty() {
+        return Err("Appeal message cannot be empty".to_string());
+    }
+    if trimmed.len() > 1000 {
+        return Err("Appeal message too long (max 1000 chars)".to_string());
+    }
+
+    let has_appeal = ctx.db.topic_ban_appeal().iter().any(|a| {
+        a.ban_id == ban.id && (a.status == "pending" || a.status == "rejected")
+    });
+    if has_appeal {
+        return Err("You have already appealed this ban".to_string());
+    }
+
+    ctx.db.topic_ban_appeal().try_insert(TopicBanAppeal {
+        id: 0,
+        ban_id: ban.id,
+        topic_id,
+        appellant_identity: caller,
+        message: trimmed,
+        status: "pending".to_string(),
+        reviewed_by: String::new(),
+        review_note: String::new(),
+        created_at: now_micros(ctx),
+        reviewed_at: 0,
+    }).map_err(|e| format!("Appeal insert failed: {e}"))?;
+
+    Ok(())
+}
+
+// ─── review_ban_appeal (topic owner / admin) ────────────────────────────────
+
+#[reducer]
+pub fn review_ban_appeal(
+    ctx: &ReducerContext,
+    appeal_id: u64,
+    approve: bool,
+    review_note: String,
+) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
+
+    let appeal = ctx.db.topic_ban_appeal().id().find(appeal_id).ok_or("Appeal not found")?;
+    if appeal.status != "pending" {
+        return Err("Appeal has already been reviewed".to_string());
+    }
+
+    let topic = ctx.db.topic().id().find(appeal.topic_id).ok_or("Topic not found")?;
+    if topic.creator_identity != caller && !is_caller_admin(ctx) {
+        return Err("Only the topic owner or an admin can review ban appeals".to_string());
+    }
+
+    if review_note.len() > 500 {
+        return Err("Review note too long (max 500 chars)".to_string());
+    }
+
+    let status = if approve { "approved" } else { "rejected" };
+    ctx.db.topic_ban_appeal().id().update(TopicBanAppeal {
+        status: status.to_string(),
+        reviewed_by: caller,
+        review_note,
+        reviewed_at: now_micros(ctx),
+        ..appeal.clone()
+    });
+
+    if approve {
+        if let Some(ban) = ctx.db.topic_ban().id().find(appeal.ban_id) {
+            lift_topic_ban(ctx, &ban)?;
+        }
+    }
+
+    let notification_type = if approve { "ban_appeal_approved" } else { "ban_appeal_rejected" };
+    insert_topic_notification(ctx, appeal.appellant_identity.clone(), notification_type, appeal.topic_id);
+
+    record_audit(
+        ctx,
+        "review_ban_appeal",
+        "user",
+        &appeal.appellant_identity,
+        Some(serde_json::json!({ "appeal_id": appeal_id, "topic_id": appeal.topic_id, "status": "pending" })),
+        Some(serde_json::json!({ "appeal_id": appeal_id, "status": status })),
+    )?;
 
     Ok(())
 }
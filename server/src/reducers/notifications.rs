@@ -0,0 +1,544 @@
+use spacetimedb::{reducer, ReducerContext, ScheduleAt, Table};
+use crate::tables::*;
+use crate::reducers::audit::record_audit;
+use crate::reducers::moderation::{is_blocked, is_muted};
+use crate::reducers::sanctions::is_shadow_banned;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Notification types users can switch off or move to the digest. Moderation
+/// notices (bans, report outcomes, warnings) are always delivered live.
+pub const CONFIGURABLE_TYPES: &[&str] = &[
+    "comment_reply",
+    "comment_like",
+    "comment_repost",
+    "video_like",
+    "new_follow",
+    "follow_request",
+    "follow_request_approved",
+    "new_message",
+    "message_request",
+    "new_video_in_topic",
+    "topic_announcement",
+];
+
+const MAX_LATEST_ACTORS: usize = 3;
+const DAY_MICROS: u64 = 86_400_000_000;
+const DIGEST_INTERVAL_HOURS: u64 = 24;
+
+fn now_micros(ctx: &ReducerContext) -> u64 {
+    ctx.timestamp.to_micros_since_unix_epoch() as u64
+}
+
+fn caller_str(ctx: &ReducerContext) -> String {
+    ctx.sender().to_hex().to_string()
+}
+
+fn is_caller_admin(ctx: &ReducerContext) -> bool {
+    let identity = caller_str(ctx);
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(identity)
+        .map(|u| u.is_admin)
+        .unwrap_or(false)
+}
+
+fn find_preference(ctx: &ReducerContext, identity: &str, notification_type: &str) -> Option<NotificationPreference> {
+    ctx.db
+        .notification_preference()
+        .identity()
+        .filter(identity)
+        .find(|p| p.notification_type == notification_type)
+}
+
+/// Write the default preference rows for a new user.
+pub fn create_default_preferences(ctx: &ReducerContext, identity: &str) -> Result<(), String> {
+    let now = now_micros(ctx);
+    for notification_type in CONFIGURABLE_TYPES {
+        if find_preference(ctx, identity, notification_type).is_some() {
+            continue;
+        }
+        ctx.db
+            .notification_preference()
+            .try_insert(NotificationPreference {
+                id: 0,
+                identity: identity.to_string(),
+                notification_type: notification_type.to_string(),
+                in_app: true,
+                digest_only: false,
+                updated_at: now,
+            })
+            .map_err(|e| format!("Preference insert failed: {e}"))?;
+    }
+    Ok(())
+}
+
+impl Notification {
+    /// An unread notification from `actor` with no target, stamped now. Set
+    /// block_id / comment_id / topic_id / announcement_id / conversation_id with
+    /// struct update syntax; notify fills in the grouping fields.
+    pub fn new(
+        ctx: &ReducerContext,
+        recipient: &str,
+        actor: &str,
+        actor_name: &str,
+        notification_type: &str,
+    ) -> Self {
+        let now = now_micros(ctx);
+        Notification {
+            id: 0,
+            recipient_identity: recipient.to_string(),
+            actor_identity: actor.to_string(),
+            actor_name: actor_name.to_string(),
+            notification_type: notification_type.to_string(),
+            block_id: 0,
+            comment_id: 0,
+            is_read: false,
+            created_at: now,
+            topic_id: 0,
+            actor_muted: false,
+            digest: false,
+            actor_count: 1,
+            latest_actors: None,
+            updated_at: now,
+            announcement_id: 0,
+            conversation_id: 0,
+        }
+    }
+}
+
+/// Insert a notification triggered by another user's activity (replies, likes,
+/// reposts, follows, messages). Dropped when the actor is the recipient, is
+/// shadow-banned, is muted by the recipient, or either has blocked the other.
+pub fn notify_activity(ctx: &ReducerContext, notification: Notification) {
+    let recipient = notification.recipient_identity.as_str();
+    let actor = notification.actor_identity.as_str();
+
+    if recipient == actor
+        || is_shadow_banned(ctx, actor)
+        || is_muted(ctx, recipient, actor)
+        || is_blocked(ctx, recipient, actor)
+    {
+        return;
+    }
+
+    notify(ctx, notification);
+}
+
+/// Insert a notification, honouring the recipient's preference for its type.
+/// Used directly for moderation / system notices, which ignore blocks and mutes.
+pub fn notify(ctx: &ReducerContext, notification: Notification) {
+    if let Err(e) = ensure_prune_schedule(ctx) {
+        log::warn!("Could not start notification pruning: {e}");
+    }
+
+    let preference = find_preference(ctx, &notification.recipient_identity, &notification.notification_type);
+    let digest = match preference {
+        Some(p) if p.digest_only => true,
+        Some(p) if !p.in_app => return,
+        _ => false,
+    };
+
+    if CONFIGURABLE_TYPES.contains(&notification.notification_type.as_str()) {
+        if let Some(group) = find_open_group(ctx, &notification, digest) {
+            add_to_group(ctx, group, notification);
+            return;
+        }
+    }
+
+    let actor = notification.actor_identity.clone();
+    let latest_actors = (!actor.is_empty()).then(|| serde_json::json!([actor]).to_string());
+    let Ok(row) = ctx.db.notification().try_insert(Notification {
+        digest,
+        actor_count: 1,
+        latest_actors,
+        updated_at: notification.created_at,
+        ..notification
+    }) else {
+        return;
+    };
+
+    if !actor.is_empty() {
+        let _ = ctx.db.notification_actor().try_insert(NotificationActor {
+            id: 0,
+            notification_id: row.id,
+            actor_identity: actor,
+        });
+    }
+}
+
+/// Follow notifications are about one follower each, so they never collapse
+/// across actors.
+fn groups_per_actor(notification_type: &str) -> bool {
+    matches!(notification_type, "new_follow" | "follow_request" | "follow_request_approved")
+}
+
+/// The recipient's unread group for the same type and target, if any.
+fn find_open_group(ctx: &ReducerContext, notification: &Notification, digest: bool) -> Option<Notification> {
+    let per_actor = groups_per_actor(&notification.notification_type);
+    ctx.db
+        .notification()
+        .recipient_identity()
+        .filter(notification.recipient_identity.as_str())
+        .find(|n| {
+            !n.is_read
+                && n.digest == digest
+                && n.notification_type == notification.notification_type
+                && n.block_id == notification.block_id
+                && n.comment_id == notification.comment_id
+                && n.topic_id == notification.topic_id
+                && n.announcement_id == notification.announcement_id
+                && n.conversation_id == notification.conversation_id
+                && (!per_actor || n.actor_identity == notification.actor_identity)
+        })
+}
+
+/// Fold a new event into an unread group: bump it to the top with the new actor
+/// as the latest, counting the actor once no matter how many events they cause.
+fn add_to_group(ctx: &ReducerContext, group: Notification, event: Notification) {
+    let actor = event.actor_identity.clone();
+    let seen = ctx
+        .db
+        .notification_actor()
+        .notification_id()
+        .filter(group.id)
+        .any(|a| a.actor_identity == actor);
+    if !seen {
+        let _ = ctx.db.notification_actor().try_insert(NotificationActor {
+            id: 0,
+            notification_id: group.id,
+            actor_identity: actor.clone(),
+        });
+    }
+
+    let mut latest: Vec<String> = group
+        .latest_actors
+        .as_deref()
+        .and_then(|s| serde_json::from_str(s).ok())
+        .unwrap_or_default();
+    latest.retain(|a| a != &actor);
+    latest.insert(0, actor.clone());
+    latest.truncate(MAX_LATEST_ACTORS);
+
+    ctx.db.notification().id().update(Notification {
+        actor_identity: actor,
+        actor_name: event.actor_name,
+        actor_count: if seen { group.actor_count } else { group.actor_count + 1 },
+        latest_actors: Some(serde_json::json!(latest).to_string()),
+        updated_at: event.created_at,
+        ..group
+    });
+}
+
+/// Delete notifications together with their grouped-actor bookkeeping.
+pub fn delete_notifications(ctx: &ReducerContext, notification_ids: &[u64]) {
+    for &id in notification_ids {
+        ctx.db.notification_actor().notification_id().delete(id);
+        ctx.db.notification().id().delete(id);
+    }
+}
+
+// ─── Retention ───────────────────────────────────────────────────────────────
+
+fn default_retention() -> NotificationRetentionConfig {
+    NotificationRetentionConfig {
+        id: 0,
+        read_retention_days: 30,
+        max_unread_per_user: 500,
+        prune_interval_hours: 24,
+        updated_by: String::new(),
+        updated_at: 0,
+    }
+}
+
+fn retention_config(ctx: &ReducerContext) -> NotificationRetentionConfig {
+    ctx.db
+        .notification_retention_config()
+        .id()
+        .find(0)
+        .unwrap_or_else(default_retention)
+}
+
+/// Start the repeating prune if it isn't running. Called from init and from
+/// notify, so databases published before the schedule existed pick it up too.
+pub fn ensure_prune_schedule(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.notification_prune_schedule().iter().next().is_some() {
+        return Ok(());
+    }
+    reset_prune_schedule(ctx)
+}
+
+/// (Re)create the repeating prune schedule at the configured interval. Called
+/// whenever the retention settings change.
+fn reset_prune_schedule(ctx: &ReducerContext) -> Result<(), String> {
+    let existing: Vec<u64> = ctx
+        .db
+        .notification_prune_schedule()
+        .iter()
+        .map(|s| s.scheduled_id)
+        .collect();
+    for id in existing {
+        ctx.db.notification_prune_schedule().scheduled_id().delete(id);
+    }
+
+    let hours = retention_config(ctx).prune_interval_hours;
+    ctx.db
+        .notification_prune_schedule()
+        .try_insert(NotificationPruneSchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(Duration::from_secs(hours * 3600).into()),
+        })
+        .map_err(|e| format!("Schedule insert failed: {e}"))?;
+    Ok(())
+}
+
+#[reducer]
+pub fn prune_notifications(ctx: &ReducerContext, _schedule: NotificationPruneSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("prune_notifications may only be invoked by the scheduler".to_string());
+    }
+
+    let config = retention_config(ctx);
+    let now = now_micros(ctx);
+    let read_cutoff = now.saturating_sub(config.read_retention_days.saturating_mul(DAY_MICROS));
+
+    // Grouped rows keep their first event's created_at, so age is measured
+    // from the latest event.
+    let mut to_delete: Vec<u64> = Vec::new();
+    let mut unread_by_user: HashMap<String, Vec<(u64, u64)>> = HashMap::new();
+    for n in ctx.db.notification().iter() {
+        let last_event = n.updated_at.max(n.created_at);
+        if n.is_read {
+            if last_event < read_cutoff {
+                to_delete.push(n.id);
+            }
+        } else {
+            unread_by_user
+                .entry(n.recipient_identity)
+                .or_default()
+                .push((last_event, n.id));
+        }
+    }
+    let read_pruned = to_delete.len() as u64;
+
+    let cap = config.max_unread_per_user as usize;
+    let mut users_capped = 0u64;
+    for unread in unread_by_user.values_mut() {
+        if unread.len() <= cap {
+            continue;
+        }
+        users_capped += 1;
+        unread.sort_unstable_by(|a, b| b.cmp(a));
+        to_delete.extend(unread[cap..].iter().map(|&(_, id)| id));
+    }
+    let unread_pruned = to_delete.len() as u64 - read_pruned;
+
+    delete_notifications(ctx, &to_delete);
+
+    ctx.db
+        .notification_prune_log()
+        .try_insert(NotificationPruneLog {
+            id: 0,
+            ran_at: now,
+            read_cutoff,
+            read_pruned,
+            unread_pruned,
+            users_capped,
+        })
+        .map_err(|e| format!("Insert failed: {e}"))?;
+
+    log::info!(
+        "Pruned {} read and {} unread notifications ({} users over the unread cap)",
+        read_pruned,
+        unread_pruned,
+        users_capped
+    );
+
+    Ok(())
+}
+
+// ─── Digest delivery ─────────────────────────────────────────────────────────
+
+/// Start the daily digest if it isn't running. Called when a user turns on
+/// digest_only for any type.
+pub fn ensure_digest_schedule(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.notification_digest_schedule().iter().next().is_some() {
+        return Ok(());
+    }
+    ctx.db
+        .notification_digest_schedule()
+        .try_insert(NotificationDigestSchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(Duration::from_secs(DIGEST_INTERVAL_HOURS * 3600).into()),
+        })
+        .map_err(|e| format!("Schedule insert failed: {e}"))?;
+    Ok(())
+}
+
+/// Announce each recipient's unread digest notifications with one live
+/// "notification_digest" notice and mark them read. An unread notice from a
+/// previous run is topped up rather than duplicated.
+#[reducer]
+pub fn deliver_notification_digests(ctx: &ReducerContext, _schedule: NotificationDigestSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("deliver_notification_digests may only be invoked by the scheduler".to_string());
+    }
+
+    let pending: Vec<Notification> = ctx.db.notification().iter().filter(|n| n.digest && !n.is_read).collect();
+    let mut count_by_user: HashMap<String, u64> = HashMap::new();
+    for n in pending {
+        *count_by_user.entry(n.recipient_identity.clone()).or_default() += 1;
+        ctx.db.notification().id().update(Notification { is_read: true, ..n });
+    }
+
+    let now = now_micros(ctx);
+    for (recipient, count) in count_by_user {
+        let open = ctx
+            .db
+            .notification()
+            .recipient_identity()
+            .filter(recipient.as_str())
+            .find(|n| !n.is_read && n.notification_type == "notification_digest");
+        match open {
+            Some(notice) => {
+                ctx.db.notification().id().update(Notification {
+                    actor_count: notice.actor_count + count,
+                    updated_at: now,
+                    ..notice
+                });
+            }
+            None => {
+                ctx.db
+                    .notification()
+                    .try_insert(Notification {
+                        actor_count: count,
+                        ..Notification::new(ctx, &recipient, "", "", "notification_digest")
+                    })
+                    .map_err(|e| format!("Insert failed: {e}"))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// ─── set_notification_retention (admin) ─────────────────────────────────────
+
+#[reducer]
+pub fn set_notification_retention(
+    ctx: &ReducerContext,
+    read_retention_days: u64,
+    max_unread_per_user: u64,
+    prune_interval_hours: u64,
+) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can configure notification retention".to_string());
+    }
+
+    if read_retention_days == 0 {
+        return Err("Read notifications must be kept for at least 1 day".to_string());
+    }
+    if max_unread_per_user == 0 {
+        return Err("Unread cap must be greater than 0".to_string());
+    }
+    if !(1..=168).contains(&prune_interval_hours) {
+        return Err("Prune interval must be between 1 and 168 hours".to_string());
+    }
+
+    let before = retention_config(ctx);
+    let after = NotificationRetentionConfig {
+        id: 0,
+        read_retention_days,
+        max_unread_per_user,
+        prune_interval_hours,
+        updated_by: caller_str(ctx),
+        updated_at: now_micros(ctx),
+    };
+
+    if ctx.db.notification_retention_config().id().find(0).is_some() {
+        ctx.db.notification_retention_config().id().update(after);
+    } else {
+        ctx.db
+            .notification_retention_config()
+            .try_insert(after)
+            .map_err(|e| format!("Insert failed: {e}"))?;
+    }
+
+    reset_prune_schedule(ctx)?;
+
+    record_audit(
+        ctx,
+        "set_notification_retention",
+        "notification_retention_config",
+        "0",
+        Some(serde_json::json!({
+            "read_retention_days": before.read_retention_days,
+            "max_unread_per_user": before.max_unread_per_user,
+            "prune_interval_hours": before.prune_interval_hours,
+        })),
+        Some(serde_json::json!({
+            "read_retention_days": read_retention_days,
+            "max_unread_per_user": max_unread_per_user,
+            "prune_interval_hours": prune_interval_hours,
+        })),
+    )?;
+
+    Ok(())
+}
+
+// ─── set_notification_preference ─────────────────────────────────────────────
+
+#[reducer]
+pub fn set_notification_preference(
+    ctx: &ReducerContext,
+    notification_type: String,
+    in_app: bool,
+    digest_only: bool,
+) -> Result<(), String> {
+    let caller = caller_str(ctx);
+
+    if !CONFIGURABLE_TYPES.contains(&notification_type.as_str()) {
+        return Err(format!(
+            "Notification type can't be configured. Must be one of: {}",
+            CONFIGURABLE_TYPES.join(", ")
+        ));
+    }
+
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(caller.clone())
+        .ok_or("User not found")?;
+
+    let now = now_micros(ctx);
+    match find_preference(ctx, &caller, &notification_type) {
+        Some(existing) => {
+            ctx.db.notification_preference().id().update(NotificationPreference {
+                in_app,
+                digest_only,
+                updated_at: now,
+                ..existing
+            });
+        }
+        None => {
+            ctx.db
+                .notification_preference()
+                .try_insert(NotificationPreference {
+                    id: 0,
+                    identity: caller,
+                    notification_type,
+                    in_app,
+                    digest_only,
+                    updated_at: now,
+                })
+                .map_err(|e| format!("Insert failed: {e}"))?;
+        }
+    }
+
+    if digest_only {
+        ensure_digest_schedule(ctx)?;
+    }
+
+    Ok(())
+}
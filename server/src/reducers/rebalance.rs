@@ -1,6 +1,6 @@
-use spacetimedb::{reducer, ReducerContext, Table};
+use spacetimedb::{reducer, ReducerContext};
 use crate::tables::*;
-use crate::reducers::topic::{spiral_coords, block_score};
+use crate::reducers::claim::{ranked_topic_blocks, reposition_blocks};
 
 /// Re-sort claimed blocks within a topic by combined score
 /// (YouTube metrics + platform likes/dislikes), reassigning their spiral
@@ -16,27 +16,10 @@ pub fn rebalance_topic(ctx: &ReducerContext, topic_id: u64, batch_size: u32) ->
         return Err("Only admins can trigger rebalance".to_string());
     }
 
-    let mut claimed_blocks: Vec<Block> = ctx
-        .db
-        .block()
-        .iter()
-        .filter(|b| b.topic_id == topic_id && b.status == "claimed")
-        .collect();
+    let ranked = ranked_topic_blocks(ctx, topic_id);
 
-    claimed_blocks.sort_by(|a, b| block_score(b).cmp(&block_score(a)));
-
-    let limit = (batch_size as usize).min(claimed_blocks.len());
-    for (i, block) in claimed_blocks.iter().take(limit).enumerate() {
-        let (new_x, new_y) = spiral_coords(i as u64);
-        if block.x != new_x || block.y != new_y {
-            ctx.db.block().id().delete(block.id);
-            ctx.db.block().try_insert(Block {
-                x: new_x,
-                y: new_y,
-                ..block.clone()
-            }).map_err(|e| format!("Insert failed: {e}"))?;
-        }
-    }
+    let limit = (batch_size as usize).min(ranked.len());
+    reposition_blocks(ctx, &ranked, limit)?;
 
     log::info!("Rebalanced {} blocks in topic {}", limit, topic_id);
     Ok(())
@@ -0,0 +1,366 @@
+use spacetimedb::{reducer, ReducerContext, ScheduleAt, Table, Timestamp};
+use crate::tables::*;
+use crate::reducers::audit::record_audit;
+use crate::reducers::moderation::{set_block_hide_reason, set_comment_hide_reason};
+
+const SANCTION_TYPES: &[&str] = &["suspension", "shadow_ban"];
+
+fn now_micros(ctx: &ReducerContext) -> u64 {
+    ctx.timestamp.to_micros_since_unix_epoch() as u64
+}
+
+fn caller_str(ctx: &ReducerContext) -> String {
+    ctx.sender().to_hex().to_string()
+}
+
+fn is_caller_admin(ctx: &ReducerContext) -> bool {
+    let identity = caller_str(ctx);
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(identity)
+        .map(|u| u.is_admin)
+        .unwrap_or(false)
+}
+
+fn has_active_sanction(ctx: &ReducerContext, identity: &str, sanction_type: &str) -> bool {
+    let now = now_micros(ctx);
+    ctx.db.user_sanction().identity().filter(identity).any(|s| {
+        s.sanction_type == sanction_type
+            && s.status == "active"
+            && s.expires_at.map(|t| t > now).unwrap_or(true)
+    })
+}
+
+pub fn is_suspended(ctx: &ReducerContext, identity: &str) -> bool {
+    has_active_sanction(ctx, identity, "suspension")
+}
+
+pub fn is_shadow_banned(ctx: &ReducerContext, identity: &str) -> bool {
+    has_active_sanction(ctx, identity, "shadow_ban")
+}
+
+/// Guard for write reducers. Safety actions (block, mute, report, appeals),
+/// undoing your own interactions and deleting your own content stay available.
+pub fn ensure_not_suspended(ctx: &ReducerContext) -> Result<(), String> {
+    if is_suspended(ctx, &caller_str(ctx)) {
+        return Err("Your account is suspended".to_string());
+    }
+    Ok(())
+}
+
+/// Private hide reason for new content from the caller: "shadow_ban" if they are
+/// shadow-banned, otherwise None (visible). The public row only gets `hidden = true`.
+pub fn new_content_hide_reason(ctx: &ReducerContext) -> Option<&'static str> {
+    if is_shadow_banned(ctx, &caller_str(ctx)) {
+        Some("shadow_ban")
+    } else {
+        None
+    }
+}
+
+/// Make content hidden by a shadow ban visible again.
+fn reveal_shadow_banned_content(ctx: &ReducerContext, identity: &str) -> Result<(), String> {
+    let hides: Vec<ContentHide> = ctx
+        .db
+        .content_hide()
+        .iter()
+        .filter(|h| h.reason == "shadow_ban")
+        .collect();
+    for hide in hides {
+        if hide.comment_id != 0 {
+            let Some(comment) = ctx.db.comment().id().find(hide.comment_id) else {
+                continue;
+            };
+            if comment.user_identity == identity {
+                set_comment_hide_reason(ctx, comment.id, None)?;
+                ctx.db.comment().id().update(Comment {
+                    hidden: false,
+                    ..comment
+                });
+            }
+        } else if let Some(block) = ctx.db.block().id().find(hide.block_id) {
+            if block.owner_identity == identity {
+                set_block_hide_reason(ctx, block.id, None)?;
+                ctx.db.block().id().update(Block {
+                    hidden: false,
+                    ..block
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Issue a sanction against `identity`. `until` = 0 means indefinite.
+/// Public so report resolution can apply sanctions in the same transaction.
+pub fn issue_user_sanction(
+    ctx: &ReducerContext,
+    identity: &str,
+    sanction_type: &str,
+    until: u64,
+    reason: &str,
+) -> Result<UserSanction, String> {
+    if !SANCTION_TYPES.contains(&sanction_type) {
+        return Err(format!(
+            "Invalid sanction type. Must be one of: {}",
+            SANCTION_TYPES.join(", ")
+        ));
+    }
+
+    let caller = caller_str(ctx);
+    if caller == identity {
+        return Err("Cannot sanction yourself".to_string());
+    }
+
+    let target = ctx
+        .db
+        .user_profile()
+        .identity()
+        .find(identity.to_string())
+        .ok_or("User not found")?;
+    if target.is_admin {
+        return Err("Cannot sanction an admin".to_string());
+    }
+
+    if reason.len() > 500 {
+        return Err("Reason too long (max 500 chars)".to_string());
+    }
+
+    let now = now_micros(ctx);
+    if until != 0 && until <= now {
+        return Err("Sanction end must be in the future".to_string());
+    }
+    if has_active_sanction(ctx, identity, sanction_type) {
+        return Err("User already has an active sanction of this type".to_string());
+    }
+
+    let expires_at = if until == 0 { None } else { Some(until) };
+    let sanction = ctx
+        .db
+        .user_sanction()
+        .try_insert(UserSanction {
+            id: 0,
+            identity: identity.to_string(),
+            sanction_type: sanction_type.to_string(),
+            reason: reason.to_string(),
+            issued_by: caller,
+            status: "active".to_string(),
+            created_at: now,
+            expires_at,
+            lifted_by: String::new(),
+            lifted_at: 0,
+        })
+        .map_err(|e| format!("Sanction insert failed: {e}"))?;
+
+    if let Some(expires_at) = expires_at {
+        ctx.db
+            .user_sanction_expiry()
+            .try_insert(UserSanctionExpiry {
+                scheduled_id: 0,
+                scheduled_at: ScheduleAt::Time(Timestamp::from_micros_since_unix_epoch(expires_at as i64)),
+                sanction_id: sanction.id,
+            })
+            .map_err(|e| format!("Sanction expiry schedule failed: {e}"))?;
+    }
+
+    record_audit(
+        ctx,
+        if sanction_type == "suspension" { "suspend_user" } else { "shadow_ban_user" },
+        "user",
+        identity,
+        None,
+        Some(serde_json::json!({
+            "sanction_id": sanction.id,
+            "sanction_type": sanction_type,
+            "reason": reason,
+            "expires_at": expires_at,
+        })),
+    )?;
+
+    Ok(sanction)
+}
+
+/// Strike counts at which a user is automatically suspended, with the
+/// suspension length in days (0 = indefinite).
+const STRIKE_ESCALATION: &[(u64, u64)] = &[(3, 7), (5, 30), (7, 0)];
+
+pub fn strike_count(ctx: &ReducerContext, identity: &str) -> u64 {
+    ctx.db.user_strike().iter().filter(|s| s.identity == identity).count() as u64
+}
+
+/// Record a strike for an upheld report and suspend the user automatically
+/// when the new count reaches a STRIKE_ESCALATION step. Returns the new count.
+pub fn add_strike(
+    ctx: &ReducerContext,
+    identity: &str,
+    report_id: u64,
+    action: &str,
+) -> Result<u64, String> {
+    let now = now_micros(ctx);
+    ctx.db
+        .user_strike()
+        .try_insert(UserStrike {
+            id: 0,
+            identity: identity.to_string(),
+            report_id,
+            action: action.to_string(),
+            issued_by: caller_str(ctx),
+            created_at: now,
+        })
+        .map_err(|e| format!("Strike insert failed: {e}"))?;
+
+    let count = strike_count(ctx, identity);
+
+    let Some(&(_, days)) = STRIKE_ESCALATION.iter().find(|(strikes, _)| *strikes == count) else {
+        return Ok(count);
+    };
+    // Content can outlive its author's profile; the strike still stands, but
+    // there is no account left to suspend.
+    let Some(profile) = ctx.db.user_profile().identity().find(identity.to_string()) else {
+        return Ok(count);
+    };
+    if profile.is_admin || is_suspended(ctx, identity) {
+        return Ok(count);
+    }
+
+    let until = if days == 0 { 0 } else { now + days * 24 * 60 * 60 * 1_000_000 };
+    issue_user_sanction(
+        ctx,
+        identity,
+        "suspension",
+        until,
+        &format!("Automatic suspension after {count} strikes"),
+    )?;
+
+    Ok(count)
+}
+
+/// Mark a sanction as ended ("expired" or "lifted") and undo its visibility effects.
+fn end_user_sanction(ctx: &ReducerContext, sanction: UserSanction, status: &str, lifted_by: String) -> Result<(), String> {
+    let schedule_ids: Vec<u64> = ctx
+        .db
+        .user_sanction_expiry()
+        .iter()
+        .filter(|s| s.sanction_id == sanction.id)
+        .map(|s| s.scheduled_id)
+        .collect();
+    for id in schedule_ids {
+        ctx.db.user_sanction_expiry().scheduled_id().delete(id);
+    }
+
+    let identity = sanction.identity.clone();
+    let was_shadow_ban = sanction.sanction_type == "shadow_ban";
+
+    ctx.db.user_sanction().id().update(UserSanction {
+        status: status.to_string(),
+        lifted_by,
+        lifted_at: now_micros(ctx),
+        ..sanction
+    });
+
+    if was_shadow_ban && !is_shadow_banned(ctx, &identity) {
+        reveal_shadow_banned_content(ctx, &identity)?;
+    }
+    Ok(())
+}
+
+// ─── suspend_user (admin) ────────────────────────────────────────────────────
+
+/// Suspend a user site-wide until `until` (microseconds; 0 = indefinite).
+#[reducer]
+pub fn suspend_user(
+    ctx: &ReducerContext,
+    identity: String,
+    until: u64,
+    reason: String,
+) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can suspend users".to_string());
+    }
+
+    issue_user_sanction(ctx, &identity, "suspension", until, &reason)?;
+    Ok(())
+}
+
+// ─── shadow_ban_user (admin) ─────────────────────────────────────────────────
+
+/// Shadow-ban a user until `until` (microseconds; 0 = indefinite).
+/// Their new comments and blocks are hidden from everyone else (hide reason "shadow_ban").
+#[reducer]
+pub fn shadow_ban_user(
+    ctx: &ReducerContext,
+    identity: String,
+    until: u64,
+    reason: String,
+) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can shadow-ban users".to_string());
+    }
+
+    issue_user_sanction(ctx, &identity, "shadow_ban", until, &reason)?;
+    Ok(())
+}
+
+// ─── lift_user_sanction (admin) ──────────────────────────────────────────────
+
+#[reducer]
+pub fn lift_user_sanction(ctx: &ReducerContext, sanction_id: u64) -> Result<(), String> {
+    if !is_caller_admin(ctx) {
+        return Err("Only admins can lift sanctions".to_string());
+    }
+
+    let sanction = ctx
+        .db
+        .user_sanction()
+        .id()
+        .find(sanction_id)
+        .ok_or("Sanction not found")?;
+
+    if sanction.status != "active" {
+        return Err("Sanction is no longer active".to_string());
+    }
+
+    let before = serde_json::json!({
+        "sanction_type": sanction.sanction_type,
+        "status": sanction.status,
+        "expires_at": sanction.expires_at,
+    });
+    let identity = sanction.identity.clone();
+
+    end_user_sanction(ctx, sanction, "lifted", caller_str(ctx))?;
+
+    record_audit(
+        ctx,
+        "lift_user_sanction",
+        "user",
+        &identity,
+        Some(before),
+        Some(serde_json::json!({ "sanction_id": sanction_id, "status": "lifted" })),
+    )?;
+
+    Ok(())
+}
+
+// ─── expire_user_sanction (scheduled) ────────────────────────────────────────
+
+/// Ends a time-limited sanction once its `expires_at` has passed.
+#[reducer]
+pub fn expire_user_sanction(ctx: &ReducerContext, schedule: UserSanctionExpiry) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("expire_user_sanction may only be invoked by the scheduler".to_string());
+    }
+
+    let Some(sanction) = ctx.db.user_sanction().id().find(schedule.sanction_id) else {
+        return Ok(());
+    };
+
+    let now = now_micros(ctx);
+    if sanction.status != "active" || sanction.expires_at.map(|t| t > now).unwrap_or(true) {
+        return Ok(());
+    }
+
+    log::info!("User sanction {} ({}) expired", sanction.id, sanction.sanction_type);
+    end_user_sanction(ctx, sanction, "expired", String::new())?;
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use spacetimedb::{reducer, ReducerContext, Table};
 use crate::tables::*;
+use crate::reducers::audit::record_audit;
+use crate::reducers::admin_actions::propose_admin_action;
 
 pub const GRID_COLS: i32 = 1250;
 const GRID_ROWS: i32 = 800;
@@ -252,6 +254,8 @@ pub fn seed_ads(_ctx: &ReducerContext) -> Result<(), String> {
     Err("seed_ads is deprecated.".to_string())
 }
 
+/// Propose wiping every block. Executes only after a second admin confirms
+/// via `confirm_admin_action`.
 #[reducer]
 pub fn clear_all_blocks(ctx: &ReducerContext) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
@@ -260,10 +264,30 @@ pub fn clear_all_blocks(ctx: &ReducerContext) -> Result<(), String> {
         return Err("Only admins can clear data".to_string());
     }
 
+    propose_admin_action(ctx, "clear_all_blocks", serde_json::json!({}))?;
+    Ok(())
+}
+
+/// Delete every block. Called by `confirm_admin_action` once two admins agree.
+pub fn execute_clear_all_blocks(ctx: &ReducerContext) -> Result<(), String> {
     let ids: Vec<u64> = ctx.db.block().iter().map(|b| b.id).collect();
+    let block_count = ids.len();
     for id in ids {
         ctx.db.block().id().delete(id);
     }
+    let hide_ids: Vec<u64> = ctx.db.content_hide().iter().filter(|h| h.block_id != 0).map(|h| h.id).collect();
+    for id in hide_ids {
+        ctx.db.content_hide().id().delete(id);
+    }
+
+    record_audit(
+        ctx,
+        "clear_all_blocks",
+        "block",
+        "*",
+        Some(serde_json::json!({ "block_count": block_count })),
+        Some(serde_json::json!({ "block_count": 0 })),
+    )?;
 
     log::info!("Cleared all blocks");
     Ok(())
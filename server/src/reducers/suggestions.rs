@@ -0,0 +1,508 @@
+use spacetimedb::{reducer, ReducerContext, ScheduleAt, Table};
+use crate::tables::*;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// Suggestions kept per user and type.
+const MAX_SUGGESTIONS: usize = 10;
+/// Users refreshed per refresh_suggestions run.
+const REFRESH_BATCH: usize = 500;
+const REFRESH_INTERVAL_MINUTES: u64 = 30;
+/// Minimum gap between two refresh_my_suggestions calls by the same user.
+const MANUAL_REFRESH_COOLDOWN_MICROS: u64 = 5 * 60 * 1_000_000;
+
+// Signal weights. A followed user's follow is the strongest hint; shared
+// topics are the weakest because popular topics connect almost everyone.
+const FRIEND_OF_FRIEND_WEIGHT: u64 = 3;
+const LIKED_OWNER_WEIGHT: u64 = 2;
+const CO_LIKER_WEIGHT: u64 = 1;
+const SHARED_TOPIC_WEIGHT: u64 = 1;
+const FOLLOWED_USERS_TOPIC_WEIGHT: u64 = 3;
+const LIKED_TOPIC_WEIGHT: u64 = 2;
+
+fn now_micros(ctx: &ReducerContext) -> u64 {
+    ctx.timestamp.to_micros_since_unix_epoch() as u64
+}
+
+fn caller_str(ctx: &ReducerContext) -> String {
+    ctx.sender().to_hex().to_string()
+}
+
+/// Snapshot of the follow / like / topic graph, built once per refresh so each
+/// user's suggestions don't rescan the tables.
+#[derive(Default)]
+struct Graph {
+    following: HashMap<String, HashSet<String>>,
+    liked_blocks: HashMap<String, HashSet<u64>>,
+    block_likers: HashMap<u64, HashSet<String>>,
+    /// block_id → (owner, topic_id), visible claimed blocks only
+    blocks: HashMap<u64, (String, u64)>,
+    followed_topics: HashMap<String, HashSet<u64>>,
+    topic_followers: HashMap<u64, HashSet<String>>,
+    posted_topics: HashMap<String, HashSet<u64>>,
+}
+
+/// Users and topics a given user must never be offered.
+#[derive(Default)]
+struct Exclusions {
+    users: HashSet<String>,
+    topics: HashSet<u64>,
+}
+
+#[derive(Default)]
+struct Scores {
+    by_reason: HashMap<&'static str, u64>,
+}
+
+impl Scores {
+    fn add(&mut self, reason: &'static str, weight: u64) {
+        *self.by_reason.entry(reason).or_default() += weight;
+    }
+
+    fn total(&self) -> u64 {
+        self.by_reason.values().sum()
+    }
+
+    /// The strongest signal; ties break alphabetically so results are stable.
+    fn reason(&self) -> &'static str {
+        self.by_reason
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(r, _)| *r)
+            .unwrap_or("")
+    }
+}
+
+fn top<K: Ord + Clone>(scores: HashMap<K, Scores>) -> Vec<(K, u64, &'static str)> {
+    let mut ranked: Vec<(K, u64, &'static str)> = scores
+        .into_iter()
+        .map(|(k, s)| (k, s.total(), s.reason()))
+        .filter(|(_, score, _)| *score > 0)
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(MAX_SUGGESTIONS);
+    ranked
+}
+
+/// Rank people `user` might follow: friends-of-friends, owners of videos they
+/// liked, people who liked the same videos, and people in the same topics.
+fn suggest_users(graph: &Graph, user: &str, excluded: &Exclusions) -> Vec<(String, u64, &'static str)> {
+    let mut scores: HashMap<String, Scores> = HashMap::new();
+    let mut score = |candidate: &String, reason: &'static str, weight: u64| {
+        if candidate != user && !excluded.users.contains(candidate) {
+            scores.entry(candidate.clone()).or_default().add(reason, weight);
+        }
+    };
+
+    if let Some(following) = graph.following.get(user) {
+        for friend in following {
+            for candidate in graph.following.get(friend).into_iter().flatten() {
+                score(candidate, "friends_of_friends", FRIEND_OF_FRIEND_WEIGHT);
+            }
+        }
+    }
+
+    for block_id in graph.liked_blocks.get(user).into_iter().flatten() {
+        if let Some((owner, _)) = graph.blocks.get(block_id) {
+            score(owner, "co_likes", LIKED_OWNER_WEIGHT);
+        }
+        for candidate in graph.block_likers.get(block_id).into_iter().flatten() {
+            score(candidate, "co_likes", CO_LIKER_WEIGHT);
+        }
+    }
+
+    for topic_id in graph.followed_topics.get(user).into_iter().flatten() {
+        for candidate in graph.topic_followers.get(topic_id).into_iter().flatten() {
+            score(candidate, "shared_topics", SHARED_TOPIC_WEIGHT);
+        }
+    }
+
+    top(scores)
+}
+
+/// Rank topics `user` might follow: topics followed or posted in by people they
+/// follow, and topics of videos they liked.
+fn suggest_topics(graph: &Graph, user: &str, excluded: &Exclusions) -> Vec<(u64, u64, &'static str)> {
+    let mut scores: HashMap<u64, Scores> = HashMap::new();
+    let mut score = |topic_id: u64, reason: &'static str, weight: u64| {
+        if !excluded.topics.contains(&topic_id) {
+            scores.entry(topic_id).or_default().add(reason, weight);
+        }
+    };
+
+    if let Some(following) = graph.following.get(user) {
+        for friend in following {
+            let followed = graph.followed_topics.get(friend).into_iter().flatten();
+            let posted = graph.posted_topics.get(friend).into_iter().flatten();
+            for &topic_id in followed.chain(posted) {
+                score(topic_id, "followed_users", FOLLOWED_USERS_TOPIC_WEIGHT);
+            }
+        }
+    }
+
+    for block_id in graph.liked_blocks.get(user).into_iter().flatten() {
+        if let Some(&(_, topic_id)) = graph.blocks.get(block_id) {
+            score(topic_id, "co_likes", LIKED_TOPIC_WEIGHT);
+        }
+    }
+
+    top(scores)
+}
+
+fn build_graph(ctx: &ReducerContext) -> Graph {
+    let mut graph = Graph::default();
+
+    for f in ctx.db.user_follow().iter() {
+        graph.following.entry(f.follower_identity).or_default().insert(f.following_identity);
+    }
+    for b in ctx.db.block().iter() {
+        if b.status == "claimed" && !b.hidden {
+            graph.posted_topics.entry(b.owner_identity.clone()).or_default().insert(b.topic_id);
+            graph.blocks.insert(b.id, (b.owner_identity, b.topic_id));
+        }
+    }
+    for like in ctx.db.like_record().iter() {
+        if !graph.blocks.contains_key(&like.block_id) {
+            continue;
+        }
+        graph.block_likers.entry(like.block_id).or_default().insert(like.user_identity.clone());
+        graph.liked_blocks.entry(like.user_identity).or_default().insert(like.block_id);
+    }
+    for f in ctx.db.topic_follow().iter() {
+        graph.topic_followers.entry(f.topic_id).or_default().insert(f.follower_identity.clone());
+        graph.followed_topics.entry(f.follower_identity).or_default().insert(f.topic_id);
+    }
+
+    graph
+}
+
+/// Users nobody should be offered (deleted or sanctioned) and inactive topics.
+fn global_exclusions(ctx: &ReducerContext) -> Exclusions {
+    let now = now_micros(ctx);
+    let mut users: HashSet<String> = ctx
+        .db
+        .user_sanction()
+        .iter()
+        .filter(|s| s.status == "active" && s.expires_at.map(|t| t > now).unwrap_or(true))
+        .map(|s| s.identity)
+        .collect();
+    users.extend(
+        ctx.db
+            .user_profile()
+            .iter()
+            .filter(|p| p.clerk_user_id.is_empty())
+            .map(|p| p.identity),
+    );
+    let topics = ctx.db.topic().iter().filter(|t| !t.is_active).map(|t| t.id).collect();
+    Exclusions { users, topics }
+}
+
+/// Add `user`'s own exclusions: people they follow, block, are blocked by or
+/// have muted, topics they already follow, and anything they dismissed.
+fn user_exclusions(ctx: &ReducerContext, graph: &Graph, global: &Exclusions, user: &str) -> Exclusions {
+    let now = now_micros(ctx);
+    let mut users = global.users.clone();
+    users.extend(graph.following.get(user).into_iter().flatten().cloned());
+    users.extend(ctx.db.user_block().blocker_identity().filter(user).map(|b| b.blocked_identity));
+    users.extend(ctx.db.user_block().blocked_identity().filter(user).map(|b| b.blocker_identity));
+    users.extend(
+        ctx.db
+            .user_mute()
+            .muter_identity()
+            .filter(user)
+            .filter(|m| m.expires_at.map(|t| t > now).unwrap_or(true))
+            .map(|m| m.muted_identity),
+    );
+
+    let mut topics = global.topics.clone();
+    topics.extend(graph.followed_topics.get(user).into_iter().flatten().copied());
+
+    for d in ctx.db.dismissed_suggestion().identity().filter(user) {
+        if d.suggested_identity.is_empty() {
+            topics.insert(d.topic_id);
+        } else {
+            users.insert(d.suggested_identity);
+        }
+    }
+
+    Exclusions { users, topics }
+}
+
+fn write_suggestions(ctx: &ReducerContext, graph: &Graph, global: &Exclusions, user: &str) {
+    let excluded = user_exclusions(ctx, graph, global, user);
+    let now = now_micros(ctx);
+
+    ctx.db.suggestion().identity().delete(user);
+
+    for (suggested_identity, score, reason) in suggest_users(graph, user, &excluded) {
+        let _ = ctx.db.suggestion().try_insert(Suggestion {
+            id: 0,
+            identity: user.to_string(),
+            suggestion_type: "user".to_string(),
+            suggested_identity,
+            topic_id: 0,
+            score,
+            reason: reason.to_string(),
+            created_at: now,
+        });
+    }
+    for (topic_id, score, reason) in suggest_topics(graph, user, &excluded) {
+        let _ = ctx.db.suggestion().try_insert(Suggestion {
+            id: 0,
+            identity: user.to_string(),
+            suggestion_type: "topic".to_string(),
+            suggested_identity: String::new(),
+            topic_id,
+            score,
+            reason: reason.to_string(),
+            created_at: now,
+        });
+    }
+}
+
+/// Drop `suggested` from `identity`'s suggestions right away (block / mute)
+/// rather than waiting for the next refresh.
+pub fn remove_user_suggestion(ctx: &ReducerContext, identity: &str, suggested: &str) {
+    let ids: Vec<u64> = ctx
+        .db
+        .suggestion()
+        .identity()
+        .filter(identity)
+        .filter(|s| s.suggested_identity == suggested)
+        .map(|s| s.id)
+        .collect();
+    for id in ids {
+        ctx.db.suggestion().id().delete(id);
+    }
+}
+
+/// Start the periodic refresh if it isn't running. Called from init and
+/// refresh_my_suggestions.
+pub fn ensure_suggestion_schedule(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.suggestion_refresh_schedule().iter().next().is_some() {
+        return Ok(());
+    }
+    ctx.db
+        .suggestion_refresh_schedule()
+        .try_insert(SuggestionRefreshSchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(Duration::from_secs(REFRESH_INTERVAL_MINUTES * 60).into()),
+        })
+        .map_err(|e| format!("Schedule insert failed: {e}"))?;
+    Ok(())
+}
+
+// ─── refresh_suggestions (scheduled) ─────────────────────────────────────────
+
+/// Rebuild suggestions for the next REFRESH_BATCH registered users (by
+/// identity), wrapping around once everyone has been refreshed.
+#[reducer]
+pub fn refresh_suggestions(ctx: &ReducerContext, _schedule: SuggestionRefreshSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("refresh_suggestions may only be invoked by the scheduler".to_string());
+    }
+
+    let last = ctx
+        .db
+        .suggestion_refresh_cursor()
+        .id()
+        .find(0)
+        .map(|c| c.last_identity)
+        .unwrap_or_default();
+
+    let global = global_exclusions(ctx);
+    let mut users: Vec<String> = ctx
+        .db
+        .user_profile()
+        .iter()
+        .map(|p| p.identity)
+        .filter(|id| !global.users.contains(id))
+        .collect();
+    users.sort();
+    let mut batch: Vec<String> = users.iter().filter(|id| **id > last).take(REFRESH_BATCH).cloned().collect();
+    if batch.is_empty() {
+        batch = users.into_iter().take(REFRESH_BATCH).collect();
+    }
+    let Some(next_cursor) = batch.last().cloned() else {
+        return Ok(());
+    };
+
+    let graph = build_graph(ctx);
+    for user in &batch {
+        write_suggestions(ctx, &graph, &global, user);
+    }
+
+    let cursor = SuggestionRefreshCursor { id: 0, last_identity: next_cursor };
+    if ctx.db.suggestion_refresh_cursor().id().find(0).is_some() {
+        ctx.db.suggestion_refresh_cursor().id().update(cursor);
+    } else {
+        ctx.db
+            .suggestion_refresh_cursor()
+            .try_insert(cursor)
+            .map_err(|e| format!("Insert failed: {e}"))?;
+    }
+
+    Ok(())
+}
+
+// ─── refresh_my_suggestions ──────────────────────────────────────────────────
+
+/// Rebuild the caller's suggestions now, e.g. right after they follow a batch
+/// of people during onboarding. Rebuilding scans the whole graph, so each user
+/// may do it at most once per MANUAL_REFRESH_COOLDOWN_MICROS.
+#[reducer]
+pub fn refresh_my_suggestions(ctx: &ReducerContext) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    ctx.db
+        .user_profile()
+        .identity()
+        .find(caller.clone())
+        .ok_or("User not found")?;
+
+    let now = now_micros(ctx);
+    let stamp = SuggestionManualRefresh { identity: caller.clone(), refreshed_at: now };
+    match ctx.db.suggestion_manual_refresh().identity().find(caller.clone()) {
+        Some(last) if now < last.refreshed_at.saturating_add(MANUAL_REFRESH_COOLDOWN_MICROS) => {
+            return Err("Suggestions were refreshed recently — try again in a few minutes".to_string());
+        }
+        Some(_) => {
+            ctx.db.suggestion_manual_refresh().identity().update(stamp);
+        }
+        None => {
+            ctx.db
+                .suggestion_manual_refresh()
+                .try_insert(stamp)
+                .map_err(|e| format!("Insert failed: {e}"))?;
+        }
+    }
+
+    ensure_suggestion_schedule(ctx)?;
+
+    let graph = build_graph(ctx);
+    let global = global_exclusions(ctx);
+    write_suggestions(ctx, &graph, &global, &caller);
+    Ok(())
+}
+
+// ─── dismiss_suggestion ──────────────────────────────────────────────────────
+
+/// Drop one suggestion and remember the dismissal so later refreshes never
+/// offer the same user or topic again.
+#[reducer]
+pub fn dismiss_suggestion(ctx: &ReducerContext, suggestion_id: u64) -> Result<(), String> {
+    let caller = caller_str(ctx);
+    let suggestion = ctx
+        .db
+        .suggestion()
+        .id()
+        .find(suggestion_id)
+        .ok_or("Suggestion not found")?;
+    if suggestion.identity != caller {
+        return Err("Not authorized".to_string());
+    }
+    ctx.db.suggestion().id().delete(suggestion_id);
+
+    let already = ctx
+        .db
+        .dismissed_suggestion()
+        .identity()
+        .filter(caller.as_str())
+        .any(|d| d.suggested_identity == suggestion.suggested_identity && d.topic_id == suggestion.topic_id);
+    if !already {
+        ctx.db
+            .dismissed_suggestion()
+            .try_insert(DismissedSuggestion {
+                id: 0,
+                identity: caller,
+                suggested_identity: suggestion.suggested_identity,
+                topic_id: suggestion.topic_id,
+                created_at: now_micros(ctx),
+            })
+            .map_err(|e| format!("Insert failed: {e}"))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(v: &str) -> String {
+        v.to_string()
+    }
+
+    fn follow(graph: &mut Graph, follower: &str, following: &str) {
+        graph.following.entry(s(follower)).or_default().insert(s(following));
+    }
+
+    fn like(graph: &mut Graph, user: &str, block_id: u64) {
+        graph.liked_blocks.entry(s(user)).or_default().insert(block_id);
+        graph.block_likers.entry(block_id).or_default().insert(s(user));
+    }
+
+    fn follow_topic(graph: &mut Graph, user: &str, topic_id: u64) {
+        graph.followed_topics.entry(s(user)).or_default().insert(topic_id);
+        graph.topic_followers.entry(topic_id).or_default().insert(s(user));
+    }
+
+    #[test]
+    fn friends_of_friends_outrank_weaker_signals() {
+        let mut graph = Graph::default();
+        follow(&mut graph, "me", "ann");
+        follow(&mut graph, "ann", "bob");
+        follow_topic(&mut graph, "me", 7);
+        follow_topic(&mut graph, "cat", 7);
+
+        let ranked = suggest_users(&graph, "me", &Exclusions::default());
+
+        assert_eq!(ranked[0], (s("bob"), FRIEND_OF_FRIEND_WEIGHT, "friends_of_friends"));
+        assert_eq!(ranked[1], (s("cat"), SHARED_TOPIC_WEIGHT, "shared_topics"));
+        assert!(ranked.iter().all(|(u, _, _)| u != "me"));
+    }
+
+    #[test]
+    fn co_likes_credit_owner_and_other_likers() {
+        let mut graph = Graph::default();
+        graph.blocks.insert(1, (s("owner"), 3));
+        like(&mut graph, "me", 1);
+        like(&mut graph, "dan", 1);
+
+        let ranked = suggest_users(&graph, "me", &Exclusions::default());
+
+        assert_eq!(ranked[0], (s("owner"), LIKED_OWNER_WEIGHT, "co_likes"));
+        assert_eq!(ranked[1], (s("dan"), CO_LIKER_WEIGHT, "co_likes"));
+    }
+
+    #[test]
+    fn excluded_users_and_topics_are_never_suggested() {
+        let mut graph = Graph::default();
+        follow(&mut graph, "me", "ann");
+        follow(&mut graph, "ann", "blocked");
+        follow_topic(&mut graph, "ann", 5);
+        follow_topic(&mut graph, "ann", 6);
+
+        let mut excluded = Exclusions::default();
+        excluded.users.insert(s("blocked"));
+        excluded.topics.insert(5);
+
+        assert!(suggest_users(&graph, "me", &excluded).is_empty());
+        assert_eq!(
+            suggest_topics(&graph, "me", &excluded),
+            vec![(6, FOLLOWED_USERS_TOPIC_WEIGHT, "followed_users")]
+        );
+    }
+
+    #[test]
+    fn results_are_capped() {
+        let mut graph = Graph::default();
+        follow(&mut graph, "me", "hub");
+        for i in 0..(MAX_SUGGESTIONS + 5) {
+            follow(&mut graph, "hub", &format!("user{i:02}"));
+        }
+
+        let ranked = suggest_users(&graph, "me", &Exclusions::default());
+
+        assert_eq!(ranked.len(), MAX_SUGGESTIONS);
+        assert_eq!(ranked[0].0, "user00");
+    }
+}
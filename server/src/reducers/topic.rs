@@ -1,5 +1,11 @@
 use spacetimedb::{reducer, ReducerContext, Table};
 use crate::tables::*;
+use crate::reducers::activity::{record_activity, remove_block_activity};
+use crate::reducers::counters::{adjust_topic_follow_count, adjust_videos_posted, remove_block_from_counters};
+use crate::reducers::audit::record_audit;
+use crate::reducers::claim::{ranked_topic_blocks, reposition_blocks};
+use crate::reducers::filter::filter_text_or_reject;
+use crate::reducers::moderation::{clear_block_hide_reason, set_block_hide_reason};
 use std::collections::{HashMap, HashSet};
 
 const REAPPLY_COOLDOWN_MICROS: u64 = 24 * 60 * 60 * 1_000_000;
@@ -124,7 +130,8 @@ fn is_admin(ctx: &ReducerContext, caller: &str) -> bool {
         .unwrap_or(false)
 }
 
-fn can_moderate_topic(ctx: &ReducerContext, caller: &str, topic_id: u64) -> bool {
+/// Admin, topic creator, or active topic moderator.
+pub fn can_moderate_topic(ctx: &ReducerContext, caller: &str, topic_id: u64) -> bool {
     if is_admin(ctx, caller) {
         return true;
     }
@@ -252,6 +259,7 @@ pub fn create_topic(
     category: String,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     if ctx.db.user_profile().identity().find(caller.clone()).is_none() {
         return Err("Must be registered to create a topic".to_string());
@@ -261,6 +269,12 @@ pub fn create_topic(
     if trimmed.is_empty() {
         return Err("Title cannot be empty".to_string());
     }
+    // Titles become slugs and URLs, so a masked word rejects instead of
+    // leaving asterisks in them.
+    if filter_text_or_reject(ctx, &trimmed, 0)? != trimmed {
+        return Err("Your title contains a blocked word — please rephrase".to_string());
+    }
+    let description = filter_text_or_reject(ctx, &description, 0)?;
 
     let base_slug = slug_from_title(&trimmed);
     if base_slug.is_empty() {
@@ -294,6 +308,7 @@ pub fn create_topic(
         total_views: 0,
         is_active: true,
         created_at: now_micros(ctx),
+        follower_count: 0,
     }).map_err(|e| format!("Insert failed: {e}"))?;
 
     ensure_owner_moderator_row(ctx, topic.id, &topic.creator_identity, &topic.creator_identity)?;
@@ -309,6 +324,22 @@ pub fn block_score(b: &Block) -> i64 {
     yt + platform
 }
 
+pub fn adjust_topic_follower_count(ctx: &ReducerContext, topic_id: u64, delta: i64) -> Result<(), String> {
+    if let Some(topic) = ctx.db.topic().id().find(topic_id) {
+        let new_count = if delta > 0 {
+            topic.follower_count.saturating_add(delta as u64)
+        } else {
+            topic.follower_count.saturating_sub((-delta) as u64)
+        };
+        ctx.db.topic().id().delete(topic_id);
+        ctx.db.topic().try_insert(Topic {
+            follower_count: new_count,
+            ..topic
+        }).map_err(|e| format!("Topic update failed: {e}"))?;
+    }
+    Ok(())
+}
+
 #[derive(Clone, Copy, Default)]
 struct ActivitySignal {
     claim_count: u64,
@@ -485,6 +516,7 @@ pub fn claim_block_in_topic(
     yt_likes: u64,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     if ctx.db.user_profile().identity().find(caller.clone()).is_none() {
         return Err("Must be registered to claim a block".to_string());
@@ -506,8 +538,7 @@ pub fn claim_block_in_topic(
     }
 
     // Reject banned users.
-    let is_banned = ctx.db.topic_ban().iter().any(|b| b.topic_id == topic_id && b.banned_identity == caller);
-    if is_banned {
+    if crate::reducers::moderation::is_topic_banned(ctx, topic_id, &caller) {
         return Err("You are banned from posting in this topic".to_string());
     }
 
@@ -525,7 +556,8 @@ pub fn claim_block_in_topic(
     // Temporary position; will be corrected by the rebalance below.
     let (temp_x, temp_y) = spiral_coords(topic.video_count);
 
-    ctx.db.block().try_insert(Block {
+    let hide_reason = crate::reducers::sanctions::new_content_hide_reason(ctx);
+    let block = ctx.db.block().try_insert(Block {
         id: 0,
         topic_id,
         x: temp_x,
@@ -543,7 +575,16 @@ pub fn claim_block_in_topic(
         ad_image_url: String::new(),
         ad_link_url: String::new(),
         claimed_at: now_micros(ctx),
+        hidden: hide_reason.is_some(),
     }).map_err(|e| format!("Block insert failed: {e}"))?;
+    set_block_hide_reason(ctx, block.id, hide_reason)?;
+    adjust_videos_posted(ctx, &block.owner_identity, 1);
+
+    // Shadow-banned / held videos stay invisible, so followers aren't told about them.
+    if !block.hidden {
+        crate::reducers::follow::queue_topic_video_fanout(ctx, block.id)?;
+        record_activity(ctx, &block.owner_identity, "video_claimed", block.id, 0, topic_id, "")?;
+    }
 
     // Increment video count.
     ctx.db.topic().id().delete(topic_id);
@@ -553,28 +594,7 @@ pub fn claim_block_in_topic(
     }).map_err(|e| format!("Topic update failed: {e}"))?;
 
     // --- Auto-rebalance: sort all claimed blocks by score, reassign spiral positions ---
-    let mut claimed: Vec<Block> = ctx
-        .db
-        .block()
-        .iter()
-        .filter(|b| b.topic_id == topic_id && b.status == "claimed")
-        .collect();
-
-    claimed.sort_by(|a, b| block_score(b).cmp(&block_score(a)));
-
-    for (i, block) in claimed.iter().enumerate() {
-        let (new_x, new_y) = spiral_coords(i as u64);
-        if block.x != new_x || block.y != new_y {
-            ctx.db.block().id().delete(block.id);
-            ctx.db.block().try_insert(Block {
-                x: new_x,
-                y: new_y,
-                ..block.clone()
-            }).map_err(|e| format!("Rebalance insert failed: {e}"))?;
-        }
-    }
-
-    Ok(())
+    crate::reducers::claim::rebalance_topic_blocks(ctx, topic_id)
 }
 
 /// Increment the view counter for a topic (call when a user opens the topic page).
@@ -606,6 +626,7 @@ pub fn update_topic(
     category: String,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let topic = ctx
         .db
@@ -618,6 +639,18 @@ pub fn update_topic(
         return Err("Only a topic moderator or an admin can update this topic".to_string());
     }
 
+    // Empty fields keep the current value; new text goes through the same
+    // filter as create_topic, plus this topic's own overrides.
+    let title = title.trim().to_string();
+    if !title.is_empty() && filter_text_or_reject(ctx, &title, topic_id)? != title {
+        return Err("Your title contains a blocked word — please rephrase".to_string());
+    }
+    let description = if description.is_empty() {
+        description
+    } else {
+        filter_text_or_reject(ctx, &description, topic_id)?
+    };
+
     ctx.db.topic().id().delete(topic_id);
     let next_category = if category.is_empty() {
         topic.category.clone()
@@ -642,9 +675,68 @@ pub fn update_topic(
     Ok(())
 }
 
+/// Delete a deleted topic's bans (with their expiry schedules), ban appeals,
+/// moderation queue items and word-filter overrides.
+fn remove_topic_moderation(ctx: &ReducerContext, topic_id: u64) {
+    let ban_ids: HashSet<u64> = ctx
+        .db
+        .topic_ban()
+        .iter()
+        .filter(|b| b.topic_id == topic_id)
+        .map(|b| b.id)
+        .collect();
+    let schedule_ids: Vec<u64> = ctx
+        .db
+        .topic_ban_expiry()
+        .iter()
+        .filter(|s| ban_ids.contains(&s.ban_id))
+        .map(|s| s.scheduled_id)
+        .collect();
+    for id in schedule_ids {
+        ctx.db.topic_ban_expiry().scheduled_id().delete(id);
+    }
+    for id in ban_ids {
+        ctx.db.topic_ban().id().delete(id);
+    }
+
+    let appeal_ids: Vec<u64> = ctx
+        .db
+        .topic_ban_appeal()
+        .iter()
+        .filter(|a| a.topic_id == topic_id)
+        .map(|a| a.id)
+        .collect();
+    for id in appeal_ids {
+        ctx.db.topic_ban_appeal().id().delete(id);
+    }
+
+    let queue_ids: Vec<u64> = ctx
+        .db
+        .moderation_queue_item()
+        .iter()
+        .filter(|q| q.topic_id == topic_id)
+        .map(|q| q.id)
+        .collect();
+    for id in queue_ids {
+        ctx.db.moderation_queue_item().id().delete(id);
+    }
+
+    let term_ids: Vec<u64> = ctx
+        .db
+        .blocked_term()
+        .iter()
+        .filter(|t| t.topic_id == topic_id)
+        .map(|t| t.id)
+        .collect();
+    for id in term_ids {
+        ctx.db.blocked_term().id().delete(id);
+    }
+}
+
 /// Delete a topic.
 ///
-/// - If the caller owns all claimed blocks (or there are none) → delete all blocks and the topic.
+/// - If the caller owns all claimed blocks (or there are none) → delete all blocks, the
+///   topic and its moderation rows (bans, appeals, queue items, filter overrides).
 /// - If other users have blocks:
 ///   1) optional explicit successor (if eligible),
 ///   2) otherwise the best active moderator by activity,
@@ -657,6 +749,7 @@ pub fn delete_topic(
     preferred_new_owner_identity: Option<String>,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
 
     let topic = ctx
         .db
@@ -671,19 +764,23 @@ pub fn delete_topic(
         return Err("Only the topic creator or an admin can delete this topic".to_string());
     }
 
-    let all_claimed: Vec<Block> = ctx
+    let all_blocks: Vec<Block> = ctx
         .db
         .block()
         .iter()
-        .filter(|b| b.topic_id == topic_id && b.status == "claimed")
+        .filter(|b| b.topic_id == topic_id)
         .collect();
+    let all_claimed: Vec<&Block> = all_blocks.iter().filter(|b| b.status == "claimed").collect();
 
     let has_others = all_claimed.iter().any(|b| b.owner_identity != caller);
 
     if !has_others {
-        // Sole owner (or empty topic) — delete all blocks and the topic itself.
-        for b in &all_claimed {
+        // Sole owner (or empty topic) — delete all blocks (hidden ones too) and the topic itself.
+        for b in &all_blocks {
             ctx.db.block().id().delete(b.id);
+            clear_block_hide_reason(ctx, b.id);
+            remove_block_from_counters(ctx, b);
+            remove_block_activity(ctx, b.id);
         }
         let mod_rows: Vec<u64> = ctx
             .db
@@ -705,6 +802,13 @@ pub fn delete_topic(
         for id in application_rows {
             ctx.db.topic_moderator_application().id().delete(id);
         }
+        let follows: Vec<TopicFollow> = ctx.db.topic_follow().topic_id().filter(topic_id).collect();
+        for follow in follows {
+            ctx.db.topic_follow().id().delete(follow.id);
+            adjust_topic_follow_count(ctx, &follow.follower_identity, -1);
+        }
+        ctx.db.topic_announcement().topic_id().delete(topic_id);
+        remove_topic_moderation(ctx, topic_id);
         ctx.db.topic().id().delete(topic_id);
     } else {
         // Other users have posts — transfer ownership, remove caller's blocks, rebalance.
@@ -746,31 +850,16 @@ pub fn delete_topic(
         // Remove caller's blocks.
         for b in all_claimed.iter().filter(|b| b.owner_identity == caller) {
             ctx.db.block().id().delete(b.id);
+            clear_block_hide_reason(ctx, b.id);
+            remove_block_from_counters(ctx, b);
+            remove_block_activity(ctx, b.id);
         }
 
         // Rebalance remaining blocks and update video_count.
-        let mut remaining: Vec<Block> = ctx
-            .db
-            .block()
-            .iter()
-            .filter(|b| b.topic_id == topic_id && b.status == "claimed")
-            .collect();
-
-        remaining.sort_by(|a, b| block_score(b).cmp(&block_score(a)));
-
-        for (i, block) in remaining.iter().enumerate() {
-            let (new_x, new_y) = spiral_coords(i as u64);
-            if block.x != new_x || block.y != new_y {
-                ctx.db.block().id().delete(block.id);
-                ctx.db.block().try_insert(Block {
-                    x: new_x,
-                    y: new_y,
-                    ..block.clone()
-                }).map_err(|e| format!("Rebalance failed: {e}"))?;
-            }
-        }
+        let remaining = ranked_topic_blocks(ctx, topic_id);
+        reposition_blocks(ctx, &remaining, remaining.len())?;
 
-        let new_count = remaining.len() as u64;
+        let new_count = remaining.iter().filter(|b| b.status == "claimed").count() as u64;
         if let Some(updated_topic) = ctx.db.topic().id().find(topic_id) {
             ctx.db.topic().id().delete(topic_id);
             ctx.db.topic().try_insert(Topic {
@@ -795,6 +884,7 @@ pub fn create_topic_taxonomy_node(
     parent_id: Option<u64>,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
     if ctx.db.user_profile().identity().find(caller.clone()).is_none() {
         return Err("Must be registered to create taxonomy nodes".to_string());
     }
@@ -853,6 +943,7 @@ pub fn set_topic_taxonomy(
     taxonomy_node_id: u64,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
     if !can_moderate_topic(ctx, &caller, topic_id) {
         return Err("Only topic moderators or admins can set taxonomy".to_string());
     }
@@ -865,16 +956,30 @@ pub fn set_topic_taxonomy(
         .find(taxonomy_node_id)
         .ok_or("Taxonomy node not found")?;
 
+    let before = serde_json::json!({
+        "taxonomy_node_id": topic.taxonomy_node_id,
+        "category": topic.category,
+    });
+
     ctx.db.topic().id().delete(topic_id);
     ctx.db
         .topic()
         .try_insert(Topic {
             taxonomy_node_id: Some(node.id),
-            category: node.name,
+            category: node.name.clone(),
             ..topic
         })
         .map_err(|e| format!("Topic update failed: {e}"))?;
 
+    record_audit(
+        ctx,
+        "set_topic_taxonomy",
+        "topic",
+        &topic_id.to_string(),
+        Some(before),
+        Some(serde_json::json!({ "taxonomy_node_id": node.id, "category": node.name })),
+    )?;
+
     Ok(())
 }
 
@@ -886,12 +991,15 @@ pub fn apply_topic_moderator(
     message: String,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
     if ctx.db.user_profile().identity().find(caller.clone()).is_none() {
         return Err("Must be registered to apply as moderator".to_string());
     }
 
     let _topic = ctx.db.topic().id().find(topic_id).ok_or("Topic not found")?;
 
+    crate::reducers::moderation::ensure_not_topic_banned(ctx, topic_id, &caller)?;
+
     if can_moderate_topic(ctx, &caller, topic_id) {
         return Err("You are already a moderator for this topic".to_string());
     }
@@ -943,6 +1051,7 @@ pub fn review_topic_moderator_application(
     approve: bool,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
     let application = ctx
         .db
         .topic_moderator_application()
@@ -1005,6 +1114,7 @@ pub fn remove_topic_moderator(
     identity: String,
 ) -> Result<(), String> {
     let caller = ctx.sender().to_hex().to_string();
+    crate::reducers::sanctions::ensure_not_suspended(ctx)?;
     let topic = ctx.db.topic().id().find(topic_id).ok_or("Topic not found")?;
     let caller_is_admin = is_admin(ctx, &caller);
     if !caller_is_admin && topic.creator_identity != caller {
@@ -1027,10 +1137,24 @@ pub fn remove_topic_moderator(
         .topic_moderator()
         .try_insert(TopicModerator {
             status: "removed".to_string(),
-            ..mod_row
+            ..mod_row.clone()
         })
         .map_err(|e| format!("Moderator update failed: {e}"))?;
 
+    record_audit(
+        ctx,
+        "remove_topic_moderator",
+        "topic_moderator",
+        &mod_row.id.to_string(),
+        Some(serde_json::json!({
+            "topic_id": topic_id,
+            "identity": mod_row.identity,
+            "role": mod_row.role,
+            "status": mod_row.status,
+        })),
+        Some(serde_json::json!({ "status": "removed" })),
+    )?;
+
     Ok(())
 }
 
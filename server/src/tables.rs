@@ -1,4 +1,9 @@
-use spacetimedb::table;
+use spacetimedb::{table, ScheduleAt};
+use crate::reducers::follow::{fan_out_topic_announcement, fan_out_topic_video};
+use crate::reducers::moderation::{expire_topic_ban, expire_user_mute};
+use crate::reducers::notifications::{deliver_notification_digests, prune_notifications};
+use crate::reducers::sanctions::expire_user_sanction;
+use crate::reducers::suggestions::refresh_suggestions;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Platform {
@@ -48,6 +53,9 @@ pub struct Topic {
     /// Added at end + default for automatic migration compatibility.
     #[default(None::<u64>)]
     pub taxonomy_node_id: Option<u64>,
+    /// Maintained count of TopicFollow rows
+    #[default(0u64)]
+    pub follower_count: u64,
 }
 
 /// Hierarchical taxonomy node used to group topics, e.g. Science > Physics.
@@ -125,6 +133,11 @@ pub struct Block {
     pub ad_image_url: String,
     pub ad_link_url: String,
     pub claimed_at: u64,
+    /// true = hidden. With status "hidden" the block is off the grid entirely; a hidden
+    /// "claimed" block is shown only to its owner. Why it is hidden lives in the private
+    /// ContentHide table, so owners can't tell a shadow ban from other moderation.
+    #[default(false)]
+    pub hidden: bool,
 }
 
 #[table(accessor = user_profile, public)]
@@ -155,6 +168,22 @@ pub struct UserProfile {
     pub social_tiktok: Option<String>,
     #[default(None::<String>)]
     pub social_instagram: Option<String>,
+    /// New followers need approval (follow_request)
+    #[default(false)]
+    pub is_private: bool,
+    // Maintained counters; recompute_profile_counters repairs drift.
+    #[default(0u64)]
+    pub follower_count: u64,
+    #[default(0u64)]
+    pub following_count: u64,
+    #[default(0u64)]
+    pub topic_follow_count: u64,
+    /// Sum of `likes` on the user's claimed / hidden blocks
+    #[default(0u64)]
+    pub total_likes_received: u64,
+    /// Claimed / hidden blocks the user owns
+    #[default(0u64)]
+    pub videos_posted: u64,
 }
 
 #[table(accessor = like_record, public)]
@@ -259,6 +288,54 @@ pub struct Comment {
     /// 0 = never edited; > 0 = microsecond timestamp of last edit
     #[default(0u64)]
     pub edited_at: u64,
+    /// true = hidden from everyone but the author; the reason is in the private ContentHide table
+    #[default(false)]
+    pub hidden: bool,
+}
+
+/// Why a block or comment is hidden. Private: the public rows only carry `hidden`,
+/// so authors can't tell a shadow ban from a topic ban or a report.
+#[table(accessor = content_hide)]
+pub struct ContentHide {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// Set for blocks, 0 for comments
+    #[index(btree)]
+    pub block_id: u64,
+    /// Set for comments, 0 for blocks
+    #[index(btree)]
+    pub comment_id: u64,
+    /// "shadow_ban" | "topic_ban" | "report" | "auto_moderation" | "filter_hold"
+    pub reason: String,
+    pub created_at: u64,
+}
+
+/// The block whose comments a viewer currently has open (one per viewer).
+/// Set by load_comment_view; its entries live in comment_view_entry.
+/// Private, like the entries: together they would reveal who muted whom.
+#[table(accessor = comment_view)]
+#[derive(Clone)]
+pub struct CommentView {
+    #[primary_key]
+    pub viewer_identity: String,
+    pub block_id: u64,
+    pub loaded_at: u64,
+}
+
+/// Per-viewer list of a block's comments, excluding authors the viewer has muted.
+/// Maintained as comments are added or deleted and as mutes change. Clients
+/// read their own rows through the my_comment_view_entry view.
+#[table(accessor = comment_view_entry)]
+#[derive(Clone)]
+pub struct CommentViewEntry {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub viewer_identity: String,
+    pub block_id: u64,
+    pub comment_id: u64,
 }
 
 #[table(accessor = comment_like, public)]
@@ -278,15 +355,136 @@ pub struct Notification {
     #[primary_key]
     #[auto_inc]
     pub id: u64,
+    #[index(btree)]
     pub recipient_identity: String,
     pub actor_identity: String,
     pub actor_name: String,
-    /// "comment_reply" | "comment_like" | "comment_repost"
+    /// "comment_reply" | "comment_like" | "comment_repost" | "video_like" | "new_follow"
+    /// | "follow_request" | "follow_request_approved" | "new_video_in_topic" | "topic_announcement"
+    /// | "new_message" | "message_request" | "topic_ban" | "topic_ban_lifted"
+    /// | "ban_appeal_approved" | "ban_appeal_rejected" | "content_reported"
+    /// | "report_resolved" | "report_escalated" | "moderation_warning" | "content_hidden"
+    /// | "account_suspended" | "content_held" | "notification_digest"
     pub notification_type: String,
     pub block_id: u64,
     pub comment_id: u64,
     pub is_read: bool,
     pub created_at: u64,
+    /// Context reference for topic-scoped notifications (0 = none)
+    #[default(0u64)]
+    pub topic_id: u64,
+    /// Recipient has muted the actor; clients leave these out of the inbox.
+    /// Kept in sync by mute_user / unmute_user / expire_user_mute.
+    #[default(false)]
+    pub actor_muted: bool,
+    /// Recipient chose digest-only delivery for this type: stored without
+    /// alerting and announced by the next "notification_digest" notice, which
+    /// marks it read.
+    #[default(false)]
+    pub digest: bool,
+    /// Activity notifications are grouped per (recipient, type, target) while
+    /// unread: actor_identity / actor_name hold the latest actor, actor_count the
+    /// number of distinct actors. A new event after the group is read starts a new row.
+    /// Replies and reposts group on the comment replied to / reposted, messages on
+    /// the conversation, and follows never group across followers.
+    /// For "notification_digest", the number of digested notifications.
+    #[default(1u64)]
+    pub actor_count: u64,
+    /// JSON array of up to 3 most recent actor identities, newest first
+    /// (None = no actor, e.g. moderation notices)
+    #[default(None::<String>)]
+    pub latest_actors: Option<String>,
+    /// Time of the latest event in the group (created_at = first event)
+    #[default(0u64)]
+    pub updated_at: u64,
+    /// TopicAnnouncement for "topic_announcement" notifications (0 = none)
+    #[default(0u64)]
+    pub announcement_id: u64,
+    /// Conversation for "new_message" / "message_request" notifications (0 = none)
+    #[default(0u64)]
+    pub conversation_id: u64,
+}
+
+/// Distinct actors folded into a grouped notification (private bookkeeping for actor_count).
+#[table(accessor = notification_actor)]
+pub struct NotificationActor {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub notification_id: u64,
+    pub actor_identity: String,
+}
+
+/// Singleton (id = 0) retention settings for prune_notifications, edited by
+/// admins via set_notification_retention.
+#[table(accessor = notification_retention_config, public)]
+#[derive(Clone)]
+pub struct NotificationRetentionConfig {
+    #[primary_key]
+    pub id: u32,
+    /// Read notifications older than this are deleted
+    pub read_retention_days: u64,
+    /// Oldest unread notifications beyond this many per user are deleted
+    pub max_unread_per_user: u64,
+    pub prune_interval_hours: u64,
+    pub updated_by: String,
+    pub updated_at: u64,
+}
+
+/// Repeating schedule for deliver_notification_digests (at most one row).
+/// Started by the first set_notification_preference that turns on digest_only.
+#[table(accessor = notification_digest_schedule, scheduled(deliver_notification_digests))]
+pub struct NotificationDigestSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Repeating schedule for prune_notifications (at most one row). Started by the
+/// first notification and re-created whenever the retention settings change.
+#[table(accessor = notification_prune_schedule, scheduled(prune_notifications))]
+pub struct NotificationPruneSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// One row per prune_notifications run. Private: operators read it with SQL as
+/// the database owner.
+#[table(accessor = notification_prune_log)]
+#[derive(Clone)]
+pub struct NotificationPruneLog {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub ran_at: u64,
+    /// Read notifications last updated before this were deleted
+    pub read_cutoff: u64,
+    pub read_pruned: u64,
+    pub unread_pruned: u64,
+    /// Users whose unread notifications hit max_unread_per_user
+    pub users_capped: u64,
+}
+
+/// Per-user delivery setting for one notification type. Missing rows mean the
+/// default (in-app, not digest-only); register_user writes the defaults.
+#[table(accessor = notification_preference, public)]
+#[derive(Clone)]
+pub struct NotificationPreference {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub identity: String,
+    pub notification_type: String,
+    /// false = don't store this type at all (unless digest_only)
+    pub in_app: bool,
+    /// Store with digest = true instead of as a live notification
+    pub digest_only: bool,
+    pub updated_at: u64,
 }
 
 #[table(accessor = clerk_identity_map, public)]
@@ -305,12 +503,41 @@ pub struct DirectMessage {
     pub sender_identity: String,
     pub recipient_identity: String,
     pub text: String,
+    /// Only maintained for legacy messages with conversation_id = 0; everything
+    /// else is read/unread according to ConversationReadState.
     pub is_read: bool,
     pub created_at: u64,
     #[default(0u64)]
+    #[index(btree)]
     pub conversation_id: u64,
     #[default(false)]
     pub is_deleted: bool,
+    /// Last edit time (0 = never edited)
+    #[default(0u64)]
+    pub edited_at: u64,
+    /// Shared video (0 = none). Referenced by id so the card follows the block around the spiral.
+    #[default(0u64)]
+    pub attachment_block_id: u64,
+    /// Shared topic, or the topic of the shared video (0 = none)
+    #[default(0u64)]
+    pub attachment_topic_id: u64,
+    /// Shared comment on attachment_block_id (0 = none)
+    #[default(0u64)]
+    pub attachment_comment_id: u64,
+}
+
+/// One emoji reaction per user per message; reacting again replaces it.
+#[table(accessor = message_reaction, public)]
+#[derive(Clone)]
+pub struct MessageReaction {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub message_id: u64,
+    pub identity: String,
+    pub emoji: String,
+    pub created_at: u64,
 }
 
 #[table(accessor = user_follow, public)]
@@ -319,11 +546,29 @@ pub struct UserFollow {
     #[primary_key]
     #[auto_inc]
     pub id: u64,
+    #[index(btree)]
     pub follower_identity: String,
+    #[index(btree)]
     pub following_identity: String,
     pub created_at: u64,
 }
 
+/// A pending (or answered) request to follow a private account.
+#[table(accessor = follow_request, public)]
+#[derive(Clone)]
+pub struct FollowRequest {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub requester_identity: String,
+    #[index(btree)]
+    pub target_identity: String,
+    /// "pending" | "approved" | "rejected"
+    pub status: String,
+    pub created_at: u64,
+    pub responded_at: u64,
+}
+
 #[table(accessor = conversation, public)]
 #[derive(Clone)]
 pub struct Conversation {
@@ -340,6 +585,47 @@ pub struct Conversation {
     pub request_recipient: String,
     pub created_at: u64,
     pub updated_at: u64,
+    /// Group conversations leave participant_a/b empty; members live in ConversationParticipant
+    #[default(false)]
+    pub is_group: bool,
+    /// Group name (None for 1:1)
+    #[default(None::<String>)]
+    pub title: Option<String>,
+}
+
+/// Membership of a group conversation. 1:1 conversations have no rows here.
+#[table(accessor = conversation_participant, public)]
+#[derive(Clone)]
+pub struct ConversationParticipant {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub conversation_id: u64,
+    #[index(btree)]
+    pub identity: String,
+    /// Admins can invite, remove, rename and promote
+    pub is_admin: bool,
+    pub joined_at: u64,
+}
+
+/// How far a participant has read in a conversation (1:1 or group). Messages
+/// from others with a higher id are unread; marking read is one row write.
+#[table(accessor = conversation_read_state, public)]
+#[derive(Clone)]
+pub struct ConversationReadState {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub conversation_id: u64,
+    #[index(btree)]
+    pub identity: String,
+    pub last_read_message_id: u64,
+    pub last_read_at: u64,
+    /// delete_conversation marker: messages up to this id are hidden for this participant only
+    #[default(0u64)]
+    pub hidden_through_message_id: u64,
 }
 
 #[table(accessor = contest_winner, public)]
@@ -364,7 +650,9 @@ pub struct UserBlock {
     #[primary_key]
     #[auto_inc]
     pub id: u64,
+    #[index(btree)]
     pub blocker_identity: String,
+    #[index(btree)]
     pub blocked_identity: String,
     pub created_at: u64,
 }
@@ -375,9 +663,23 @@ pub struct UserMute {
     #[primary_key]
     #[auto_inc]
     pub id: u64,
+    #[index(btree)]
     pub muter_identity: String,
     pub muted_identity: String,
     pub created_at: u64,
+    /// null = until unmuted; otherwise removed by expire_user_mute
+    #[default(None::<u64>)]
+    pub expires_at: Option<u64>,
+}
+
+/// One-shot schedule that ends a time-limited mute.
+#[table(accessor = user_mute_expiry, scheduled(expire_user_mute))]
+pub struct UserMuteExpiry {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub mute_id: u64,
 }
 
 #[table(accessor = user_report, public)]
@@ -386,16 +688,128 @@ pub struct UserReport {
     #[primary_key]
     #[auto_inc]
     pub id: u64,
+    #[index(btree)]
     pub reporter_identity: String,
     pub reported_identity: String,
     /// "spam" | "harassment" | "hate_speech" | "impersonation" | "other"
     pub reason: String,
     pub description: String,
-    /// "pending" | "reviewed" | "dismissed"
+    /// "pending" | "escalated" | "upheld" (content removed or author sanctioned)
+    /// | "reviewed" (closed without action) | "dismissed"
     pub status: String,
     pub reviewed_by: String,
     pub created_at: u64,
     pub reviewed_at: u64,
+    /// "block" | "comment" for content reports, where reported_identity is the author.
+    /// None for user reports, including every report filed before content reports existed.
+    #[default(None::<String>)]
+    pub target_type: Option<String>,
+    #[default(0u64)]
+    pub block_id: u64,
+    #[default(0u64)]
+    pub comment_id: u64,
+    /// Topic the reported content belongs to (0 = site-wide user report)
+    #[default(0u64)]
+    pub topic_id: u64,
+    /// ModerationQueueItem this report was grouped into
+    #[default(0u64)]
+    pub queue_item_id: u64,
+    /// Action taken by review_report: None (unresolved) | "dismissed" | "reviewed" | "warn"
+    /// | "hide_content" | "suspend" | "ban_from_topic" | "escalate"
+    #[default(None::<String>)]
+    pub resolution: Option<String>,
+}
+
+/// One strike per upheld report against a user. The running count drives
+/// automatic escalation to site-wide suspension (see sanctions::add_strike).
+#[table(accessor = user_strike, public)]
+#[derive(Clone)]
+pub struct UserStrike {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub identity: String,
+    pub report_id: u64,
+    /// review_report action that issued the strike
+    pub action: String,
+    pub issued_by: String,
+    pub created_at: u64,
+}
+
+/// Combined moderation queue: one open item per reported target, grouping all of
+/// its reports. Topic-scoped items are handled by that topic's moderators;
+/// items with topic_id = 0 (user reports) go to global admins.
+#[table(accessor = moderation_queue_item, public)]
+#[derive(Clone)]
+pub struct ModerationQueueItem {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// "user" | "block" | "comment"
+    pub target_type: String,
+    pub topic_id: u64,
+    pub block_id: u64,
+    pub comment_id: u64,
+    pub reported_identity: String,
+    pub report_count: u64,
+    /// Reason of the most recent report
+    pub last_reason: String,
+    /// "open" | "resolved" | "dismissed"
+    pub status: String,
+    /// resolve_moderation_item action, or the review_report action that closed it
+    pub resolution: String,
+    pub resolved_by: String,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub resolved_at: u64,
+    /// Handed from topic moderators to site admins via review_report "escalate"
+    #[default(false)]
+    pub escalated: bool,
+    /// Content was hidden by auto-moderation (ContentHide reason "auto_moderation") or
+    /// held by the keyword filter ("filter_hold"); restored if the item is
+    /// dismissed or reviewed without action.
+    #[default(false)]
+    pub auto_hidden: bool,
+}
+
+/// Keyword / link filter entry applied by reducers::filter to comments, messages,
+/// topic titles and profile text. topic_id = 0 rows are admin-managed and apply
+/// site-wide; topic rows are set by that topic's moderators and override a global
+/// row with the same term and match_mode inside the topic ("allow" disables it).
+#[table(accessor = blocked_term, public)]
+#[derive(Clone)]
+pub struct BlockedTerm {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub term: String,
+    /// "exact" (case-insensitive whole word / phrase) | "regex"
+    pub match_mode: String,
+    /// "reject" | "hold" | "mask" | "allow" (topic overrides only)
+    pub action: String,
+    /// 0 = global
+    pub topic_id: u64,
+    pub created_by: String,
+    pub created_at: u64,
+}
+
+/// Singleton (id = 0) auto-moderation settings, edited by admins via
+/// set_auto_moderation_config. Weights are in percent of a fully trusted
+/// reporter, so min_weight = 300 means "three trusted reporters".
+#[table(accessor = auto_moderation_config, public)]
+#[derive(Clone)]
+pub struct AutoModerationConfig {
+    #[primary_key]
+    pub id: u32,
+    pub enabled: bool,
+    pub block_min_reporters: u64,
+    pub block_min_weight: u64,
+    pub comment_min_reporters: u64,
+    pub comment_min_weight: u64,
+    /// Reporters whose account is younger than this count at reduced weight
+    pub new_account_hours: u64,
+    pub updated_by: String,
+    pub updated_at: u64,
 }
 
 // ─── API Keys ───────────────────────────────────────────────────────────────
@@ -434,19 +848,60 @@ pub struct ApiUsageLog {
     pub created_at: u64,
 }
 
-/// A user following a topic for updates.
-#[table(accessor = topic_follow, public)]
+/// A user following a topic for updates. `by_topic_and_id` lets fan-outs resume
+/// after a follow id without rescanning the topic's earlier followers.
+#[table(accessor = topic_follow, public, index(accessor = by_topic_and_id, btree(columns = [topic_id, id])))]
 #[derive(Clone)]
 pub struct TopicFollow {
     #[primary_key]
     #[auto_inc]
     pub id: u64,
+    #[index(btree)]
     pub follower_identity: String,
+    #[index(btree)]
     pub topic_id: u64,
     #[default(0u64)]
     pub created_at: u64,
 }
 
+/// One batch of new_video_in_topic notifications for a newly claimed block.
+/// Each run notifies followers with TopicFollow.id > after_follow_id and
+/// schedules the next batch until every follower has been reached.
+#[table(accessor = topic_video_fanout, scheduled(fan_out_topic_video))]
+pub struct TopicVideoFanout {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub block_id: u64,
+    pub after_follow_id: u64,
+}
+
+/// A message from a topic's owner to everyone following the topic.
+#[table(accessor = topic_announcement, public)]
+#[derive(Clone)]
+pub struct TopicAnnouncement {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub topic_id: u64,
+    pub author_identity: String,
+    pub text: String,
+    pub created_at: u64,
+}
+
+/// One batch of topic_announcement notifications; same cursoring as TopicVideoFanout.
+#[table(accessor = topic_announcement_fanout, scheduled(fan_out_topic_announcement))]
+pub struct TopicAnnouncementFanout {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub announcement_id: u64,
+    pub after_follow_id: u64,
+}
+
 /// A saved/bookmarked block (video) by a user.
 #[table(accessor = saved_block, public)]
 #[derive(Clone)]
@@ -474,4 +929,261 @@ pub struct TopicBan {
     pub reason: String,
     #[default(0u64)]
     pub created_at: u64,
+    /// None = permanent; Some = microsecond timestamp when the ban is lifted automatically
+    #[default(None::<u64>)]
+    pub expires_at: Option<u64>,
+    /// What happened to the user's existing content in the topic: "keep" | "hide" | "remove".
+    /// Hidden content is restored when the ban is lifted. None on bans created before this column.
+    #[default(None::<String>)]
+    pub content_action: Option<String>,
+}
+
+/// One-shot schedule that lifts a time-limited topic ban when it expires.
+#[table(accessor = topic_ban_expiry, scheduled(expire_topic_ban))]
+pub struct TopicBanExpiry {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub ban_id: u64,
+}
+
+/// A banned user's request to have a topic ban lifted.
+#[table(accessor = topic_ban_appeal, public)]
+#[derive(Clone)]
+pub struct TopicBanAppeal {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub ban_id: u64,
+    pub topic_id: u64,
+    pub appellant_identity: String,
+    pub message: String,
+    /// "pending" | "approved" | "rejected"
+    pub status: String,
+    pub reviewed_by: String,
+    pub review_note: String,
+    pub created_at: u64,
+    pub reviewed_at: u64,
+}
+
+// ─── Audit Log ──────────────────────────────────────────────────────────────
+
+/// Append-only record of every privileged (admin / moderator) action.
+/// Private: admins read it through the `query_audit_log_*` reducers.
+#[table(accessor = audit_log)]
+#[derive(Clone)]
+pub struct AuditLog {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub actor_identity: String,
+    /// Reducer name, e.g. "set_admin", "mod_remove_block"
+    pub action: String,
+    /// "user" | "block" | "topic" | "topic_moderator" | "user_report" | "ad_placement" | "contest"
+    pub target_type: String,
+    pub target_id: String,
+    /// JSON snapshot of the affected state before the action ("" when not applicable)
+    pub before_json: String,
+    /// JSON snapshot of the affected state after the action ("" when not applicable)
+    pub after_json: String,
+    pub created_at: u64,
+}
+
+/// Result rows of the last audit log query run by an admin.
+/// Replaced wholesale on every query. Private: clients read their own rows
+/// through the `my_audit_log_query_result` view.
+#[table(accessor = audit_log_query_result)]
+#[derive(Clone)]
+pub struct AuditLogQueryResult {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub viewer_identity: String,
+    pub audit_log_id: u64,
+    pub actor_identity: String,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: String,
+    pub before_json: String,
+    pub after_json: String,
+    pub created_at: u64,
+}
+
+/// A destructive admin operation awaiting confirmation by a second admin.
+#[table(accessor = pending_admin_action, public)]
+#[derive(Clone)]
+pub struct PendingAdminAction {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// "clear_all_blocks" | "clear_all_users" | "set_admin" | "finalize_contest"
+    pub action: String,
+    /// JSON arguments for the action, e.g. {"target_identity": "...", "is_admin": true}
+    pub payload_json: String,
+    pub proposed_by: String,
+    /// "pending" | "executed" | "cancelled"
+    pub status: String,
+    /// Second admin who confirmed (executed) or cancelled the action
+    pub resolved_by: String,
+    pub created_at: u64,
+    /// Confirmation must happen before this timestamp
+    pub expires_at: u64,
+    pub resolved_at: u64,
+}
+
+// ─── User Sanctions ─────────────────────────────────────────────────────────
+
+/// A site-wide sanction issued by an admin. Rows are kept after expiry for history.
+/// Private: a shadow-banned user must not be able to see their own sanction.
+#[table(accessor = user_sanction)]
+#[derive(Clone)]
+pub struct UserSanction {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub identity: String,
+    /// "suspension" (all writes rejected) | "shadow_ban" (new content visible only to the author)
+    pub sanction_type: String,
+    pub reason: String,
+    pub issued_by: String,
+    /// "active" | "expired" | "lifted"
+    pub status: String,
+    pub created_at: u64,
+    /// None = indefinite; Some = microsecond timestamp when `expire_user_sanction` ends it
+    pub expires_at: Option<u64>,
+    pub lifted_by: String,
+    pub lifted_at: u64,
+}
+
+/// One-shot schedule that ends a time-limited user sanction.
+#[table(accessor = user_sanction_expiry, scheduled(expire_user_sanction))]
+pub struct UserSanctionExpiry {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub sanction_id: u64,
+}
+
+// ─── Activity Feed ──────────────────────────────────────────────────────────
+
+/// Something a user did that shows up in their followers' feeds.
+#[table(accessor = activity_event, public)]
+#[derive(Clone)]
+pub struct ActivityEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub actor_identity: String,
+    /// "video_claimed" | "video_liked" | "comment_posted" | "comment_reposted" | "user_followed"
+    pub event_type: String,
+    #[index(btree)]
+    pub block_id: u64,
+    pub comment_id: u64,
+    pub topic_id: u64,
+    /// Followed user for "user_followed"; otherwise the owner of the block / comment
+    pub target_identity: String,
+    #[index(btree)]
+    pub created_at: u64,
+    /// true = copied into followers' feeds on write; false = actor has too many
+    /// followers and refresh_feed pulls it in on read
+    pub fanned_out: bool,
+}
+
+/// One row of a user's materialized feed. Clients subscribe with
+/// `owner_identity = <self>`. Event fields are copied so the row stands alone.
+#[table(accessor = feed_entry, public)]
+#[derive(Clone)]
+pub struct FeedEntry {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub owner_identity: String,
+    #[index(btree)]
+    pub event_id: u64,
+    pub actor_identity: String,
+    pub event_type: String,
+    pub block_id: u64,
+    pub comment_id: u64,
+    pub topic_id: u64,
+    pub target_identity: String,
+    pub created_at: u64,
+}
+
+/// When refresh_feed last pulled fan-out-on-read events for a user.
+#[table(accessor = feed_cursor)]
+pub struct FeedCursor {
+    #[primary_key]
+    pub identity: String,
+    pub refreshed_at: u64,
+}
+
+// ─── Suggestions ────────────────────────────────────────────────────────────
+
+/// A "who to follow" or "topics you may like" entry for one user. Rebuilt
+/// wholesale by refresh_suggestions / refresh_my_suggestions.
+#[table(accessor = suggestion, public)]
+#[derive(Clone)]
+pub struct Suggestion {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub identity: String,
+    /// "user" | "topic"
+    pub suggestion_type: String,
+    /// Set for "user" suggestions, empty for "topic"
+    pub suggested_identity: String,
+    /// Set for "topic" suggestions, 0 for "user"
+    pub topic_id: u64,
+    pub score: u64,
+    /// Strongest signal: "friends_of_friends" | "co_likes" | "shared_topics"
+    /// | "followed_users" (topics followed or posted in by people you follow)
+    pub reason: String,
+    pub created_at: u64,
+}
+
+/// A suggestion the user dismissed; never offered again. Exactly one of
+/// `suggested_identity` / `topic_id` is set, as on Suggestion.
+#[table(accessor = dismissed_suggestion)]
+pub struct DismissedSuggestion {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub identity: String,
+    pub suggested_identity: String,
+    pub topic_id: u64,
+    pub created_at: u64,
+}
+
+/// When a user last rebuilt their own suggestions with refresh_my_suggestions.
+#[table(accessor = suggestion_manual_refresh)]
+pub struct SuggestionManualRefresh {
+    #[primary_key]
+    pub identity: String,
+    pub refreshed_at: u64,
+}
+
+/// Repeating schedule for refresh_suggestions (at most one row).
+#[table(accessor = suggestion_refresh_schedule, scheduled(refresh_suggestions))]
+pub struct SuggestionRefreshSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Singleton (id = 0): the last identity refresh_suggestions processed, so each
+/// run picks up the next batch of users.
+#[table(accessor = suggestion_refresh_cursor)]
+pub struct SuggestionRefreshCursor {
+    #[primary_key]
+    pub id: u32,
+    pub last_identity: String,
 }
@@ -81,7 +81,7 @@ export default function ReportsManagement() {
   const handleReview = (reportId: number, action: string) => {
     const conn = getConnection();
     if (!conn) return;
-    conn.reducers.reviewReport({ reportId: BigInt(reportId), action });
+    conn.reducers.reviewReport({ reportId: BigInt(reportId), action, durationDays: BigInt(0) });
   };
 
   const filtered = filter === "all"
@@ -95,6 +95,7 @@ export default function ReportsManagement() {
   const statusColor = (status: string) => {
     switch (status) {
       case "pending": return "bg-yellow-500/20 text-yellow-400";
+      case "upheld": return "bg-red-500/20 text-red-400";
       case "reviewed": return "bg-green-500/20 text-green-400";
       case "dismissed": return "bg-gray-500/20 text-gray-400";
       default: return "bg-surface-light text-muted";
@@ -235,7 +235,7 @@ export const GET = withApiKey(async (request: NextRequest) => {
       .map((id) => `topic_id = ${id}`)
       .join(" OR ");
     const blockResults = await runSql(
-      `SELECT id, topic_id, video_id, platform, thumbnail_url, likes, dislikes, yt_views, yt_likes, owner_name FROM block WHERE (${topicIdOrClause}) AND status = 'claimed' AND video_id != '' AND platform != ''`
+      `SELECT id, topic_id, video_id, platform, thumbnail_url, likes, dislikes, yt_views, yt_likes, owner_name FROM block WHERE (${topicIdOrClause}) AND status = 'claimed' AND hidden = false AND video_id != '' AND platform != ''`
     );
 
     const blocksByTopic = new Map<number, CompareBlock[]>();
@@ -97,7 +97,7 @@ async function fetchCommentsForBlockIds(blockIds: number[]) {
   const chunkResults = await Promise.all(
     chunks.map((chunk) => {
       const where = chunk.map((id) => `block_id = ${id}`).join(" OR ");
-      return runSql(`SELECT * FROM comment WHERE ${where}`);
+      return runSql(`SELECT * FROM comment WHERE hidden = false AND (${where})`);
     })
   );
 
@@ -120,7 +120,7 @@ export const GET = withApiKey(async (request: NextRequest) => {
       minX != null && maxX != null && minY != null && maxY != null &&
       minX !== "" && maxX !== "" && minY !== "" && maxY !== "";
 
-    const conditions: string[] = [];
+    const conditions: string[] = ["hidden = false"];
     if (topicIdParam) {
       const topicIdNum = parseInt(topicIdParam, 10);
       if (!Number.isFinite(topicIdNum)) {
@@ -146,7 +146,7 @@ export const GET = withApiKey(async (request: NextRequest) => {
         `y <= ${Math.min(SAFE_VIEWPORT_LIMIT, maxYN)}`,
       );
     }
-    const blockWhere = conditions.length > 0 ? ` WHERE ${conditions.join(" AND ")}` : "";
+    const blockWhere = ` WHERE ${conditions.join(" AND ")}`;
 
     const blockResults = await runSql(`SELECT * FROM block${blockWhere}`);
     const blocks: MappedBlock[] = [];
@@ -164,7 +164,7 @@ export const GET = withApiKey(async (request: NextRequest) => {
     const blockIds = [...new Set(blocks.map((b) => b.id))];
     const commentResults = shouldScopeComments
       ? await fetchCommentsForBlockIds(blockIds)
-      : await runSql("SELECT * FROM comment");
+      : await runSql("SELECT * FROM comment WHERE hidden = false");
 
     for (const commentRes of commentResults) {
       for (const row of commentRes.rows) {
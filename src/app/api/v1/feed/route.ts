@@ -11,26 +11,29 @@ const TOPIC_COLUMNS = [
   "id", "slug", "title", "category",
 ];
 
+function parseIds(param: string | null): number[] {
+  if (!param) return [];
+  const ids = param
+    .split(",")
+    .map((s) => Number(s.trim()))
+    .filter((n) => Number.isFinite(n) && n > 0);
+  return [...new Set(ids)].slice(0, 100);
+}
+
 /**
  * GET /api/v1/feed?topicIds=1,2,3&limit=50
  * Returns recent claimed blocks from the given topic IDs, joined with topic metadata.
+ *
+ * GET /api/v1/feed?blockIds=4,5,6
+ * Returns the given claimed blocks (e.g. from the caller's feed_entry rows), joined with topic metadata.
  */
 export async function GET(request: NextRequest) {
-  const topicIdsParam = request.nextUrl.searchParams.get("topicIds");
+  const topicIds = parseIds(request.nextUrl.searchParams.get("topicIds"));
+  const blockIds = parseIds(request.nextUrl.searchParams.get("blockIds"));
   const limitParam = parseInt(request.nextUrl.searchParams.get("limit") || "50", 10);
   const limit = Math.max(1, Math.min(Number.isFinite(limitParam) ? limitParam : 50, 100));
 
-  if (!topicIdsParam) {
-    return NextResponse.json({ blocks: [], topics: {} });
-  }
-
-  const topicIds = topicIdsParam
-    .split(",")
-    .map((s) => Number(s.trim()))
-    .filter((n) => Number.isFinite(n) && n > 0);
-  const uniqueTopicIds = [...new Set(topicIds)].slice(0, 100);
-
-  if (uniqueTopicIds.length === 0) {
+  if (topicIds.length === 0 && blockIds.length === 0) {
     return NextResponse.json({ blocks: [], topics: {} });
   }
 
@@ -40,17 +43,13 @@ export async function GET(request: NextRequest) {
 
   try {
     // SpacetimeDB does not support IN (...) — build OR chains instead.
-    const topicIdClause = uniqueTopicIds.map((id) => `topic_id = ${id}`).join(" OR ");
-    const topicClause = uniqueTopicIds.map((id) => `id = ${id}`).join(" OR ");
+    const blockClause = blockIds.length > 0
+      ? blockIds.map((id) => `id = ${id}`).join(" OR ")
+      : topicIds.map((id) => `topic_id = ${id}`).join(" OR ");
 
-    const [blockResults, topicResults] = await Promise.all([
-      runSql(
-        `SELECT id, topic_id, video_id, platform, thumbnail_url, likes, dislikes, yt_views, yt_likes, owner_name, owner_identity, claimed_at FROM block WHERE (${topicIdClause}) AND status = 'claimed' AND video_id != '' AND platform != ''`
-      ),
-      runSql(
-        `SELECT id, slug, title, category FROM topic WHERE ${topicClause}`
-      ),
-    ]);
+    const blockResults = await runSql(
+      `SELECT id, topic_id, video_id, platform, thumbnail_url, likes, dislikes, yt_views, yt_likes, owner_name, owner_identity, claimed_at FROM block WHERE (${blockClause}) AND status = 'claimed' AND hidden = false AND video_id != '' AND platform != ''`
+    );
 
     // Parse blocks
     const blocks: Array<Record<string, unknown>> = [];
@@ -76,7 +75,12 @@ export async function GET(request: NextRequest) {
 
     // Sort by claimedAt descending (newest first), take limit
     blocks.sort((a, b) => (b.claimedAt as number) - (a.claimedAt as number));
-    const limited = blocks.slice(0, limit);
+    const limited = blocks.slice(0, blockIds.length > 0 ? blockIds.length : limit);
+
+    const topicIdsForBlocks = [...new Set(limited.map((b) => b.topicId as number))];
+    const topicResults = topicIdsForBlocks.length > 0
+      ? await runSql(`SELECT id, slug, title, category FROM topic WHERE ${topicIdsForBlocks.map((id) => `id = ${id}`).join(" OR ")}`)
+      : [];
 
     // Parse topics into a lookup map
     const topics: Record<number, { slug: string; title: string; category: string }> = {};
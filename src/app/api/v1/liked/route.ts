@@ -33,7 +33,7 @@ export async function GET(request: NextRequest) {
     // SpacetimeDB does not support IN (...) — build OR chains instead.
     const blockIdClause = uniqueBlockIds.map((id) => `id = ${id}`).join(" OR ");
     const blockResults = await runSql(
-      `SELECT id, topic_id, video_id, platform, thumbnail_url, likes, dislikes, yt_views, yt_likes, owner_name, owner_identity, claimed_at FROM block WHERE (${blockIdClause}) AND status = 'claimed' AND video_id != '' AND platform != ''`
+      `SELECT id, topic_id, video_id, platform, thumbnail_url, likes, dislikes, yt_views, yt_likes, owner_name, owner_identity, claimed_at FROM block WHERE (${blockIdClause}) AND status = 'claimed' AND hidden = false AND video_id != '' AND platform != ''`
     );
 
     const blocks: Array<Record<string, unknown>> = [];
@@ -24,7 +24,7 @@ export async function GET(request: NextRequest) {
   try {
     const [blockResults, topicResults] = await Promise.all([
       runSql(
-        `SELECT id, topic_id, video_id, platform, thumbnail_url, likes, dislikes, yt_views, yt_likes, owner_name, owner_identity, claimed_at FROM block WHERE status = 'claimed' AND video_id != '' AND platform != ''`
+        `SELECT id, topic_id, video_id, platform, thumbnail_url, likes, dislikes, yt_views, yt_likes, owner_name, owner_identity, claimed_at FROM block WHERE status = 'claimed' AND hidden = false AND video_id != '' AND platform != ''`
       ),
       runSql(`SELECT id, slug, title, category FROM topic WHERE is_active = true`),
     ]);
@@ -8,9 +8,9 @@ export const GET = withApiKey(async (_request: NextRequest) => {
     const [topicResults, blockResults, userResults, commentResults] =
       await Promise.all([
         runSql("SELECT id FROM topic"),
-        runSql("SELECT id FROM block WHERE status = 'claimed'"),
+        runSql("SELECT id FROM block WHERE status = 'claimed' AND hidden = false"),
         runSql("SELECT identity FROM user_profile"),
-        runSql("SELECT id FROM comment"),
+        runSql("SELECT id FROM comment WHERE hidden = false"),
       ]);
 
     return NextResponse.json({
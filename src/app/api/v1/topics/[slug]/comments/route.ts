@@ -45,7 +45,7 @@ export const GET = withApiKey(async (request: NextRequest, context) => {
           { status: 404 }
         );
       }
-      sql = `SELECT * FROM comment WHERE block_id = ${blockIdNum}`;
+      sql = `SELECT * FROM comment WHERE block_id = ${blockIdNum} AND hidden = false`;
     } else {
       // Get all block IDs for this topic, then fetch comments
       // SpacetimeDB doesn't support JOINs, so we do a two-step approach
@@ -65,7 +65,7 @@ export const GET = withApiKey(async (request: NextRequest, context) => {
       // SpacetimeDB doesn't support IN — use OR chains (limit to first 50 blocks)
       const limited = blockIds.slice(0, 50);
       const orClause = limited.map((id) => `block_id = ${id}`).join(" OR ");
-      sql = `SELECT * FROM comment WHERE ${orClause}`;
+      sql = `SELECT * FROM comment WHERE hidden = false AND (${orClause})`;
     }
 
     const commentResults = await runSql(sql);
@@ -38,7 +38,7 @@ export const GET = withApiKey(async (_request: NextRequest, context) => {
 
     // Fetch top 20 blocks by score
     const blockResults = await runSql(
-      `SELECT id, topic_id, video_id, platform, thumbnail_url, likes, dislikes, yt_views, yt_likes, owner_name, owner_identity FROM block WHERE topic_id = ${topicId} AND status = 'claimed' AND video_id != '' AND platform != ''`
+      `SELECT id, topic_id, video_id, platform, thumbnail_url, likes, dislikes, yt_views, yt_likes, owner_name, owner_identity FROM block WHERE topic_id = ${topicId} AND status = 'claimed' AND hidden = false AND video_id != '' AND platform != ''`
     );
 
     const blocks: Array<Record<string, unknown>> = [];
@@ -194,7 +194,7 @@ export const GET = withApiKey(async (_request: NextRequest) => {
     const topScores: Record<number, number> = {};
     try {
       const blockResults = await runSql(
-        "SELECT topic_id, video_id, platform, thumbnail_url, likes, dislikes FROM block WHERE status = 'claimed' AND video_id != '' AND platform != ''"
+        "SELECT topic_id, video_id, platform, thumbnail_url, likes, dislikes FROM block WHERE status = 'claimed' AND hidden = false AND video_id != '' AND platform != ''"
       );
       const blockRes = blockResults[0];
       if (blockRes) {
@@ -6,7 +6,8 @@ const USER_COLUMNS = [
   "identity", "clerk_user_id", "username", "display_name", "email",
   "stripe_account_id", "total_earnings", "credits", "is_admin", "created_at",
   "bio", "location", "website_url", "social_x", "social_youtube",
-  "social_tiktok", "social_instagram",
+  "social_tiktok", "social_instagram", "is_private", "follower_count",
+  "following_count", "topic_follow_count", "total_likes_received", "videos_posted",
 ];
 
 export const GET = withApiKey(async (_request: NextRequest, context) => {
@@ -28,22 +29,7 @@ export const GET = withApiKey(async (_request: NextRequest, context) => {
 
     const row = rowToObject(userRes.rows[0], userRes.schema, USER_COLUMNS);
 
-    // Get follow counts
     const identity = String(row.identity ?? "");
-    let followerCount = 0;
-    let followingCount = 0;
-
-    try {
-      const safeIdentity = identity.replace(/'/g, "''");
-      const [followerResults, followingResults] = await Promise.all([
-        runSql(`SELECT * FROM user_follow WHERE following_identity = '${safeIdentity}'`),
-        runSql(`SELECT * FROM user_follow WHERE follower_identity = '${safeIdentity}'`),
-      ]);
-      followerCount = followerResults[0]?.rows.length ?? 0;
-      followingCount = followingResults[0]?.rows.length ?? 0;
-    } catch {
-      // Non-fatal
-    }
 
     // Return public fields only — NO email, stripe, earnings, credits, admin, clerk
     const opt = (v: unknown) => {
@@ -64,8 +50,10 @@ export const GET = withApiKey(async (_request: NextRequest, context) => {
       socialTiktok: opt(row.social_tiktok ?? row.socialTiktok),
       socialInstagram: opt(row.social_instagram ?? row.socialInstagram),
       createdAt: Number(row.created_at ?? row.createdAt ?? 0),
-      followerCount,
-      followingCount,
+      followerCount: Number(row.follower_count ?? row.followerCount ?? 0),
+      followingCount: Number(row.following_count ?? row.followingCount ?? 0),
+      videosPosted: Number(row.videos_posted ?? row.videosPosted ?? 0),
+      totalLikesReceived: Number(row.total_likes_received ?? row.totalLikesReceived ?? 0),
     });
   } catch (err) {
     console.error("[api/v1/users]", err);
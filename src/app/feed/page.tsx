@@ -7,7 +7,7 @@ import { Header } from "@/components/ui/Header";
 import { Card, CardContent } from "@/components/ui/card";
 import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
-import { getConnection } from "@/lib/spacetimedb/client";
+import { getConnection, subscribeToFeed } from "@/lib/spacetimedb/client";
 import { useAuthStore } from "@/stores/auth-store";
 import { hotScoreBlock } from "@/lib/utils/hot-score";
 import { Flame, Clock, Eye, ThumbsUp, ThumbsDown } from "lucide-react";
@@ -34,6 +34,9 @@ interface TopicMeta {
 
 type SortMode = "newest" | "hot";
 
+/** How many distinct feed videos to resolve via the REST API. */
+const FEED_FETCH_LIMIT = 100;
+
 function fmt(n: number): string {
   if (n >= 1_000_000) return `${(n / 1_000_000).toFixed(1)}M`;
   if (n >= 1_000) return `${(n / 1_000).toFixed(1)}K`;
@@ -72,17 +75,22 @@ export default function FeedPage() {
       return;
     }
 
-    // Get followed topic IDs from WS cache
-    const followedIds: number[] = [];
+    let followed = 0;
     for (const tf of conn.db.topic_follow.iter()) {
-      if (tf.followerIdentity === user.identity) {
-        followedIds.push(Number(tf.topicId));
-      }
+      if (tf.followerIdentity === user.identity) followed++;
     }
+    for (const uf of conn.db.user_follow.iter()) {
+      if (uf.followerIdentity === user.identity) followed++;
+    }
+    setFollowedCount(followed);
 
-    setFollowedCount(followedIds.length);
+    // Newest feed entries first; several entries can point at the same video.
+    const entries = [...conn.db.feed_entry.iter()]
+      .filter((e) => e.ownerIdentity === user.identity && Number(e.blockId) > 0)
+      .sort((a, b) => Number(b.createdAt) - Number(a.createdAt));
+    const blockIds = [...new Set(entries.map((e) => Number(e.blockId)))].slice(0, FEED_FETCH_LIMIT);
 
-    if (followedIds.length === 0) {
+    if (blockIds.length === 0) {
       setBlocks([]);
       setTopics({});
       setLoading(false);
@@ -90,7 +98,7 @@ export default function FeedPage() {
     }
 
     try {
-      const res = await fetch(`/api/v1/feed?topicIds=${followedIds.join(",")}&limit=60`);
+      const res = await fetch(`/api/v1/feed?blockIds=${blockIds.join(",")}`);
       if (res.ok) {
         const data = await res.json();
         setBlocks(data.blocks || []);
@@ -105,9 +113,14 @@ export default function FeedPage() {
 
   useEffect(() => {
     if (isAuthenticated && user?.identity) {
-      // Small delay to ensure WS subscriptions are applied
-      const t = setTimeout(loadFeed, 500);
-      return () => clearTimeout(t);
+      const conn = getConnection();
+      if (!conn) {
+        setLoading(false);
+        return;
+      }
+      // Pull in entries the server doesn't fan out on write, then read the feed.
+      conn.reducers.refreshFeed({});
+      subscribeToFeed(user.identity, loadFeed);
     } else {
       setLoading(false);
     }
@@ -128,8 +141,8 @@ export default function FeedPage() {
             <h1 className="text-xl font-bold text-foreground">Your Feed</h1>
             <p className="text-xs text-muted">
               {followedCount > 0
-                ? `Videos from ${followedCount} followed topic${followedCount !== 1 ? "s" : ""}`
-                : "Follow topics to see videos here"}
+                ? `Videos from the topics and creators you follow (${followedCount})`
+                : "Follow topics or creators to see videos here"}
             </p>
           </div>
 
@@ -177,7 +190,7 @@ export default function FeedPage() {
 
         {!loading && isAuthenticated && followedCount === 0 && (
           <div className="rounded-xl border border-border bg-surface px-6 py-16 text-center">
-            <p className="mb-2 text-sm text-muted">You&apos;re not following any topics yet.</p>
+            <p className="mb-2 text-sm text-muted">You&apos;re not following any topics or creators yet.</p>
             <Link
               href="/"
               className="text-sm font-medium text-accent hover:underline"
@@ -189,7 +202,7 @@ export default function FeedPage() {
 
         {!loading && isAuthenticated && followedCount > 0 && blocks.length === 0 && (
           <div className="rounded-xl border border-border bg-surface px-6 py-16 text-center">
-            <p className="text-sm text-muted">No videos yet in your followed topics.</p>
+            <p className="text-sm text-muted">No videos yet from the topics and creators you follow.</p>
           </div>
         )}
 
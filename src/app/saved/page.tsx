@@ -6,7 +6,7 @@ import Image from "next/image";
 import { Header } from "@/components/ui/Header";
 import { Card, CardContent } from "@/components/ui/card";
 import { Badge } from "@/components/ui/badge";
-import { getConnection } from "@/lib/spacetimedb/client";
+import { findVisibleBlock, getConnection } from "@/lib/spacetimedb/client";
 import { useAuthStore } from "@/stores/auth-store";
 import { Eye, ThumbsUp, ThumbsDown, Bookmark } from "lucide-react";
 
@@ -67,7 +67,7 @@ export default function SavedPage() {
       if (sb.userIdentity !== userIdentity) continue;
 
       const blockId = Number(sb.blockId);
-      const block = conn.db.block.id.find(BigInt(blockId));
+      const block = findVisibleBlock(conn, blockId);
       if (!block || block.status !== "claimed") continue;
 
       const topicId = Number(sb.topicId);
@@ -219,13 +219,7 @@ function TopicModerationMenu({ topicId, topicCreatorIdentity }: { topicId: numbe
   const [showBanForm, setShowBanForm] = useState(false);
   const [removeConfirmIdentity, setRemoveConfirmIdentity] = useState<string | null>(null);
 
-  const reducers = (getConnection()?.reducers as unknown as {
-    applyTopicModerator?: (args: { topicId: bigint; message: string }) => void;
-    reviewTopicModeratorApplication?: (args: { applicationId: bigint; approve: boolean }) => void;
-    removeTopicModerator?: (args: { topicId: bigint; identity: string }) => void;
-    banUserFromTopic?: (args: { topicId: bigint; targetIdentity: string; reason: string }) => void;
-    unbanUserFromTopic?: (args: { topicId: bigint; targetIdentity: string }) => void;
-  }) || {};
+  const reducers = getConnection()?.reducers;
 
   // Get banned users for this topic
   const bannedUsers = (() => {
@@ -253,7 +247,7 @@ function TopicModerationMenu({ topicId, topicCreatorIdentity }: { topicId: numbe
   const submitApplication = () => {
     if (!applyMessage.trim()) return;
     setIsApplying(true);
-    reducers.applyTopicModerator?.({
+    reducers?.applyTopicModerator({
       topicId: BigInt(topicId),
       message: applyMessage.trim(),
     });
@@ -264,7 +258,7 @@ function TopicModerationMenu({ topicId, topicCreatorIdentity }: { topicId: numbe
   const handleReview = (applicationId: number, approve: boolean) => {
     setBusyId(applicationId);
     try {
-      reducers.reviewTopicModeratorApplication?.({
+      reducers?.reviewTopicModeratorApplication({
         applicationId: BigInt(applicationId),
         approve,
       });
@@ -279,7 +273,7 @@ function TopicModerationMenu({ topicId, topicCreatorIdentity }: { topicId: numbe
 
   const confirmRemoveModerator = () => {
     if (!removeConfirmIdentity) return;
-    reducers.removeTopicModerator?.({
+    reducers?.removeTopicModerator({
       topicId: BigInt(topicId),
       identity: removeConfirmIdentity,
     });
@@ -465,7 +459,7 @@ function TopicModerationMenu({ topicId, topicCreatorIdentity }: { topicId: numbe
                           variant="ghost"
                           size="sm"
                           className="h-5 shrink-0 px-1.5 text-[10px] text-green-400 hover:text-green-300"
-                          onClick={() => reducers.unbanUserFromTopic?.({ topicId: BigInt(topicId), targetIdentity: ban.bannedIdentity })}
+                          onClick={() => reducers?.unbanUserFromTopic({ topicId: BigInt(topicId), targetIdentity: ban.bannedIdentity })}
                         >
                           Unban
                         </Button>
@@ -513,7 +507,7 @@ function TopicModerationMenu({ topicId, topicCreatorIdentity }: { topicId: numbe
                                   }
                                 }
                               }
-                              reducers.banUserFromTopic?.({ topicId: BigInt(topicId), targetIdentity: identity, reason: banReason.trim() });
+                              reducers?.banUserFromTopic({ topicId: BigInt(topicId), targetIdentity: identity, reason: banReason.trim(), durationHours: BigInt(0), contentAction: "keep" });
                               setBanTarget("");
                               setBanReason("");
                               setShowBanForm(false);
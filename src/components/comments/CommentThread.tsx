@@ -1,9 +1,10 @@
 "use client";
 
-import { useState } from "react";
+import { useEffect, useState } from "react";
 import { MessageCircle, Flame, Clock, TrendingUp } from "lucide-react";
 import { Separator } from "@/components/ui/separator";
 import { useCommentsStore, type Comment } from "@/stores/comments-store";
+import { getConnection } from "@/lib/spacetimedb/client";
 import { CommentComposer } from "./CommentComposer";
 import { CommentItem } from "./CommentItem";
 import { cn } from "@/lib/utils";
@@ -37,8 +38,23 @@ interface Props {
 export function CommentThread({ blockId, className }: Props) {
   const [sortMode, setSortMode] = useState<CommentSort>("hot");
   const getTopLevelComments = useCommentsStore((s) => s.getTopLevelComments);
+  // Re-render as my comment view fills in, so muted authors drop out.
+  useCommentsStore((s) => s.viewCommentIds);
   const topLevel = sortComments(getTopLevelComments(blockId), sortMode);
 
+  // Ask the server for my view of this block's comments (muted authors left
+  // out); the entries arrive through the my_comment_view_entry subscription.
+  useEffect(() => {
+    const conn = getConnection();
+    if (!conn) return;
+    useCommentsStore.getState().setViewBlock(blockId);
+    conn.reducers.loadCommentView({ blockId: BigInt(blockId) });
+    return () => {
+      useCommentsStore.getState().setViewBlock(null);
+      getConnection()?.reducers.clearCommentView({});
+    };
+  }, [blockId]);
+
   const SORT_TABS: { key: CommentSort; icon: typeof Flame; label: string }[] = [
     { key: "hot", icon: Flame, label: "Hot" },
     { key: "new", icon: Clock, label: "New" },
@@ -7,6 +7,7 @@ import { Textarea } from "@/components/ui/textarea";
 import { ScrollArea } from "@/components/ui/scroll-area";
 import { useMessagesStore, type DirectMessage } from "@/stores/messages-store";
 import { getConnection } from "@/lib/spacetimedb/client";
+import { MessageAttachmentCard } from "./MessageAttachmentCard";
 import { MessageRequestCard } from "./MessageRequestCard";
 import { timeAgo } from "@/lib/utils/time";
 
@@ -130,17 +131,21 @@ export function ChatPane({
                   key={msg.id}
                   className={`flex flex-col gap-0.5 ${isMine ? "items-end" : "items-start"}`}
                 >
-                  <div
-                    className={`max-w-[75%] rounded-2xl px-4 py-2.5 text-sm leading-relaxed ${
-                      isMine
-                        ? "rounded-br-md bg-accent text-white"
-                        : "rounded-bl-md bg-accent/10 text-foreground"
-                    }`}
-                  >
-                    {msg.text}
-                  </div>
+                  {msg.text && (
+                    <div
+                      className={`max-w-[75%] rounded-2xl px-4 py-2.5 text-sm leading-relaxed ${
+                        isMine
+                          ? "rounded-br-md bg-accent text-white"
+                          : "rounded-bl-md bg-accent/10 text-foreground"
+                      }`}
+                    >
+                      {msg.text}
+                    </div>
+                  )}
+                  <MessageAttachmentCard message={msg} />
                   <span className="px-1 text-[10px] text-muted-foreground">
                     {timeAgo(msg.createdAt)}
+                    {msg.editedAt > 0 && " · edited"}
                   </span>
                 </div>
               );
@@ -1,6 +1,6 @@
 "use client";
 
-import type { Conversation } from "@/stores/messages-store";
+import type { Conversation, DirectMessage } from "@/stores/messages-store";
 import { timeAgoCompact } from "@/lib/utils/time";
 
 function Avatar({ name }: { name: string }) {
@@ -17,6 +17,12 @@ function Avatar({ name }: { name: string }) {
   );
 }
 
+function previewAttachment(message: DirectMessage): string {
+  if (message.attachmentBlockId) return "Shared a video";
+  if (message.attachmentTopicId) return "Shared a topic";
+  return "";
+}
+
 interface Props {
   conversation: Conversation;
   isSelected: boolean;
@@ -57,7 +63,7 @@ export function ConversationItem({ conversation, isSelected, myIdentity, onClick
           }`}
         >
           {lastMessage
-            ? `${lastMessage.senderIdentity === myIdentity ? "You: " : ""}${lastMessage.text}`
+            ? `${lastMessage.senderIdentity === myIdentity ? "You: " : ""}${lastMessage.text || previewAttachment(lastMessage)}`
             : "No messages yet"}
         </p>
       </div>
@@ -0,0 +1,47 @@
+"use client";
+
+import Link from "next/link";
+import type { DirectMessage } from "@/stores/messages-store";
+import { findVisibleBlock, getConnection } from "@/lib/spacetimedb/client";
+
+/**
+ * A shared video or topic, looked up live from the subscribed tables by id —
+ * so the card keeps pointing at the block after it moves in the spiral, and
+ * shows as unavailable once it is removed.
+ */
+export function MessageAttachmentCard({ message }: { message: DirectMessage }) {
+  const { attachmentBlockId, attachmentTopicId, attachmentCommentId } = message;
+  if (!attachmentBlockId && !attachmentTopicId) return null;
+
+  const conn = getConnection();
+  const topic = conn?.db.topic?.id?.find(BigInt(attachmentTopicId));
+  const block = conn && attachmentBlockId ? findVisibleBlock(conn, attachmentBlockId) : undefined;
+
+  const unavailable = !topic || (attachmentBlockId > 0 && (!block || block.status !== "claimed"));
+  if (unavailable) {
+    return (
+      <div className="max-w-[75%] rounded-xl border border-border/40 px-3 py-2 text-xs text-muted-foreground">
+        This content is no longer available
+      </div>
+    );
+  }
+
+  const href = block ? `/t/${topic.slug}?block=${attachmentBlockId}` : `/t/${topic.slug}`;
+
+  return (
+    <Link
+      href={href}
+      className="flex max-w-[75%] items-center gap-3 rounded-xl border border-border/40 bg-accent/5 p-2 text-sm transition-colors hover:bg-accent/10"
+    >
+      {block?.thumbnailUrl && (
+        <img src={block.thumbnailUrl} alt="" className="h-12 w-20 shrink-0 rounded-md object-cover" />
+      )}
+      <div className="min-w-0">
+        <p className="truncate font-medium text-foreground">{topic.title}</p>
+        <p className="truncate text-xs text-muted-foreground">
+          {block ? `Video by ${block.ownerName}${attachmentCommentId ? " · comment" : ""}` : "Topic"}
+        </p>
+      </div>
+    </Link>
+  );
+}
@@ -25,8 +25,7 @@ export function MessageRequestCard({
     if (!conn) return;
     setLoading(true);
     try {
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      (conn.reducers as any).acceptMessageRequest({ conversationId });
+      conn.reducers.acceptMessageRequest({ conversationId: BigInt(conversationId) });
       onAccepted?.();
     } finally {
       setLoading(false);
@@ -38,8 +37,7 @@ export function MessageRequestCard({
     if (!conn) return;
     setLoading(true);
     try {
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      (conn.reducers as any).declineMessageRequest({ conversationId });
+      conn.reducers.declineMessageRequest({ conversationId: BigInt(conversationId) });
       onDeclined?.();
     } finally {
       setLoading(false);
@@ -7,6 +7,7 @@ import { ScrollArea } from "@/components/ui/scroll-area";
 import { Separator } from "@/components/ui/separator";
 import { useMessagesStore, type DirectMessage } from "@/stores/messages-store";
 import { getConnection } from "@/lib/spacetimedb/client";
+import { MessageAttachmentCard } from "./MessageAttachmentCard";
 import { timeAgo } from "@/lib/utils/time";
 
 function Avatar({ name }: { name: string }) {
@@ -102,17 +103,21 @@ export function MessageThread({ otherIdentity, otherName, onBack }: Props) {
                   key={msg.id}
                   className={`flex flex-col gap-0.5 ${isMine ? "items-end" : "items-start"}`}
                 >
-                  <div
-                    className={`max-w-[85%] rounded-2xl px-3 py-2 text-sm leading-snug ${
-                      isMine
-                        ? "rounded-br-sm bg-accent text-white"
-                        : "rounded-bl-sm bg-accent/10 text-foreground"
-                    }`}
-                  >
-                    {msg.text}
-                  </div>
+                  {msg.text && (
+                    <div
+                      className={`max-w-[85%] rounded-2xl px-3 py-2 text-sm leading-snug ${
+                        isMine
+                          ? "rounded-br-sm bg-accent text-white"
+                          : "rounded-bl-sm bg-accent/10 text-foreground"
+                      }`}
+                    >
+                      {msg.text}
+                    </div>
+                  )}
+                  <MessageAttachmentCard message={msg} />
                   <span className="text-[10px] text-muted-foreground">
                     {timeAgo(msg.createdAt)}
+                    {msg.editedAt > 0 && " · edited"}
                   </span>
                 </div>
               );
@@ -2,14 +2,14 @@
 
 import { useMemo } from "react";
 import { useRouter } from "next/navigation";
-import { Heart, MessageCircle, Repeat2, CheckCheck, ThumbsUp, Mail, UserPlus, MessageSquare, Trophy, Video, Shield } from "lucide-react";
+import { Heart, MessageCircle, Repeat2, CheckCheck, ThumbsUp, Mail, UserPlus, MessageSquare, Trophy, Video, Shield, Megaphone, Inbox } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { ScrollArea } from "@/components/ui/scroll-area";
 import { Separator } from "@/components/ui/separator";
 import { useNotificationsStore, type NotificationType, type Notification } from "@/stores/notifications-store";
 import { useModerationStore } from "@/stores/moderation-store";
 import { useAuthStore } from "@/stores/auth-store";
-import { getConnection } from "@/lib/spacetimedb/client";
+import { findVisibleBlock, getConnection } from "@/lib/spacetimedb/client";
 
 function timeAgo(ts: number): string {
   const s = Math.floor((Date.now() - ts / 1000) / 1000);
@@ -26,15 +26,20 @@ function NotificationIcon({ type }: { type: NotificationType }) {
     case "video_like": return <ThumbsUp className="h-3.5 w-3.5 text-amber-500" />;
     case "new_message": return <Mail className="h-3.5 w-3.5 text-blue-500" />;
     case "message_request": return <MessageSquare className="h-3.5 w-3.5 text-amber-500" />;
-    case "new_follow": return <UserPlus className="h-3.5 w-3.5 text-violet-500" />;
-    case "topic_new_video": return <Video className="h-3.5 w-3.5 text-cyan-500" />;
+    case "new_follow":
+    case "follow_request":
+    case "follow_request_approved": return <UserPlus className="h-3.5 w-3.5 text-violet-500" />;
+    case "new_video_in_topic": return <Video className="h-3.5 w-3.5 text-cyan-500" />;
+    case "topic_announcement": return <Megaphone className="h-3.5 w-3.5 text-cyan-500" />;
     case "contest_result": return <Trophy className="h-3.5 w-3.5 text-yellow-500" />;
     case "moderator_application_reviewed": return <Shield className="h-3.5 w-3.5 text-green-500" />;
+    case "notification_digest": return <Inbox className="h-3.5 w-3.5 text-sky-500" />;
     default: return <MessageCircle className="h-3.5 w-3.5 text-sky-500" />;
   }
 }
 
-function notificationLabel(type: NotificationType, actorName: string): string {
+function notificationLabel(notif: Notification): string {
+  const { notificationType: type, actorName } = notif;
   switch (type) {
     case "comment_like": return `${actorName} liked your comment`;
     case "comment_reply": return `${actorName} replied to your comment`;
@@ -43,9 +48,14 @@ function notificationLabel(type: NotificationType, actorName: string): string {
     case "new_message": return `${actorName} sent you a message`;
     case "message_request": return `${actorName} wants to send you a message`;
     case "new_follow": return `${actorName} followed you`;
-    case "topic_new_video": return `New video in a topic you follow`;
+    case "follow_request": return `${actorName} requested to follow you`;
+    case "follow_request_approved": return `${actorName} accepted your follow request`;
+    case "new_video_in_topic": return `New video in a topic you follow`;
+    case "topic_announcement": return `${actorName} posted an announcement in a topic you follow`;
     case "contest_result": return `Contest results are in!`;
     case "moderator_application_reviewed": return `Your moderator application was reviewed`;
+    case "notification_digest":
+      return `Your digest: ${notif.actorCount} ${notif.actorCount === 1 ? "update" : "updates"} since last time`;
     default: return `${actorName} interacted with your content`;
   }
 }
@@ -55,13 +65,22 @@ function resolveBlockTopicSlug(blockId: number): string | null {
   if (!blockId) return null;
   const conn = getConnection();
   if (!conn) return null;
-  const block = conn.db.block?.id?.find(BigInt(blockId));
+  const block = findVisibleBlock(conn, blockId);
   if (!block) return null;
   const topicId = Number(block.topicId);
   const topic = conn.db.topic?.id?.find(BigInt(topicId));
   return topic?.slug ?? null;
 }
 
+/** Resolve a topicId to its slug */
+function resolveTopicSlug(topicId: number): string | null {
+  if (!topicId) return null;
+  const conn = getConnection();
+  if (!conn) return null;
+  const topic = conn.db.topic?.id?.find(BigInt(topicId));
+  return topic?.slug ?? null;
+}
+
 /** Resolve an actor identity to their username */
 function resolveUsername(identity: string): string | null {
   const conn = getConnection();
@@ -76,15 +95,21 @@ function getNotificationHref(notif: Notification): string | null {
     case "comment_like":
     case "comment_repost":
     case "video_like":
-    case "topic_new_video": {
+    case "new_video_in_topic": {
       const slug = resolveBlockTopicSlug(notif.blockId);
       if (slug) return `/t/${slug}?block=${notif.blockId}`;
       return null;
     }
+    case "topic_announcement": {
+      const slug = resolveTopicSlug(notif.topicId);
+      return slug ? `/t/${slug}` : null;
+    }
     case "new_message":
     case "message_request":
       return "/messages";
-    case "new_follow": {
+    case "new_follow":
+    case "follow_request":
+    case "follow_request_approved": {
       const username = resolveUsername(notif.actorIdentity);
       return username ? `/u/${username}` : null;
     }
@@ -127,7 +152,7 @@ export function NotificationPanel() {
     () =>
       [...notificationsMap.values()]
         .sort((a, b) => b.createdAt - a.createdAt)
-        .filter((n) => !hiddenIds.has(n.actorIdentity)),
+        .filter((n) => !n.actorMuted && !hiddenIds.has(n.actorIdentity)),
     [notificationsMap, hiddenIds]
   );
 
@@ -198,7 +223,7 @@ export function NotificationPanel() {
                   <div className="flex items-center gap-1.5">
                     <NotificationIcon type={notif.notificationType} />
                     <p className="text-xs leading-snug text-foreground/90">
-                      {notificationLabel(notif.notificationType, notif.actorName)}
+                      {notificationLabel(notif)}
                     </p>
                   </div>
                   <p className="mt-0.5 text-[11px] text-muted-foreground">
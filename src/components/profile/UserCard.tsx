@@ -132,7 +132,7 @@ export function UserCard({ profileUser, isOwnProfile, stats }: UserCardProps) {
     if (isMuted) {
       conn.reducers.unmuteUser({ targetIdentity: profileUser.identity });
     } else {
-      conn.reducers.muteUser({ targetIdentity: profileUser.identity });
+      conn.reducers.muteUser({ targetIdentity: profileUser.identity, durationHours: BigInt(0) });
     }
   };
 
@@ -1,7 +1,7 @@
 "use client";
 
 import { useEffect, useRef, type ReactNode } from "react";
-import { connect, disconnect, reconnect, getConnection, subscribeToNotifications, subscribeToMessages, subscribeToFollows, subscribeToConversations, subscribeToUserBlockRelationships, subscribeToUserMutes, type ConnectionCallbacks } from "@/lib/spacetimedb/client";
+import { connect, disconnect, reconnect, getConnection, findVisibleBlock, subscribeToNotifications, subscribeToMessages, subscribeToFollows, subscribeToConversations, subscribeToUserBlockRelationships, subscribeToUserMutes, type ConnectionCallbacks } from "@/lib/spacetimedb/client";
 import { useBlocksStore, type Block as StoreBlock } from "@/stores/blocks-store";
 import {
   useTopicStore,
@@ -12,76 +12,42 @@ import {
 } from "@/stores/topic-store";
 import { useContestStore } from "@/stores/contest-store";
 import { useAuthStore } from "@/stores/auth-store";
-import { useCommentsStore } from "@/stores/comments-store";
-import { useNotificationsStore } from "@/stores/notifications-store";
-import { useMessagesStore, type ConversationMeta } from "@/stores/messages-store";
+import { useCommentsStore, type Comment } from "@/stores/comments-store";
+import { useNotificationsStore, type Notification, type NotificationType } from "@/stores/notifications-store";
+import { useMessagesStore, type ConversationMeta, type DirectMessage as StoreDirectMessage, type ReadCursor } from "@/stores/messages-store";
 import { useFollowsStore } from "@/stores/follows-store";
 import { useModerationStore } from "@/stores/moderation-store";
 import { BlockStatus, ContestStatus, Platform } from "@/lib/constants";
 import { batchSpiralCoordinates } from "@/lib/canvas/spiral-layout";
 import { useAuth } from "@/components/auth/AuthProvider";
 import type { DbConnection } from "@/module_bindings";
-
-type NumericLike = number | string | bigint;
-
-type DirectMessageLikeRow = {
-  id: NumericLike;
-  conversationId?: NumericLike;
-  senderIdentity: string;
-  recipientIdentity: string;
-  text: string;
-  isRead: boolean;
-  isDeleted?: boolean;
-  createdAt: NumericLike;
-};
-
-type UserFollowLikeRow = {
-  id: NumericLike;
-  followerIdentity: string;
-  followingIdentity: string;
-  createdAt: NumericLike;
-};
-
-type ConversationLikeRow = {
-  id: NumericLike;
-  participantA: string;
-  participantB: string;
-  status: ConversationMeta["status"];
-  requestRecipient: string;
-  createdAt: NumericLike;
-  updatedAt: NumericLike;
-};
-
-type FollowRow = {
-  id: number | bigint;
-  followerIdentity: string;
-  followingIdentity: string;
-  createdAt: number | bigint;
-};
-
-type ConversationRow = {
-  id: number | bigint;
-  participantA: string;
-  participantB: string;
-  status: "active" | "request_pending" | "request_declined" | string;
-  requestRecipient: string;
-  createdAt: number | bigint;
-  updatedAt: number | bigint;
-};
-
-type OptionalRealtimeTables = {
-  user_follow?: {
-    iter: () => Iterable<FollowRow>;
-    onInsert: (handler: (_ctx: unknown, row: FollowRow) => void) => void;
-    onDelete: (handler: (_ctx: unknown, row: FollowRow) => void) => void;
-  };
-  conversation?: {
-    iter: () => Iterable<ConversationRow>;
-    onInsert: (handler: (_ctx: unknown, row: ConversationRow) => void) => void;
-    onUpdate: (handler: (_ctx: unknown, _old: ConversationRow, row: ConversationRow) => void) => void;
-    onDelete: (handler: (_ctx: unknown, row: ConversationRow) => void) => void;
+import type {
+  Conversation,
+  ConversationReadState,
+  DirectMessage,
+  Notification as NotificationRow,
+} from "@/module_bindings/types";
+
+function toReadCursor(row: ConversationReadState): ReadCursor {
+  return {
+    lastReadMessageId: Number(row.lastReadMessageId),
+    hiddenThroughMessageId: Number(row.hiddenThroughMessageId),
   };
-};
+}
+
+/**
+ * Hidden content is only shown to its author (the visible_block and
+ * visible_comment views already leave it out for everyone else); blocks taken
+ * off the grid are not shown at all.
+ */
+function isHiddenFromViewer(
+  conn: DbConnection,
+  row: { hidden: boolean; status?: string },
+  authorIdentity: string,
+): boolean {
+  if (row.status === "hidden") return true;
+  return row.hidden && authorIdentity !== conn.identity?.toHexString();
+}
 
 // eslint-disable-next-line @typescript-eslint/no-explicit-any
 function mapBlock(row: any): StoreBlock {
@@ -179,19 +145,35 @@ function toConversationStatus(
       return "active";
   }
 }
-type UserBlockRowLike = {
-  id: NumericLike;
-  blockerIdentity: string;
-  blockedIdentity: string;
-  createdAt: NumericLike;
-};
-
-type UserMuteRowLike = {
-  id: NumericLike;
-  muterIdentity: string;
-  mutedIdentity: string;
-  createdAt: NumericLike;
-};
+
+function mapDirectMessage(row: DirectMessage): StoreDirectMessage {
+  return {
+    id: Number(row.id),
+    conversationId: Number(row.conversationId),
+    senderIdentity: row.senderIdentity,
+    recipientIdentity: row.recipientIdentity,
+    text: row.text,
+    isRead: row.isRead,
+    isDeleted: row.isDeleted,
+    editedAt: Number(row.editedAt),
+    attachmentBlockId: Number(row.attachmentBlockId),
+    attachmentTopicId: Number(row.attachmentTopicId),
+    attachmentCommentId: Number(row.attachmentCommentId),
+    createdAt: Number(row.createdAt),
+  };
+}
+
+function mapConversation(row: Conversation): ConversationMeta {
+  return {
+    id: Number(row.id),
+    participantA: row.participantA,
+    participantB: row.participantB,
+    status: toConversationStatus(row.status),
+    requestRecipient: row.requestRecipient,
+    createdAt: Number(row.createdAt),
+    updatedAt: Number(row.updatedAt),
+  };
+}
 
 let statsDebounceTimer: ReturnType<typeof setTimeout> | null = null;
 
@@ -251,23 +233,29 @@ function bulkLoadTopicModeratorApplications(conn: DbConnection) {
   useTopicStore.getState().setModeratorApplications(all);
 }
 
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+function mapComment(row: any): Comment {
+  return {
+    id: Number(row.id),
+    blockId: Number(row.blockId),
+    userIdentity: row.userIdentity,
+    userName: row.userName,
+    text: row.text,
+    createdAt: Number(row.createdAt),
+    parentCommentId: row.parentCommentId != null ? Number(row.parentCommentId) : null,
+    repostOfId: row.repostOfId != null ? Number(row.repostOfId) : null,
+    likesCount: Number(row.likesCount ?? 0),
+    repliesCount: Number(row.repliesCount ?? 0),
+    repostsCount: Number(row.repostsCount ?? 0),
+    editedAt: Number(row.editedAt ?? 0),
+  };
+}
+
 function bulkLoadComments(conn: DbConnection) {
   const all = [];
-  for (const row of conn.db.comment.iter()) {
-    all.push({
-      id: Number(row.id),
-      blockId: Number(row.blockId),
-      userIdentity: row.userIdentity,
-      userName: row.userName,
-      text: row.text,
-      createdAt: Number(row.createdAt),
-      parentCommentId: row.parentCommentId != null ? Number(row.parentCommentId) : null,
-      repostOfId: row.repostOfId != null ? Number(row.repostOfId) : null,
-      likesCount: Number(row.likesCount ?? 0),
-      repliesCount: Number(row.repliesCount ?? 0),
-      repostsCount: Number(row.repostsCount ?? 0),
-      editedAt: Number(row.editedAt ?? 0),
-    });
+  for (const row of conn.db.visible_comment.iter()) {
+    if (isHiddenFromViewer(conn, row, row.userIdentity)) continue;
+    all.push(mapComment(row));
   }
   if (all.length > 0) {
     useCommentsStore.getState().setComments(all);
@@ -289,21 +277,28 @@ function bulkLoadCommentLikes(conn: DbConnection) {
   }
 }
 
+function mapNotification(row: NotificationRow): Notification {
+  return {
+    id: Number(row.id),
+    recipientIdentity: row.recipientIdentity,
+    actorIdentity: row.actorIdentity,
+    actorName: row.actorName,
+    notificationType: row.notificationType as NotificationType,
+    blockId: Number(row.blockId),
+    commentId: Number(row.commentId),
+    topicId: Number(row.topicId),
+    isRead: row.isRead,
+    createdAt: Number(row.createdAt),
+    actorMuted: row.actorMuted,
+    digest: row.digest,
+    actorCount: Number(row.actorCount),
+  };
+}
+
 function bulkLoadNotifications(conn: DbConnection) {
   const all = [];
   for (const row of conn.db.notification.iter()) {
-    all.push({
-      id: Number(row.id),
-      recipientIdentity: row.recipientIdentity,
-      actorIdentity: row.actorIdentity,
-      actorName: row.actorName,
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      notificationType: row.notificationType as any,
-      blockId: Number(row.blockId),
-      commentId: Number(row.commentId),
-      isRead: row.isRead,
-      createdAt: Number(row.createdAt),
-    });
+    all.push(mapNotification(row));
   }
   if (all.length > 0) {
     useNotificationsStore.getState().setNotifications(all);
@@ -313,18 +308,7 @@ function bulkLoadNotifications(conn: DbConnection) {
 function bulkLoadMessages(conn: DbConnection, identity: string) {
   const all = [];
   for (const row of conn.db.direct_message.iter()) {
-    // eslint-disable-next-line @typescript-eslint/no-explicit-any
-    const r = row as any;
-    all.push({
-      id: Number(r.id),
-      conversationId: Number(r.conversationId ?? 0),
-      senderIdentity: r.senderIdentity,
-      recipientIdentity: r.recipientIdentity,
-      text: r.text,
-      isRead: r.isRead,
-      isDeleted: r.isDeleted ?? false,
-      createdAt: Number(r.createdAt),
-    });
+    all.push(mapDirectMessage(row));
   }
   useMessagesStore.getState().setMyIdentity(identity);
   if (all.length > 0) {
@@ -333,10 +317,8 @@ function bulkLoadMessages(conn: DbConnection, identity: string) {
 }
 
 function bulkLoadFollows(conn: DbConnection) {
-  const db = conn.db as typeof conn.db & OptionalRealtimeTables;
-  if (!db.user_follow) return;
   const all = [];
-  for (const row of db.user_follow.iter()) {
+  for (const row of conn.db.user_follow.iter()) {
     all.push({
       id: Number(row.id),
       followerIdentity: row.followerIdentity,
@@ -351,19 +333,9 @@ function bulkLoadFollows(conn: DbConnection) {
 }
 
 function bulkLoadConversations(conn: DbConnection) {
-  const db = conn.db as typeof conn.db & OptionalRealtimeTables;
-  if (!db.conversation) return;
   const all: ConversationMeta[] = [];
-  for (const row of db.conversation.iter()) {
-    all.push({
-      id: Number(row.id),
-      participantA: row.participantA,
-      participantB: row.participantB,
-      status: row.status as ConversationMeta["status"],
-      requestRecipient: row.requestRecipient,
-      createdAt: Number(row.createdAt),
-      updatedAt: Number(row.updatedAt),
-    });
+  for (const row of conn.db.conversation.iter()) {
+    all.push(mapConversation(row));
   }
   if (all.length > 0) {
     useMessagesStore.getState().setConversations(all);
@@ -371,12 +343,17 @@ function bulkLoadConversations(conn: DbConnection) {
   console.log(`[SpacetimeDB] conversations loaded: ${all.length}`);
 }
 
+function bulkLoadReadCursors(conn: DbConnection, identity: string) {
+  const store = useMessagesStore.getState();
+  for (const row of conn.db.conversation_read_state.iter()) {
+    if (row.identity !== identity) continue;
+    store.setReadCursor(Number(row.conversationId), toReadCursor(row));
+  }
+}
+
 function bulkLoadUserBlocks(conn: DbConnection) {
-  // eslint-disable-next-line @typescript-eslint/no-explicit-any
-  const db = conn.db as any;
-  if (!db.user_block) return;
   const all = [];
-  for (const row of db.user_block.iter()) {
+  for (const row of conn.db.user_block.iter()) {
     all.push({
       id: Number(row.id),
       blockerIdentity: row.blockerIdentity,
@@ -391,11 +368,8 @@ function bulkLoadUserBlocks(conn: DbConnection) {
 }
 
 function bulkLoadUserMutes(conn: DbConnection) {
-  // eslint-disable-next-line @typescript-eslint/no-explicit-any
-  const db = conn.db as any;
-  if (!db.user_mute) return;
   const all = [];
-  for (const row of db.user_mute.iter()) {
+  for (const row of conn.db.user_mute.iter()) {
     all.push({
       id: Number(row.id),
       muterIdentity: row.muterIdentity,
@@ -412,19 +386,19 @@ function bulkLoadUserMutes(conn: DbConnection) {
 function registerTableCallbacks(conn: DbConnection) {
   const { setActiveContest, setWinners } = useContestStore.getState();
 
-  conn.db.block.onInsert((_ctx, row) => {
-    const block = mapBlock(row);
-    useBlocksStore.getState().setBlock(block);
-    debouncedRecomputeStats();
-  });
-
-  conn.db.block.onUpdate((_ctx, _old, row) => {
-    const block = mapBlock(row);
-    useBlocksStore.getState().setBlock(block);
+  // visible_block is a view without a primary key, so an update arrives as a
+  // delete plus an insert; only drop a block once the view no longer has it.
+  conn.db.visible_block.onInsert((_ctx, row) => {
+    if (isHiddenFromViewer(conn, row, row.ownerIdentity)) {
+      useBlocksStore.getState().removeBlock(Number(row.id));
+    } else {
+      useBlocksStore.getState().setBlock(mapBlock(row));
+    }
     debouncedRecomputeStats();
   });
 
-  conn.db.block.onDelete((_ctx, row) => {
+  conn.db.visible_block.onDelete((_ctx, row) => {
+    if (findVisibleBlock(conn, Number(row.id))) return;
     useBlocksStore.getState().removeBlock(Number(row.id));
     debouncedRecomputeStats();
   });
@@ -501,42 +475,24 @@ function registerTableCallbacks(conn: DbConnection) {
     useTopicStore.getState().setModeratorApplications([...apps.values()]);
   });
 
-  conn.db.comment.onInsert((_ctx, row) => {
-    useCommentsStore.getState().addComment({
-      id: Number(row.id),
-      blockId: Number(row.blockId),
-      userIdentity: row.userIdentity,
-      userName: row.userName,
-      text: row.text,
-      createdAt: Number(row.createdAt),
-      parentCommentId: row.parentCommentId != null ? Number(row.parentCommentId) : null,
-      repostOfId: row.repostOfId != null ? Number(row.repostOfId) : null,
-      likesCount: Number(row.likesCount ?? 0),
-      repliesCount: Number(row.repliesCount ?? 0),
-      repostsCount: Number(row.repostsCount ?? 0),
-      editedAt: Number(row.editedAt ?? 0),
-    });
-  });
-
-  conn.db.comment.onUpdate((_ctx, _old, row) => {
-    useCommentsStore.getState().updateComment({
-      id: Number(row.id),
-      blockId: Number(row.blockId),
-      userIdentity: row.userIdentity,
-      userName: row.userName,
-      text: row.text,
-      createdAt: Number(row.createdAt),
-      parentCommentId: row.parentCommentId != null ? Number(row.parentCommentId) : null,
-      repostOfId: row.repostOfId != null ? Number(row.repostOfId) : null,
-      likesCount: Number(row.likesCount ?? 0),
-      repliesCount: Number(row.repliesCount ?? 0),
-      repostsCount: Number(row.repostsCount ?? 0),
-      editedAt: Number(row.editedAt ?? 0),
-    });
+  // Same delete-plus-insert updates as visible_block above.
+  conn.db.visible_comment.onInsert((_ctx, row) => {
+    const store = useCommentsStore.getState();
+    if (isHiddenFromViewer(conn, row, row.userIdentity)) {
+      store.removeComment(Number(row.id));
+    } else if (store.getComment(Number(row.id))) {
+      store.updateComment(mapComment(row));
+    } else {
+      store.addComment(mapComment(row));
+    }
   });
 
-  conn.db.comment.onDelete((_ctx, row) => {
-    useCommentsStore.getState().removeComment(Number(row.id));
+  conn.db.visible_comment.onDelete((_ctx, row) => {
+    const id = Number(row.id);
+    for (const current of conn.db.visible_comment.iter()) {
+      if (Number(current.id) === id) return;
+    }
+    useCommentsStore.getState().removeComment(id);
   });
 
   conn.db.comment_like.onInsert((_ctx, row) => {
@@ -552,34 +508,24 @@ function registerTableCallbacks(conn: DbConnection) {
     useCommentsStore.getState().removeCommentLike(Number(row.id));
   });
 
-  conn.db.notification.onInsert((_ctx, row) => {
-    useNotificationsStore.getState().addNotification({
+  conn.db.my_comment_view_entry.onInsert((_ctx, row) => {
+    useCommentsStore.getState().addViewEntry({
       id: Number(row.id),
-      recipientIdentity: row.recipientIdentity,
-      actorIdentity: row.actorIdentity,
-      actorName: row.actorName,
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      notificationType: row.notificationType as any,
       blockId: Number(row.blockId),
       commentId: Number(row.commentId),
-      isRead: row.isRead,
-      createdAt: Number(row.createdAt),
     });
   });
 
+  conn.db.my_comment_view_entry.onDelete((_ctx, row) => {
+    useCommentsStore.getState().removeViewEntry(Number(row.id));
+  });
+
+  conn.db.notification.onInsert((_ctx, row) => {
+    useNotificationsStore.getState().addNotification(mapNotification(row));
+  });
+
   conn.db.notification.onUpdate((_ctx, _old, row) => {
-    useNotificationsStore.getState().updateNotification({
-      id: Number(row.id),
-      recipientIdentity: row.recipientIdentity,
-      actorIdentity: row.actorIdentity,
-      actorName: row.actorName,
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      notificationType: row.notificationType as any,
-      blockId: Number(row.blockId),
-      commentId: Number(row.commentId),
-      isRead: row.isRead,
-      createdAt: Number(row.createdAt),
-    });
+    useNotificationsStore.getState().updateNotification(mapNotification(row));
   });
 
   conn.db.notification.onDelete((_ctx, row) => {
@@ -587,124 +533,76 @@ function registerTableCallbacks(conn: DbConnection) {
   });
 
   conn.db.direct_message.onInsert((_ctx, row) => {
-    useMessagesStore.getState().addMessage({
-      id: Number(row.id),
-      conversationId: Number(row.conversationId ?? 0),
-      senderIdentity: row.senderIdentity,
-      recipientIdentity: row.recipientIdentity,
-      text: row.text,
-      isRead: row.isRead,
-      isDeleted: row.isDeleted ?? false,
-      createdAt: Number(row.createdAt),
-    });
+    useMessagesStore.getState().addMessage(mapDirectMessage(row));
   });
 
   conn.db.direct_message.onUpdate((_ctx, _old, row) => {
-    useMessagesStore.getState().updateMessage({
+    useMessagesStore.getState().updateMessage(mapDirectMessage(row));
+  });
+
+  conn.db.user_follow.onInsert((_ctx, row) => {
+    useFollowsStore.getState().addFollow({
       id: Number(row.id),
-      conversationId: Number(row.conversationId ?? 0),
-      senderIdentity: row.senderIdentity,
-      recipientIdentity: row.recipientIdentity,
-      text: row.text,
-      isRead: row.isRead,
-      isDeleted: row.isDeleted ?? false,
+      followerIdentity: row.followerIdentity,
+      followingIdentity: row.followingIdentity,
       createdAt: Number(row.createdAt),
     });
   });
 
-  // Follow callbacks — tables may not exist until module is republished
-  const db = conn.db as Partial<
-    Pick<typeof conn.db, "user_follow" | "conversation" | "user_block" | "user_mute">
-  >;
-  if (db.user_follow) {
-    db.user_follow.onInsert((_ctx, row) => {
-      const follow = row as FollowRow;
-      useFollowsStore.getState().addFollow({
-        id: Number(follow.id),
-        followerIdentity: follow.followerIdentity,
-        followingIdentity: follow.followingIdentity,
-        createdAt: Number(follow.createdAt),
-      });
-    });
+  conn.db.user_follow.onDelete((_ctx, row) => {
+    useFollowsStore.getState().removeFollow(Number(row.id));
+  });
 
-    db.user_follow.onDelete((_ctx: unknown, row: unknown) => {
-      const follow = row as Pick<FollowRow, "id">;
-      useFollowsStore.getState().removeFollow(Number(follow.id));
-    });
-  }
+  conn.db.conversation.onInsert((_ctx, row) => {
+    useMessagesStore.getState().addConversation(mapConversation(row));
+  });
 
-  // Conversation callbacks
-  if (db.conversation) {
-    db.conversation.onInsert((_ctx: unknown, row: unknown) => {
-      const conversation = row as ConversationRow;
-      useMessagesStore.getState().addConversation({
-        id: Number(conversation.id),
-        participantA: conversation.participantA,
-        participantB: conversation.participantB,
-        status: toConversationStatus(conversation.status),
-        requestRecipient: conversation.requestRecipient ?? "",
-        createdAt: Number(conversation.createdAt),
-        updatedAt: Number(conversation.updatedAt),
-      });
-    });
+  conn.db.conversation.onUpdate((_ctx, _old, row) => {
+    useMessagesStore.getState().updateConversation(mapConversation(row));
+  });
 
-    db.conversation.onUpdate((_ctx: unknown, _old: unknown, row: unknown) => {
-      const conversation = row as ConversationRow;
-      useMessagesStore.getState().updateConversation({
-        id: Number(conversation.id),
-        participantA: conversation.participantA,
-        participantB: conversation.participantB,
-        status: toConversationStatus(conversation.status),
-        requestRecipient: conversation.requestRecipient ?? "",
-        createdAt: Number(conversation.createdAt),
-        updatedAt: Number(conversation.updatedAt),
-      });
-      }
-    );
+  conn.db.conversation.onDelete((_ctx, row) => {
+    useMessagesStore.getState().removeConversation(Number(row.id));
+  });
 
-    db.conversation.onDelete((_ctx: unknown, row: unknown) => {
-      const conversation = row as Pick<ConversationRow, "id">;
-      useMessagesStore.getState().removeConversation(Number(conversation.id));
-    });
-  }
+  // Read cursors — only our own rows are subscribed
+  conn.db.conversation_read_state.onInsert((_ctx, row) => {
+    useMessagesStore.getState().setReadCursor(Number(row.conversationId), toReadCursor(row));
+  });
 
-  // eslint-disable-next-line @typescript-eslint/no-explicit-any
-  if ((db as any).user_block) {
-    // eslint-disable-next-line @typescript-eslint/no-explicit-any
-    (db as any).user_block.onInsert((_ctx: unknown, row: unknown) => {
-      const blockRow = row as UserBlockRowLike;
-      useModerationStore.getState().addBlock({
-        id: Number(blockRow.id),
-        blockerIdentity: blockRow.blockerIdentity,
-        blockedIdentity: blockRow.blockedIdentity,
-        createdAt: Number(blockRow.createdAt),
-      });
-    });
-    // eslint-disable-next-line @typescript-eslint/no-explicit-any
-    (db as any).user_block.onDelete((_ctx: unknown, row: unknown) => {
-      const blockRow = row as UserBlockRowLike;
-      useModerationStore.getState().removeBlock(Number(blockRow.id));
-    });
-  }
+  conn.db.conversation_read_state.onUpdate((_ctx, _old, row) => {
+    useMessagesStore.getState().setReadCursor(Number(row.conversationId), toReadCursor(row));
+  });
 
-  // eslint-disable-next-line @typescript-eslint/no-explicit-any
-  if ((db as any).user_mute) {
-    // eslint-disable-next-line @typescript-eslint/no-explicit-any
-    (db as any).user_mute.onInsert((_ctx: unknown, row: unknown) => {
-      const muteRow = row as UserMuteRowLike;
-      useModerationStore.getState().addMute({
-        id: Number(muteRow.id),
-        muterIdentity: muteRow.muterIdentity,
-        mutedIdentity: muteRow.mutedIdentity,
-        createdAt: Number(muteRow.createdAt),
-      });
+  conn.db.conversation_read_state.onDelete((_ctx, row) => {
+    useMessagesStore.getState().removeReadCursor(Number(row.conversationId));
+  });
+
+  conn.db.user_block.onInsert((_ctx, row) => {
+    useModerationStore.getState().addBlock({
+      id: Number(row.id),
+      blockerIdentity: row.blockerIdentity,
+      blockedIdentity: row.blockedIdentity,
+      createdAt: Number(row.createdAt),
     });
-    // eslint-disable-next-line @typescript-eslint/no-explicit-any
-    (db as any).user_mute.onDelete((_ctx: unknown, row: unknown) => {
-      const muteRow = row as UserMuteRowLike;
-      useModerationStore.getState().removeMute(Number(muteRow.id));
+  });
+
+  conn.db.user_block.onDelete((_ctx, row) => {
+    useModerationStore.getState().removeBlock(Number(row.id));
+  });
+
+  conn.db.user_mute.onInsert((_ctx, row) => {
+    useModerationStore.getState().addMute({
+      id: Number(row.id),
+      muterIdentity: row.muterIdentity,
+      mutedIdentity: row.mutedIdentity,
+      createdAt: Number(row.createdAt),
     });
-  }
+  });
+
+  conn.db.user_mute.onDelete((_ctx, row) => {
+    useModerationStore.getState().removeMute(Number(row.id));
+  });
 
   conn.db.contest.onInsert((_ctx, row) => {
     if (row.status === "active") {
@@ -842,6 +740,7 @@ export function SpacetimeDBProvider({ children }: { children: ReactNode }) {
       bulkLoadFollows(connection);
       subscribeToFollows(identity.toHexString());
       bulkLoadConversations(connection);
+      bulkLoadReadCursors(connection, identity.toHexString());
       subscribeToConversations(identity.toHexString());
       bulkLoadUserBlocks(connection);
       subscribeToUserBlockRelationships(identity.toHexString());
@@ -1062,7 +961,8 @@ export function useTopicBlocksSubscription(topicId: number | null) {
         if (!c) return;
         const { setBlocks, setLoading } = useBlocksStore.getState();
         const blocks: StoreBlock[] = [];
-        for (const row of c.db.block.iter()) {
+        for (const row of c.db.visible_block.iter()) {
+          if (isHiddenFromViewer(c, row, row.ownerIdentity)) continue;
           blocks.push(mapBlock(row));
         }
         setBlocks(blocks);
@@ -1138,8 +1038,8 @@ export function useUserBlocksSubscription(ownerIdentity: string | null) {
         if (!c) return;
         const { setBlocks, setLoading } = useBlocksStore.getState();
         const blocks: StoreBlock[] = [];
-        for (const row of c.db.block.iter()) {
-          if (row.ownerIdentity === ownerIdentity) {
+        for (const row of c.db.visible_block.iter()) {
+          if (row.ownerIdentity === ownerIdentity && !isHiddenFromViewer(c, row, row.ownerIdentity)) {
             blocks.push(mapBlock(row));
           }
         }
@@ -37,7 +37,7 @@ const USER_TABLES = [
   "SELECT * FROM ad_placement",
   "SELECT * FROM contest",
   "SELECT * FROM contest_winner",
-  "SELECT * FROM comment",
+  "SELECT * FROM visible_comment",
   "SELECT * FROM comment_like",
   "SELECT * FROM transaction_log",
   "SELECT * FROM credit_transaction_log",
@@ -64,6 +64,20 @@ let blockSubscription: SubscriptionHandle | null = null;
 /** Handle for the user-specific mute subscription. */
 let muteSubscription: SubscriptionHandle | null = null;
 
+/** Handle for the user's feed subscription (opened by the feed page). */
+let feedSubscription: SubscriptionHandle | null = null;
+
+/**
+ * Look up a block the caller can see. visible_block is a view, so it has no
+ * index accessors to find by id.
+ */
+export function findVisibleBlock(conn: DbConnection, blockId: number) {
+  for (const row of conn.db.visible_block.iter()) {
+    if (Number(row.id) === blockId) return row;
+  }
+  return undefined;
+}
+
 /**
  * Subscribe to notifications for the authenticated user.
  * Called after connect when the identity is known.
@@ -105,6 +119,8 @@ export function subscribeToMessages(identity: string): void {
     })
     .subscribe([
       `SELECT * FROM direct_message WHERE sender_identity = '${identity}' OR recipient_identity = '${identity}'`,
+      // Group messages have no recipient; reach them through the user's memberships.
+      `SELECT m.* FROM direct_message m JOIN conversation_participant p ON m.conversation_id = p.conversation_id WHERE p.identity = '${identity}'`,
     ]);
 }
 
@@ -130,7 +146,9 @@ export function subscribeToFollows(identity: string): void {
 }
 
 /**
- * Subscribe to conversations for the authenticated user.
+ * Subscribe to conversations (and the user's read cursors in them) for the authenticated user.
+ * 1:1 conversations are keyed on participant_a/b; groups, and everyone in them,
+ * through the user's conversation_participant rows.
  */
 export function subscribeToConversations(identity: string): void {
   if (!connection) return;
@@ -147,6 +165,9 @@ export function subscribeToConversations(identity: string): void {
     })
     .subscribe([
       `SELECT * FROM conversation WHERE participant_a = '${identity}' OR participant_b = '${identity}'`,
+      `SELECT c.* FROM conversation c JOIN conversation_participant p ON c.id = p.conversation_id WHERE p.identity = '${identity}'`,
+      `SELECT p.* FROM conversation_participant p JOIN conversation_participant me ON p.conversation_id = me.conversation_id WHERE me.identity = '${identity}'`,
+      `SELECT * FROM conversation_read_state WHERE identity = '${identity}'`,
     ]);
 }
 
@@ -170,7 +191,8 @@ export function subscribeToUserBlockRelationships(identity: string): void {
 }
 
 /**
- * Subscribe to mute relationships for the authenticated user.
+ * Subscribe to mute relationships for the authenticated user, and to their
+ * comment view (the open block's comments minus authors they muted).
  */
 export function subscribeToUserMutes(identity: string): void {
   if (!connection) return;
@@ -185,6 +207,28 @@ export function subscribeToUserMutes(identity: string): void {
     })
     .subscribe([
       `SELECT * FROM user_mute WHERE muter_identity = '${identity}'`,
+      "SELECT * FROM my_comment_view_entry",
+    ]);
+}
+
+/**
+ * Subscribe to the authenticated user's materialized feed (feed_entry rows).
+ * `onApplied` runs once the rows are in the client cache.
+ */
+export function subscribeToFeed(identity: string, onApplied?: () => void): void {
+  if (!connection) return;
+  if (feedSubscription) {
+    feedSubscription.unsubscribe();
+    feedSubscription = null;
+  }
+  feedSubscription = connection
+    .subscriptionBuilder()
+    .onApplied(() => {
+      console.log("[SpacetimeDB] feed subscription applied");
+      onApplied?.();
+    })
+    .subscribe([
+      `SELECT * FROM feed_entry WHERE owner_identity = '${identity}'`,
     ]);
 }
 
@@ -252,6 +296,7 @@ export function connect(
         conversationSubscription = null;
         blockSubscription = null;
         muteSubscription = null;
+        feedSubscription = null;
         reportSubscription = null;
         callbacks?.onDisconnect?.();
       })
@@ -291,7 +336,7 @@ export function subscribeToTopicBlocks(
       onLoaded();
     })
     .subscribe([
-      `SELECT * FROM block WHERE topic_id = ${topicId}`,
+      `SELECT * FROM visible_block WHERE topic_id = ${topicId}`,
       `SELECT * FROM like_record`,
       `SELECT * FROM dislike_record`,
     ]);
@@ -330,7 +375,7 @@ export function subscribeToUserBlocks(
       onLoaded();
     })
     .subscribe([
-      `SELECT * FROM block WHERE owner_identity = '${ownerIdentity}'`,
+      `SELECT * FROM visible_block WHERE owner_identity = '${ownerIdentity}'`,
     ]);
 }
 
@@ -369,6 +414,8 @@ export function disconnect(): void {
   blockSubscription = null;
   muteSubscription?.unsubscribe();
   muteSubscription = null;
+  feedSubscription?.unsubscribe();
+  feedSubscription = null;
   reportSubscription?.unsubscribe();
   reportSubscription = null;
   connection?.disconnect();
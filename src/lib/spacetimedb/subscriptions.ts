@@ -63,6 +63,6 @@ export function subscribeToViewport(bounds: ViewportBounds, conn = getConnection
   return conn
     .subscriptionBuilder()
     .subscribe([
-      `SELECT * FROM block WHERE x >= ${bounds.minX} AND x <= ${bounds.maxX} AND y >= ${bounds.minY} AND y <= ${bounds.maxY}`,
+      `SELECT * FROM visible_block WHERE x >= ${bounds.minX} AND x <= ${bounds.maxX} AND y >= ${bounds.minY} AND y <= ${bounds.maxY}`,
     ]);
 }
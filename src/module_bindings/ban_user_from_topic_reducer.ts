@@ -14,4 +14,6 @@ export default {
   topicId: __t.u64(),
   targetIdentity: __t.string(),
   reason: __t.string(),
+  durationHours: __t.u64(),
+  contentAction: __t.string(),
 };
@@ -28,4 +28,5 @@ export default __t.row({
   adImageUrl: __t.string().name("ad_image_url"),
   adLinkUrl: __t.string().name("ad_link_url"),
   claimedAt: __t.u64().name("claimed_at"),
+  hidden: __t.bool(),
 });
@@ -23,4 +23,5 @@ export default __t.row({
   repliesCount: __t.u64().name("replies_count"),
   repostsCount: __t.u64().name("reposts_count"),
   editedAt: __t.u64().name("edited_at"),
+  hidden: __t.bool(),
 });
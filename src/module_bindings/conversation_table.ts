@@ -18,4 +18,6 @@ export default __t.row({
   requestRecipient: __t.string().name("request_recipient"),
   createdAt: __t.u64().name("created_at"),
   updatedAt: __t.u64().name("updated_at"),
+  isGroup: __t.bool().name("is_group"),
+  title: __t.option(__t.string()),
 });
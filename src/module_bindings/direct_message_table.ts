@@ -19,4 +19,8 @@ export default __t.row({
   createdAt: __t.u64().name("created_at"),
   conversationId: __t.u64().name("conversation_id"),
   isDeleted: __t.bool().name("is_deleted"),
+  editedAt: __t.u64().name("edited_at"),
+  attachmentBlockId: __t.u64().name("attachment_block_id"),
+  attachmentTopicId: __t.u64().name("attachment_topic_id"),
+  attachmentCommentId: __t.u64().name("attachment_comment_id"),
 });
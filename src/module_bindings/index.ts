@@ -35,15 +35,24 @@ import {
 
 // Import all reducer arg schemas
 import AcceptMessageRequestReducer from "./accept_message_request_reducer";
+import AddBlockedTermReducer from "./add_blocked_term_reducer";
 import AddCommentReducer from "./add_comment_reducer";
 import AddCreditsReducer from "./add_credits_reducer";
+import AnnounceToTopicFollowersReducer from "./announce_to_topic_followers_reducer";
 import ApplyTopicModeratorReducer from "./apply_topic_moderator_reducer";
+import ApproveFollowRequestReducer from "./approve_follow_request_reducer";
 import BackfillTopicTaxonomyFromCategoriesReducer from "./backfill_topic_taxonomy_from_categories_reducer";
 import BanUserFromTopicReducer from "./ban_user_from_topic_reducer";
 import BlockUserReducer from "./block_user_reducer";
+import CancelAdminActionReducer from "./cancel_admin_action_reducer";
+import CancelFollowRequestReducer from "./cancel_follow_request_reducer";
 import ClaimBlockInTopicReducer from "./claim_block_in_topic_reducer";
 import ClearAllBlocksReducer from "./clear_all_blocks_reducer";
+import ClearAuditLogQueryReducer from "./clear_audit_log_query_reducer";
+import ClearCommentViewReducer from "./clear_comment_view_reducer";
+import ConfirmAdminActionReducer from "./confirm_admin_action_reducer";
 import CreateContestReducer from "./create_contest_reducer";
+import CreateGroupConversationReducer from "./create_group_conversation_reducer";
 import CreateTopicReducer from "./create_topic_reducer";
 import CreateTopicTaxonomyNodeReducer from "./create_topic_taxonomy_node_reducer";
 import DeclineMessageRequestReducer from "./decline_message_request_reducer";
@@ -52,46 +61,79 @@ import DeleteConversationReducer from "./delete_conversation_reducer";
 import DeleteTopicReducer from "./delete_topic_reducer";
 import DevClearAllUsersReducer from "./dev_clear_all_users_reducer";
 import DevSeedTopicReducer from "./dev_seed_topic_reducer";
-import DevSetAdminReducer from "./dev_set_admin_reducer";
 import DislikeVideoReducer from "./dislike_video_reducer";
+import DismissSuggestionReducer from "./dismiss_suggestion_reducer";
 import EditBlockReducer from "./edit_block_reducer";
 import EditCommentReducer from "./edit_comment_reducer";
+import EditMessageReducer from "./edit_message_reducer";
 import FinalizeContestReducer from "./finalize_contest_reducer";
 import FollowTopicReducer from "./follow_topic_reducer";
 import FollowUserReducer from "./follow_user_reducer";
 import IncrementTopicViewsReducer from "./increment_topic_views_reducer";
+import InviteToConversationReducer from "./invite_to_conversation_reducer";
+import LeaveConversationReducer from "./leave_conversation_reducer";
+import LiftUserSanctionReducer from "./lift_user_sanction_reducer";
 import LikeCommentReducer from "./like_comment_reducer";
 import LikeVideoReducer from "./like_video_reducer";
+import LoadCommentViewReducer from "./load_comment_view_reducer";
 import MarkAdPaidReducer from "./mark_ad_paid_reducer";
 import MarkAllMessagesReadReducer from "./mark_all_messages_read_reducer";
 import MarkAllNotificationsReadReducer from "./mark_all_notifications_read_reducer";
+import MarkConversationReadReducer from "./mark_conversation_read_reducer";
 import MarkMessageReadReducer from "./mark_message_read_reducer";
 import MarkNotificationReadReducer from "./mark_notification_read_reducer";
 import ModRemoveBlockReducer from "./mod_remove_block_reducer";
 import MuteUserReducer from "./mute_user_reducer";
 import PlaceAdReducer from "./place_ad_reducer";
+import QueryAuditLogByActorReducer from "./query_audit_log_by_actor_reducer";
+import QueryAuditLogByTargetReducer from "./query_audit_log_by_target_reducer";
+import ReactToMessageReducer from "./react_to_message_reducer";
 import RebalanceTopicReducer from "./rebalance_topic_reducer";
+import RecomputeProfileCountersReducer from "./recompute_profile_counters_reducer";
+import RefreshFeedReducer from "./refresh_feed_reducer";
+import RefreshMySuggestionsReducer from "./refresh_my_suggestions_reducer";
 import RegisterUserReducer from "./register_user_reducer";
+import RejectFollowRequestReducer from "./reject_follow_request_reducer";
 import RemoveAdReducer from "./remove_ad_reducer";
+import RemoveBlockedTermReducer from "./remove_blocked_term_reducer";
+import RemoveFollowerReducer from "./remove_follower_reducer";
+import RemoveFromConversationReducer from "./remove_from_conversation_reducer";
 import RemoveTopicModeratorReducer from "./remove_topic_moderator_reducer";
+import RenameConversationReducer from "./rename_conversation_reducer";
+import ReportBlockReducer from "./report_block_reducer";
+import ReportCommentReducer from "./report_comment_reducer";
 import ReportUserReducer from "./report_user_reducer";
 import RepostCommentReducer from "./repost_comment_reducer";
+import ResolveModerationItemReducer from "./resolve_moderation_item_reducer";
+import ReviewBanAppealReducer from "./review_ban_appeal_reducer";
 import ReviewReportReducer from "./review_report_reducer";
 import ReviewTopicModeratorApplicationReducer from "./review_topic_moderator_application_reducer";
 import SaveBlockReducer from "./save_block_reducer";
 import SeedAdsReducer from "./seed_ads_reducer";
 import SeedDataReducer from "./seed_data_reducer";
+import SendGroupMessageReducer from "./send_group_message_reducer";
+import SendGroupMessageWithAttachmentReducer from "./send_group_message_with_attachment_reducer";
 import SendMessageReducer from "./send_message_reducer";
+import SendMessageWithAttachmentReducer from "./send_message_with_attachment_reducer";
 import ServerAddApiCreditsReducer from "./server_add_api_credits_reducer";
 import ServerDeleteUserReducer from "./server_delete_user_reducer";
 import ServerRecordApiUsageReducer from "./server_record_api_usage_reducer";
 import ServerRegisterApiKeyReducer from "./server_register_api_key_reducer";
 import ServerRevokeApiKeyReducer from "./server_revoke_api_key_reducer";
 import ServerUpdateProfileReducer from "./server_update_profile_reducer";
+import SetAccountPrivateReducer from "./set_account_private_reducer";
 import SetAdminReducer from "./set_admin_reducer";
+import SetAutoModerationConfigReducer from "./set_auto_moderation_config_reducer";
+import SetConversationAdminReducer from "./set_conversation_admin_reducer";
+import SetNotificationPreferenceReducer from "./set_notification_preference_reducer";
+import SetNotificationRetentionReducer from "./set_notification_retention_reducer";
 import SetTopicTaxonomyReducer from "./set_topic_taxonomy_reducer";
+import SetTopicTermOverrideReducer from "./set_topic_term_override_reducer";
+import ShadowBanUserReducer from "./shadow_ban_user_reducer";
 import SpendCreditsReducer from "./spend_credits_reducer";
 import StoreClerkMappingReducer from "./store_clerk_mapping_reducer";
+import SubmitBanAppealReducer from "./submit_ban_appeal_reducer";
+import SuspendUserReducer from "./suspend_user_reducer";
 import UnbanUserFromTopicReducer from "./unban_user_from_topic_reducer";
 import UnblockUserReducer from "./unblock_user_reducer";
 import UnclaimBlockReducer from "./unclaim_block_reducer";
@@ -102,6 +144,7 @@ import UnlikeCommentReducer from "./unlike_comment_reducer";
 import UnlikeVideoReducer from "./unlike_video_reducer";
 import UnmuteUserReducer from "./unmute_user_reducer";
 import UnsaveBlockReducer from "./unsave_block_reducer";
+import UnsendMessageReducer from "./unsend_message_reducer";
 import UpdateProfileReducer from "./update_profile_reducer";
 import UpdateProfileDetailsReducer from "./update_profile_details_reducer";
 import UpdateStripeAccountReducer from "./update_stripe_account_reducer";
@@ -110,24 +153,41 @@ import UpdateTopicReducer from "./update_topic_reducer";
 // Import all procedure arg schemas
 
 // Import all table schema definitions
+import ActivityEventRow from "./activity_event_table";
 import AdPlacementRow from "./ad_placement_table";
 import ApiKeyRow from "./api_key_table";
 import ApiUsageLogRow from "./api_usage_log_table";
+import AutoModerationConfigRow from "./auto_moderation_config_table";
 import BlockRow from "./block_table";
+import BlockedTermRow from "./blocked_term_table";
 import ClerkIdentityMapRow from "./clerk_identity_map_table";
 import CommentRow from "./comment_table";
 import CommentLikeRow from "./comment_like_table";
 import ContestRow from "./contest_table";
 import ContestWinnerRow from "./contest_winner_table";
 import ConversationRow from "./conversation_table";
+import ConversationParticipantRow from "./conversation_participant_table";
+import ConversationReadStateRow from "./conversation_read_state_table";
 import CreditTransactionLogRow from "./credit_transaction_log_table";
 import DirectMessageRow from "./direct_message_table";
 import DislikeRecordRow from "./dislike_record_table";
+import FeedEntryRow from "./feed_entry_table";
+import FollowRequestRow from "./follow_request_table";
 import LikeRecordRow from "./like_record_table";
+import MessageReactionRow from "./message_reaction_table";
+import ModerationQueueItemRow from "./moderation_queue_item_table";
+import MyAuditLogQueryResultRow from "./my_audit_log_query_result_table";
+import MyCommentViewEntryRow from "./my_comment_view_entry_table";
 import NotificationRow from "./notification_table";
+import NotificationPreferenceRow from "./notification_preference_table";
+import NotificationRetentionConfigRow from "./notification_retention_config_table";
+import PendingAdminActionRow from "./pending_admin_action_table";
 import SavedBlockRow from "./saved_block_table";
+import SuggestionRow from "./suggestion_table";
 import TopicRow from "./topic_table";
+import TopicAnnouncementRow from "./topic_announcement_table";
 import TopicBanRow from "./topic_ban_table";
+import TopicBanAppealRow from "./topic_ban_appeal_table";
 import TopicFollowRow from "./topic_follow_table";
 import TopicModeratorRow from "./topic_moderator_table";
 import TopicModeratorApplicationRow from "./topic_moderator_application_table";
@@ -138,11 +198,34 @@ import UserFollowRow from "./user_follow_table";
 import UserMuteRow from "./user_mute_table";
 import UserProfileRow from "./user_profile_table";
 import UserReportRow from "./user_report_table";
+import UserStrikeRow from "./user_strike_table";
+import VisibleBlockRow from "./visible_block_table";
+import VisibleCommentRow from "./visible_comment_table";
 
 /** Type-only namespace exports for generated type groups. */
 
 /** The schema information for all tables in this module. This is defined the same was as the tables would have been defined in the server. */
 const tablesSchema = __schema({
+  activity_event: __table({
+    name: 'activity_event',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'actor_identity', algorithm: 'btree', columns: [
+        'actorIdentity',
+      ] },
+      { name: 'block_id', algorithm: 'btree', columns: [
+        'blockId',
+      ] },
+      { name: 'created_at', algorithm: 'btree', columns: [
+        'createdAt',
+      ] },
+    ],
+    constraints: [
+      { name: 'activity_event_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, ActivityEventRow),
   ad_placement: __table({
     name: 'ad_placement',
     indexes: [
@@ -180,6 +263,17 @@ const tablesSchema = __schema({
       { name: 'api_usage_log_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, ApiUsageLogRow),
+  auto_moderation_config: __table({
+    name: 'auto_moderation_config',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+    ],
+    constraints: [
+      { name: 'auto_moderation_config_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, AutoModerationConfigRow),
   block: __table({
     name: 'block',
     indexes: [
@@ -191,6 +285,17 @@ const tablesSchema = __schema({
       { name: 'block_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, BlockRow),
+  blocked_term: __table({
+    name: 'blocked_term',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+    ],
+    constraints: [
+      { name: 'blocked_term_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, BlockedTermRow),
   clerk_identity_map: __table({
     name: 'clerk_identity_map',
     indexes: [
@@ -257,6 +362,40 @@ const tablesSchema = __schema({
       { name: 'conversation_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, ConversationRow),
+  conversation_participant: __table({
+    name: 'conversation_participant',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'conversation_id', algorithm: 'btree', columns: [
+        'conversationId',
+      ] },
+      { name: 'identity', algorithm: 'btree', columns: [
+        'identity',
+      ] },
+    ],
+    constraints: [
+      { name: 'conversation_participant_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, ConversationParticipantRow),
+  conversation_read_state: __table({
+    name: 'conversation_read_state',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'conversation_id', algorithm: 'btree', columns: [
+        'conversationId',
+      ] },
+      { name: 'identity', algorithm: 'btree', columns: [
+        'identity',
+      ] },
+    ],
+    constraints: [
+      { name: 'conversation_read_state_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, ConversationReadStateRow),
   credit_transaction_log: __table({
     name: 'credit_transaction_log',
     indexes: [
@@ -274,6 +413,9 @@ const tablesSchema = __schema({
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'conversation_id', algorithm: 'btree', columns: [
+        'conversationId',
+      ] },
     ],
     constraints: [
       { name: 'direct_message_id_key', constraint: 'unique', columns: ['id'] },
@@ -290,6 +432,37 @@ const tablesSchema = __schema({
       { name: 'dislike_record_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, DislikeRecordRow),
+  feed_entry: __table({
+    name: 'feed_entry',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'owner_identity', algorithm: 'btree', columns: [
+        'ownerIdentity',
+      ] },
+      { name: 'event_id', algorithm: 'btree', columns: [
+        'eventId',
+      ] },
+    ],
+    constraints: [
+      { name: 'feed_entry_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, FeedEntryRow),
+  follow_request: __table({
+    name: 'follow_request',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'target_identity', algorithm: 'btree', columns: [
+        'targetIdentity',
+      ] },
+    ],
+    constraints: [
+      { name: 'follow_request_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, FollowRequestRow),
   like_record: __table({
     name: 'like_record',
     indexes: [
@@ -301,17 +474,95 @@ const tablesSchema = __schema({
       { name: 'like_record_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, LikeRecordRow),
+  message_reaction: __table({
+    name: 'message_reaction',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'message_id', algorithm: 'btree', columns: [
+        'messageId',
+      ] },
+    ],
+    constraints: [
+      { name: 'message_reaction_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, MessageReactionRow),
+  moderation_queue_item: __table({
+    name: 'moderation_queue_item',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+    ],
+    constraints: [
+      { name: 'moderation_queue_item_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, ModerationQueueItemRow),
+  my_audit_log_query_result: __table({
+    name: 'my_audit_log_query_result',
+    indexes: [
+    ],
+    constraints: [
+    ],
+  }, MyAuditLogQueryResultRow),
+  my_comment_view_entry: __table({
+    name: 'my_comment_view_entry',
+    indexes: [
+    ],
+    constraints: [
+    ],
+  }, MyCommentViewEntryRow),
   notification: __table({
     name: 'notification',
     indexes: [
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'recipient_identity', algorithm: 'btree', columns: [
+        'recipientIdentity',
+      ] },
     ],
     constraints: [
       { name: 'notification_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, NotificationRow),
+  notification_preference: __table({
+    name: 'notification_preference',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'identity', algorithm: 'btree', columns: [
+        'identity',
+      ] },
+    ],
+    constraints: [
+      { name: 'notification_preference_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, NotificationPreferenceRow),
+  notification_retention_config: __table({
+    name: 'notification_retention_config',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+    ],
+    constraints: [
+      { name: 'notification_retention_config_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, NotificationRetentionConfigRow),
+  pending_admin_action: __table({
+    name: 'pending_admin_action',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+    ],
+    constraints: [
+      { name: 'pending_admin_action_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, PendingAdminActionRow),
   saved_block: __table({
     name: 'saved_block',
     indexes: [
@@ -323,6 +574,20 @@ const tablesSchema = __schema({
       { name: 'saved_block_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, SavedBlockRow),
+  suggestion: __table({
+    name: 'suggestion',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'identity', algorithm: 'btree', columns: [
+        'identity',
+      ] },
+    ],
+    constraints: [
+      { name: 'suggestion_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, SuggestionRow),
   topic: __table({
     name: 'topic',
     indexes: [
@@ -338,6 +603,20 @@ const tablesSchema = __schema({
       { name: 'topic_slug_key', constraint: 'unique', columns: ['slug'] },
     ],
   }, TopicRow),
+  topic_announcement: __table({
+    name: 'topic_announcement',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+      { name: 'topic_id', algorithm: 'btree', columns: [
+        'topicId',
+      ] },
+    ],
+    constraints: [
+      { name: 'topic_announcement_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, TopicAnnouncementRow),
   topic_ban: __table({
     name: 'topic_ban',
     indexes: [
@@ -349,12 +628,33 @@ const tablesSchema = __schema({
       { name: 'topic_ban_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, TopicBanRow),
+  topic_ban_appeal: __table({
+    name: 'topic_ban_appeal',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+    ],
+    constraints: [
+      { name: 'topic_ban_appeal_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, TopicBanAppealRow),
   topic_follow: __table({
     name: 'topic_follow',
     indexes: [
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'follower_identity', algorithm: 'btree', columns: [
+        'followerIdentity',
+      ] },
+      { name: 'topic_id', algorithm: 'btree', columns: [
+        'topicId',
+      ] },
+      { name: 'by_topic_and_id', algorithm: 'btree', columns: [
+        'topicId',
+        'id',
+      ] },
     ],
     constraints: [
       { name: 'topic_follow_id_key', constraint: 'unique', columns: ['id'] },
@@ -414,6 +714,12 @@ const tablesSchema = __schema({
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'blocker_identity', algorithm: 'btree', columns: [
+        'blockerIdentity',
+      ] },
+      { name: 'blocked_identity', algorithm: 'btree', columns: [
+        'blockedIdentity',
+      ] },
     ],
     constraints: [
       { name: 'user_block_id_key', constraint: 'unique', columns: ['id'] },
@@ -425,6 +731,12 @@ const tablesSchema = __schema({
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'follower_identity', algorithm: 'btree', columns: [
+        'followerIdentity',
+      ] },
+      { name: 'following_identity', algorithm: 'btree', columns: [
+        'followingIdentity',
+      ] },
     ],
     constraints: [
       { name: 'user_follow_id_key', constraint: 'unique', columns: ['id'] },
@@ -436,6 +748,9 @@ const tablesSchema = __schema({
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'muter_identity', algorithm: 'btree', columns: [
+        'muterIdentity',
+      ] },
     ],
     constraints: [
       { name: 'user_mute_id_key', constraint: 'unique', columns: ['id'] },
@@ -458,25 +773,62 @@ const tablesSchema = __schema({
       { name: 'id', algorithm: 'btree', columns: [
         'id',
       ] },
+      { name: 'reporter_identity', algorithm: 'btree', columns: [
+        'reporterIdentity',
+      ] },
     ],
     constraints: [
       { name: 'user_report_id_key', constraint: 'unique', columns: ['id'] },
     ],
   }, UserReportRow),
+  user_strike: __table({
+    name: 'user_strike',
+    indexes: [
+      { name: 'id', algorithm: 'btree', columns: [
+        'id',
+      ] },
+    ],
+    constraints: [
+      { name: 'user_strike_id_key', constraint: 'unique', columns: ['id'] },
+    ],
+  }, UserStrikeRow),
+  visible_block: __table({
+    name: 'visible_block',
+    indexes: [
+    ],
+    constraints: [
+    ],
+  }, VisibleBlockRow),
+  visible_comment: __table({
+    name: 'visible_comment',
+    indexes: [
+    ],
+    constraints: [
+    ],
+  }, VisibleCommentRow),
 });
 
 /** The schema information for all reducers in this module. This is defined the same way as the reducers would have been defined in the server, except the body of the reducer is omitted in code generation. */
 const reducersSchema = __reducers(
   __reducerSchema("accept_message_request", AcceptMessageRequestReducer),
+  __reducerSchema("add_blocked_term", AddBlockedTermReducer),
   __reducerSchema("add_comment", AddCommentReducer),
   __reducerSchema("add_credits", AddCreditsReducer),
+  __reducerSchema("announce_to_topic_followers", AnnounceToTopicFollowersReducer),
   __reducerSchema("apply_topic_moderator", ApplyTopicModeratorReducer),
+  __reducerSchema("approve_follow_request", ApproveFollowRequestReducer),
   __reducerSchema("backfill_topic_taxonomy_from_categories", BackfillTopicTaxonomyFromCategoriesReducer),
   __reducerSchema("ban_user_from_topic", BanUserFromTopicReducer),
   __reducerSchema("block_user", BlockUserReducer),
+  __reducerSchema("cancel_admin_action", CancelAdminActionReducer),
+  __reducerSchema("cancel_follow_request", CancelFollowRequestReducer),
   __reducerSchema("claim_block_in_topic", ClaimBlockInTopicReducer),
   __reducerSchema("clear_all_blocks", ClearAllBlocksReducer),
+  __reducerSchema("clear_audit_log_query", ClearAuditLogQueryReducer),
+  __reducerSchema("clear_comment_view", ClearCommentViewReducer),
+  __reducerSchema("confirm_admin_action", ConfirmAdminActionReducer),
   __reducerSchema("create_contest", CreateContestReducer),
+  __reducerSchema("create_group_conversation", CreateGroupConversationReducer),
   __reducerSchema("create_topic", CreateTopicReducer),
   __reducerSchema("create_topic_taxonomy_node", CreateTopicTaxonomyNodeReducer),
   __reducerSchema("decline_message_request", DeclineMessageRequestReducer),
@@ -485,46 +837,79 @@ const reducersSchema = __reducers(
   __reducerSchema("delete_topic", DeleteTopicReducer),
   __reducerSchema("dev_clear_all_users", DevClearAllUsersReducer),
   __reducerSchema("dev_seed_topic", DevSeedTopicReducer),
-  __reducerSchema("dev_set_admin", DevSetAdminReducer),
   __reducerSchema("dislike_video", DislikeVideoReducer),
+  __reducerSchema("dismiss_suggestion", DismissSuggestionReducer),
   __reducerSchema("edit_block", EditBlockReducer),
   __reducerSchema("edit_comment", EditCommentReducer),
+  __reducerSchema("edit_message", EditMessageReducer),
   __reducerSchema("finalize_contest", FinalizeContestReducer),
   __reducerSchema("follow_topic", FollowTopicReducer),
   __reducerSchema("follow_user", FollowUserReducer),
   __reducerSchema("increment_topic_views", IncrementTopicViewsReducer),
+  __reducerSchema("invite_to_conversation", InviteToConversationReducer),
+  __reducerSchema("leave_conversation", LeaveConversationReducer),
+  __reducerSchema("lift_user_sanction", LiftUserSanctionReducer),
   __reducerSchema("like_comment", LikeCommentReducer),
   __reducerSchema("like_video", LikeVideoReducer),
+  __reducerSchema("load_comment_view", LoadCommentViewReducer),
   __reducerSchema("mark_ad_paid", MarkAdPaidReducer),
   __reducerSchema("mark_all_messages_read", MarkAllMessagesReadReducer),
   __reducerSchema("mark_all_notifications_read", MarkAllNotificationsReadReducer),
+  __reducerSchema("mark_conversation_read", MarkConversationReadReducer),
   __reducerSchema("mark_message_read", MarkMessageReadReducer),
   __reducerSchema("mark_notification_read", MarkNotificationReadReducer),
   __reducerSchema("mod_remove_block", ModRemoveBlockReducer),
   __reducerSchema("mute_user", MuteUserReducer),
   __reducerSchema("place_ad", PlaceAdReducer),
+  __reducerSchema("query_audit_log_by_actor", QueryAuditLogByActorReducer),
+  __reducerSchema("query_audit_log_by_target", QueryAuditLogByTargetReducer),
+  __reducerSchema("react_to_message", ReactToMessageReducer),
   __reducerSchema("rebalance_topic", RebalanceTopicReducer),
+  __reducerSchema("recompute_profile_counters", RecomputeProfileCountersReducer),
+  __reducerSchema("refresh_feed", RefreshFeedReducer),
+  __reducerSchema("refresh_my_suggestions", RefreshMySuggestionsReducer),
   __reducerSchema("register_user", RegisterUserReducer),
+  __reducerSchema("reject_follow_request", RejectFollowRequestReducer),
   __reducerSchema("remove_ad", RemoveAdReducer),
+  __reducerSchema("remove_blocked_term", RemoveBlockedTermReducer),
+  __reducerSchema("remove_follower", RemoveFollowerReducer),
+  __reducerSchema("remove_from_conversation", RemoveFromConversationReducer),
   __reducerSchema("remove_topic_moderator", RemoveTopicModeratorReducer),
+  __reducerSchema("rename_conversation", RenameConversationReducer),
+  __reducerSchema("report_block", ReportBlockReducer),
+  __reducerSchema("report_comment", ReportCommentReducer),
   __reducerSchema("report_user", ReportUserReducer),
   __reducerSchema("repost_comment", RepostCommentReducer),
+  __reducerSchema("resolve_moderation_item", ResolveModerationItemReducer),
+  __reducerSchema("review_ban_appeal", ReviewBanAppealReducer),
   __reducerSchema("review_report", ReviewReportReducer),
   __reducerSchema("review_topic_moderator_application", ReviewTopicModeratorApplicationReducer),
   __reducerSchema("save_block", SaveBlockReducer),
   __reducerSchema("seed_ads", SeedAdsReducer),
   __reducerSchema("seed_data", SeedDataReducer),
+  __reducerSchema("send_group_message", SendGroupMessageReducer),
+  __reducerSchema("send_group_message_with_attachment", SendGroupMessageWithAttachmentReducer),
   __reducerSchema("send_message", SendMessageReducer),
+  __reducerSchema("send_message_with_attachment", SendMessageWithAttachmentReducer),
   __reducerSchema("server_add_api_credits", ServerAddApiCreditsReducer),
   __reducerSchema("server_delete_user", ServerDeleteUserReducer),
   __reducerSchema("server_record_api_usage", ServerRecordApiUsageReducer),
   __reducerSchema("server_register_api_key", ServerRegisterApiKeyReducer),
   __reducerSchema("server_revoke_api_key", ServerRevokeApiKeyReducer),
   __reducerSchema("server_update_profile", ServerUpdateProfileReducer),
+  __reducerSchema("set_account_private", SetAccountPrivateReducer),
   __reducerSchema("set_admin", SetAdminReducer),
+  __reducerSchema("set_auto_moderation_config", SetAutoModerationConfigReducer),
+  __reducerSchema("set_conversation_admin", SetConversationAdminReducer),
+  __reducerSchema("set_notification_preference", SetNotificationPreferenceReducer),
+  __reducerSchema("set_notification_retention", SetNotificationRetentionReducer),
   __reducerSchema("set_topic_taxonomy", SetTopicTaxonomyReducer),
+  __reducerSchema("set_topic_term_override", SetTopicTermOverrideReducer),
+  __reducerSchema("shadow_ban_user", ShadowBanUserReducer),
   __reducerSchema("spend_credits", SpendCreditsReducer),
   __reducerSchema("store_clerk_mapping", StoreClerkMappingReducer),
+  __reducerSchema("submit_ban_appeal", SubmitBanAppealReducer),
+  __reducerSchema("suspend_user", SuspendUserReducer),
   __reducerSchema("unban_user_from_topic", UnbanUserFromTopicReducer),
   __reducerSchema("unblock_user", UnblockUserReducer),
   __reducerSchema("unclaim_block", UnclaimBlockReducer),
@@ -535,6 +920,7 @@ const reducersSchema = __reducers(
   __reducerSchema("unlike_video", UnlikeVideoReducer),
   __reducerSchema("unmute_user", UnmuteUserReducer),
   __reducerSchema("unsave_block", UnsaveBlockReducer),
+  __reducerSchema("unsend_message", UnsendMessageReducer),
   __reducerSchema("update_profile", UpdateProfileReducer),
   __reducerSchema("update_profile_details", UpdateProfileDetailsReducer),
   __reducerSchema("update_stripe_account", UpdateStripeAccountReducer),
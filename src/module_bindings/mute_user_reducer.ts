@@ -12,4 +12,5 @@ import {
 
 export default {
   targetIdentity: __t.string(),
+  durationHours: __t.u64(),
 };
@@ -20,4 +20,12 @@ export default __t.row({
   commentId: __t.u64().name("comment_id"),
   isRead: __t.bool().name("is_read"),
   createdAt: __t.u64().name("created_at"),
+  topicId: __t.u64().name("topic_id"),
+  actorMuted: __t.bool().name("actor_muted"),
+  digest: __t.bool(),
+  actorCount: __t.u64().name("actor_count"),
+  latestActors: __t.option(__t.string()).name("latest_actors"),
+  updatedAt: __t.u64().name("updated_at"),
+  announcementId: __t.u64().name("announcement_id"),
+  conversationId: __t.u64().name("conversation_id"),
 });
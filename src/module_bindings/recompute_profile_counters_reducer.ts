@@ -12,5 +12,4 @@ import {
 
 export default {
   identity: __t.string(),
-  isAdmin: __t.bool(),
 };
@@ -13,4 +13,5 @@ import {
 export default {
   reportId: __t.u64(),
   action: __t.string(),
+  durationDays: __t.u64(),
 };
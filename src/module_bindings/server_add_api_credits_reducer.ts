@@ -13,5 +13,6 @@ import {
 export default {
   keyId: __t.u64(),
   amount: __t.u64(),
+  stripeSessionId: __t.string(),
   description: __t.string(),
 };
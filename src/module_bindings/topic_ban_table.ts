@@ -17,4 +17,6 @@ export default __t.row({
   bannedBy: __t.string().name("banned_by"),
   reason: __t.string(),
   createdAt: __t.u64().name("created_at"),
+  expiresAt: __t.option(__t.u64()).name("expires_at"),
+  contentAction: __t.option(__t.string()).name("content_action"),
 });
@@ -24,4 +24,5 @@ export default __t.row({
   isActive: __t.bool().name("is_active"),
   createdAt: __t.u64().name("created_at"),
   taxonomyNodeId: __t.option(__t.u64()).name("taxonomy_node_id"),
+  followerCount: __t.u64().name("follower_count"),
 });
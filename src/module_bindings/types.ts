@@ -10,6 +10,19 @@ import {
   type Infer as __Infer,
 } from "spacetimedb";
 
+export const ActivityEvent = __t.object("ActivityEvent", {
+  id: __t.u64(),
+  actorIdentity: __t.string(),
+  eventType: __t.string(),
+  blockId: __t.u64(),
+  commentId: __t.u64(),
+  topicId: __t.u64(),
+  targetIdentity: __t.string(),
+  createdAt: __t.u64(),
+  fannedOut: __t.bool(),
+});
+export type ActivityEvent = __Infer<typeof ActivityEvent>;
+
 export const AdPlacement = __t.object("AdPlacement", {
   id: __t.u64(),
   topicId: __t.u64(),
@@ -47,6 +60,33 @@ export const ApiUsageLog = __t.object("ApiUsageLog", {
 });
 export type ApiUsageLog = __Infer<typeof ApiUsageLog>;
 
+export const AuditLogQueryResult = __t.object("AuditLogQueryResult", {
+  id: __t.u64(),
+  viewerIdentity: __t.string(),
+  auditLogId: __t.u64(),
+  actorIdentity: __t.string(),
+  action: __t.string(),
+  targetType: __t.string(),
+  targetId: __t.string(),
+  beforeJson: __t.string(),
+  afterJson: __t.string(),
+  createdAt: __t.u64(),
+});
+export type AuditLogQueryResult = __Infer<typeof AuditLogQueryResult>;
+
+export const AutoModerationConfig = __t.object("AutoModerationConfig", {
+  id: __t.u32(),
+  enabled: __t.bool(),
+  blockMinReporters: __t.u64(),
+  blockMinWeight: __t.u64(),
+  commentMinReporters: __t.u64(),
+  commentMinWeight: __t.u64(),
+  newAccountHours: __t.u64(),
+  updatedBy: __t.string(),
+  updatedAt: __t.u64(),
+});
+export type AutoModerationConfig = __Infer<typeof AutoModerationConfig>;
+
 export const Block = __t.object("Block", {
   id: __t.u64(),
   topicId: __t.u64(),
@@ -65,9 +105,21 @@ export const Block = __t.object("Block", {
   adImageUrl: __t.string(),
   adLinkUrl: __t.string(),
   claimedAt: __t.u64(),
+  hidden: __t.bool(),
 });
 export type Block = __Infer<typeof Block>;
 
+export const BlockedTerm = __t.object("BlockedTerm", {
+  id: __t.u64(),
+  term: __t.string(),
+  matchMode: __t.string(),
+  action: __t.string(),
+  topicId: __t.u64(),
+  createdBy: __t.string(),
+  createdAt: __t.u64(),
+});
+export type BlockedTerm = __Infer<typeof BlockedTerm>;
+
 export const ClerkIdentityMap = __t.object("ClerkIdentityMap", {
   clerkUserId: __t.string(),
   spacetimedbIdentity: __t.string(),
@@ -87,6 +139,7 @@ export const Comment = __t.object("Comment", {
   repliesCount: __t.u64(),
   repostsCount: __t.u64(),
   editedAt: __t.u64(),
+  hidden: __t.bool(),
 });
 export type Comment = __Infer<typeof Comment>;
 
@@ -98,6 +151,14 @@ export const CommentLike = __t.object("CommentLike", {
 });
 export type CommentLike = __Infer<typeof CommentLike>;
 
+export const CommentViewEntry = __t.object("CommentViewEntry", {
+  id: __t.u64(),
+  viewerIdentity: __t.string(),
+  blockId: __t.u64(),
+  commentId: __t.u64(),
+});
+export type CommentViewEntry = __Infer<typeof CommentViewEntry>;
+
 export const Contest = __t.object("Contest", {
   id: __t.u64(),
   startAt: __t.u64(),
@@ -129,9 +190,30 @@ export const Conversation = __t.object("Conversation", {
   requestRecipient: __t.string(),
   createdAt: __t.u64(),
   updatedAt: __t.u64(),
+  isGroup: __t.bool(),
+  title: __t.option(__t.string()),
 });
 export type Conversation = __Infer<typeof Conversation>;
 
+export const ConversationParticipant = __t.object("ConversationParticipant", {
+  id: __t.u64(),
+  conversationId: __t.u64(),
+  identity: __t.string(),
+  isAdmin: __t.bool(),
+  joinedAt: __t.u64(),
+});
+export type ConversationParticipant = __Infer<typeof ConversationParticipant>;
+
+export const ConversationReadState = __t.object("ConversationReadState", {
+  id: __t.u64(),
+  conversationId: __t.u64(),
+  identity: __t.string(),
+  lastReadMessageId: __t.u64(),
+  lastReadAt: __t.u64(),
+  hiddenThroughMessageId: __t.u64(),
+});
+export type ConversationReadState = __Infer<typeof ConversationReadState>;
+
 export const CreditTransactionLog = __t.object("CreditTransactionLog", {
   id: __t.u64(),
   userIdentity: __t.string(),
@@ -153,6 +235,10 @@ export const DirectMessage = __t.object("DirectMessage", {
   createdAt: __t.u64(),
   conversationId: __t.u64(),
   isDeleted: __t.bool(),
+  editedAt: __t.u64(),
+  attachmentBlockId: __t.u64(),
+  attachmentTopicId: __t.u64(),
+  attachmentCommentId: __t.u64(),
 });
 export type DirectMessage = __Infer<typeof DirectMessage>;
 
@@ -164,6 +250,30 @@ export const DislikeRecord = __t.object("DislikeRecord", {
 });
 export type DislikeRecord = __Infer<typeof DislikeRecord>;
 
+export const FeedEntry = __t.object("FeedEntry", {
+  id: __t.u64(),
+  ownerIdentity: __t.string(),
+  eventId: __t.u64(),
+  actorIdentity: __t.string(),
+  eventType: __t.string(),
+  blockId: __t.u64(),
+  commentId: __t.u64(),
+  topicId: __t.u64(),
+  targetIdentity: __t.string(),
+  createdAt: __t.u64(),
+});
+export type FeedEntry = __Infer<typeof FeedEntry>;
+
+export const FollowRequest = __t.object("FollowRequest", {
+  id: __t.u64(),
+  requesterIdentity: __t.string(),
+  targetIdentity: __t.string(),
+  status: __t.string(),
+  createdAt: __t.u64(),
+  respondedAt: __t.u64(),
+});
+export type FollowRequest = __Infer<typeof FollowRequest>;
+
 export const LikeRecord = __t.object("LikeRecord", {
   id: __t.u64(),
   blockId: __t.u64(),
@@ -172,6 +282,35 @@ export const LikeRecord = __t.object("LikeRecord", {
 });
 export type LikeRecord = __Infer<typeof LikeRecord>;
 
+export const MessageReaction = __t.object("MessageReaction", {
+  id: __t.u64(),
+  messageId: __t.u64(),
+  identity: __t.string(),
+  emoji: __t.string(),
+  createdAt: __t.u64(),
+});
+export type MessageReaction = __Infer<typeof MessageReaction>;
+
+export const ModerationQueueItem = __t.object("ModerationQueueItem", {
+  id: __t.u64(),
+  targetType: __t.string(),
+  topicId: __t.u64(),
+  blockId: __t.u64(),
+  commentId: __t.u64(),
+  reportedIdentity: __t.string(),
+  reportCount: __t.u64(),
+  lastReason: __t.string(),
+  status: __t.string(),
+  resolution: __t.string(),
+  resolvedBy: __t.string(),
+  createdAt: __t.u64(),
+  updatedAt: __t.u64(),
+  resolvedAt: __t.u64(),
+  escalated: __t.bool(),
+  autoHidden: __t.bool(),
+});
+export type ModerationQueueItem = __Infer<typeof ModerationQueueItem>;
+
 export const Notification = __t.object("Notification", {
   id: __t.u64(),
   recipientIdentity: __t.string(),
@@ -182,9 +321,50 @@ export const Notification = __t.object("Notification", {
   commentId: __t.u64(),
   isRead: __t.bool(),
   createdAt: __t.u64(),
+  topicId: __t.u64(),
+  actorMuted: __t.bool(),
+  digest: __t.bool(),
+  actorCount: __t.u64(),
+  latestActors: __t.option(__t.string()),
+  updatedAt: __t.u64(),
+  announcementId: __t.u64(),
+  conversationId: __t.u64(),
 });
 export type Notification = __Infer<typeof Notification>;
 
+export const NotificationPreference = __t.object("NotificationPreference", {
+  id: __t.u64(),
+  identity: __t.string(),
+  notificationType: __t.string(),
+  inApp: __t.bool(),
+  digestOnly: __t.bool(),
+  updatedAt: __t.u64(),
+});
+export type NotificationPreference = __Infer<typeof NotificationPreference>;
+
+export const NotificationRetentionConfig = __t.object("NotificationRetentionConfig", {
+  id: __t.u32(),
+  readRetentionDays: __t.u64(),
+  maxUnreadPerUser: __t.u64(),
+  pruneIntervalHours: __t.u64(),
+  updatedBy: __t.string(),
+  updatedAt: __t.u64(),
+});
+export type NotificationRetentionConfig = __Infer<typeof NotificationRetentionConfig>;
+
+export const PendingAdminAction = __t.object("PendingAdminAction", {
+  id: __t.u64(),
+  action: __t.string(),
+  payloadJson: __t.string(),
+  proposedBy: __t.string(),
+  status: __t.string(),
+  resolvedBy: __t.string(),
+  createdAt: __t.u64(),
+  expiresAt: __t.u64(),
+  resolvedAt: __t.u64(),
+});
+export type PendingAdminAction = __Infer<typeof PendingAdminAction>;
+
 export const SavedBlock = __t.object("SavedBlock", {
   id: __t.u64(),
   userIdentity: __t.string(),
@@ -194,6 +374,18 @@ export const SavedBlock = __t.object("SavedBlock", {
 });
 export type SavedBlock = __Infer<typeof SavedBlock>;
 
+export const Suggestion = __t.object("Suggestion", {
+  id: __t.u64(),
+  identity: __t.string(),
+  suggestionType: __t.string(),
+  suggestedIdentity: __t.string(),
+  topicId: __t.u64(),
+  score: __t.u64(),
+  reason: __t.string(),
+  createdAt: __t.u64(),
+});
+export type Suggestion = __Infer<typeof Suggestion>;
+
 export const Topic = __t.object("Topic", {
   id: __t.u64(),
   slug: __t.string(),
@@ -208,9 +400,19 @@ export const Topic = __t.object("Topic", {
   isActive: __t.bool(),
   createdAt: __t.u64(),
   taxonomyNodeId: __t.option(__t.u64()),
+  followerCount: __t.u64(),
 });
 export type Topic = __Infer<typeof Topic>;
 
+export const TopicAnnouncement = __t.object("TopicAnnouncement", {
+  id: __t.u64(),
+  topicId: __t.u64(),
+  authorIdentity: __t.string(),
+  text: __t.string(),
+  createdAt: __t.u64(),
+});
+export type TopicAnnouncement = __Infer<typeof TopicAnnouncement>;
+
 export const TopicBan = __t.object("TopicBan", {
   id: __t.u64(),
   topicId: __t.u64(),
@@ -218,9 +420,25 @@ export const TopicBan = __t.object("TopicBan", {
   bannedBy: __t.string(),
   reason: __t.string(),
   createdAt: __t.u64(),
+  expiresAt: __t.option(__t.u64()),
+  contentAction: __t.option(__t.string()),
 });
 export type TopicBan = __Infer<typeof TopicBan>;
 
+export const TopicBanAppeal = __t.object("TopicBanAppeal", {
+  id: __t.u64(),
+  banId: __t.u64(),
+  topicId: __t.u64(),
+  appellantIdentity: __t.string(),
+  message: __t.string(),
+  status: __t.string(),
+  reviewedBy: __t.string(),
+  reviewNote: __t.string(),
+  createdAt: __t.u64(),
+  reviewedAt: __t.u64(),
+});
+export type TopicBanAppeal = __Infer<typeof TopicBanAppeal>;
+
 export const TopicFollow = __t.object("TopicFollow", {
   id: __t.u64(),
   followerIdentity: __t.string(),
@@ -297,6 +515,7 @@ export const UserMute = __t.object("UserMute", {
   muterIdentity: __t.string(),
   mutedIdentity: __t.string(),
   createdAt: __t.u64(),
+  expiresAt: __t.option(__t.u64()),
 });
 export type UserMute = __Infer<typeof UserMute>;
 
@@ -318,6 +537,12 @@ export const UserProfile = __t.object("UserProfile", {
   socialYoutube: __t.option(__t.string()),
   socialTiktok: __t.option(__t.string()),
   socialInstagram: __t.option(__t.string()),
+  isPrivate: __t.bool(),
+  followerCount: __t.u64(),
+  followingCount: __t.u64(),
+  topicFollowCount: __t.u64(),
+  totalLikesReceived: __t.u64(),
+  videosPosted: __t.u64(),
 });
 export type UserProfile = __Infer<typeof UserProfile>;
 
@@ -331,6 +556,22 @@ export const UserReport = __t.object("UserReport", {
   reviewedBy: __t.string(),
   createdAt: __t.u64(),
   reviewedAt: __t.u64(),
+  targetType: __t.option(__t.string()),
+  blockId: __t.u64(),
+  commentId: __t.u64(),
+  topicId: __t.u64(),
+  queueItemId: __t.u64(),
+  resolution: __t.option(__t.string()),
 });
 export type UserReport = __Infer<typeof UserReport>;
 
+export const UserStrike = __t.object("UserStrike", {
+  id: __t.u64(),
+  identity: __t.string(),
+  reportId: __t.u64(),
+  action: __t.string(),
+  issuedBy: __t.string(),
+  createdAt: __t.u64(),
+});
+export type UserStrike = __Infer<typeof UserStrike>;
+
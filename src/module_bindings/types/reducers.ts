@@ -7,15 +7,24 @@ import { type Infer as __Infer } from "spacetimedb";
 
 // Import all reducer arg schemas
 import AcceptMessageRequestReducer from "../accept_message_request_reducer";
+import AddBlockedTermReducer from "../add_blocked_term_reducer";
 import AddCommentReducer from "../add_comment_reducer";
 import AddCreditsReducer from "../add_credits_reducer";
+import AnnounceToTopicFollowersReducer from "../announce_to_topic_followers_reducer";
 import ApplyTopicModeratorReducer from "../apply_topic_moderator_reducer";
+import ApproveFollowRequestReducer from "../approve_follow_request_reducer";
 import BackfillTopicTaxonomyFromCategoriesReducer from "../backfill_topic_taxonomy_from_categories_reducer";
 import BanUserFromTopicReducer from "../ban_user_from_topic_reducer";
 import BlockUserReducer from "../block_user_reducer";
+import CancelAdminActionReducer from "../cancel_admin_action_reducer";
+import CancelFollowRequestReducer from "../cancel_follow_request_reducer";
 import ClaimBlockInTopicReducer from "../claim_block_in_topic_reducer";
 import ClearAllBlocksReducer from "../clear_all_blocks_reducer";
+import ClearAuditLogQueryReducer from "../clear_audit_log_query_reducer";
+import ClearCommentViewReducer from "../clear_comment_view_reducer";
+import ConfirmAdminActionReducer from "../confirm_admin_action_reducer";
 import CreateContestReducer from "../create_contest_reducer";
+import CreateGroupConversationReducer from "../create_group_conversation_reducer";
 import CreateTopicReducer from "../create_topic_reducer";
 import CreateTopicTaxonomyNodeReducer from "../create_topic_taxonomy_node_reducer";
 import DeclineMessageRequestReducer from "../decline_message_request_reducer";
@@ -24,46 +33,79 @@ import DeleteConversationReducer from "../delete_conversation_reducer";
 import DeleteTopicReducer from "../delete_topic_reducer";
 import DevClearAllUsersReducer from "../dev_clear_all_users_reducer";
 import DevSeedTopicReducer from "../dev_seed_topic_reducer";
-import DevSetAdminReducer from "../dev_set_admin_reducer";
 import DislikeVideoReducer from "../dislike_video_reducer";
+import DismissSuggestionReducer from "../dismiss_suggestion_reducer";
 import EditBlockReducer from "../edit_block_reducer";
 import EditCommentReducer from "../edit_comment_reducer";
+import EditMessageReducer from "../edit_message_reducer";
 import FinalizeContestReducer from "../finalize_contest_reducer";
 import FollowTopicReducer from "../follow_topic_reducer";
 import FollowUserReducer from "../follow_user_reducer";
 import IncrementTopicViewsReducer from "../increment_topic_views_reducer";
+import InviteToConversationReducer from "../invite_to_conversation_reducer";
+import LeaveConversationReducer from "../leave_conversation_reducer";
+import LiftUserSanctionReducer from "../lift_user_sanction_reducer";
 import LikeCommentReducer from "../like_comment_reducer";
 import LikeVideoReducer from "../like_video_reducer";
+import LoadCommentViewReducer from "../load_comment_view_reducer";
 import MarkAdPaidReducer from "../mark_ad_paid_reducer";
 import MarkAllMessagesReadReducer from "../mark_all_messages_read_reducer";
 import MarkAllNotificationsReadReducer from "../mark_all_notifications_read_reducer";
+import MarkConversationReadReducer from "../mark_conversation_read_reducer";
 import MarkMessageReadReducer from "../mark_message_read_reducer";
 import MarkNotificationReadReducer from "../mark_notification_read_reducer";
 import ModRemoveBlockReducer from "../mod_remove_block_reducer";
 import MuteUserReducer from "../mute_user_reducer";
 import PlaceAdReducer from "../place_ad_reducer";
+import QueryAuditLogByActorReducer from "../query_audit_log_by_actor_reducer";
+import QueryAuditLogByTargetReducer from "../query_audit_log_by_target_reducer";
+import ReactToMessageReducer from "../react_to_message_reducer";
 import RebalanceTopicReducer from "../rebalance_topic_reducer";
+import RecomputeProfileCountersReducer from "../recompute_profile_counters_reducer";
+import RefreshFeedReducer from "../refresh_feed_reducer";
+import RefreshMySuggestionsReducer from "../refresh_my_suggestions_reducer";
 import RegisterUserReducer from "../register_user_reducer";
+import RejectFollowRequestReducer from "../reject_follow_request_reducer";
 import RemoveAdReducer from "../remove_ad_reducer";
+import RemoveBlockedTermReducer from "../remove_blocked_term_reducer";
+import RemoveFollowerReducer from "../remove_follower_reducer";
+import RemoveFromConversationReducer from "../remove_from_conversation_reducer";
 import RemoveTopicModeratorReducer from "../remove_topic_moderator_reducer";
+import RenameConversationReducer from "../rename_conversation_reducer";
+import ReportBlockReducer from "../report_block_reducer";
+import ReportCommentReducer from "../report_comment_reducer";
 import ReportUserReducer from "../report_user_reducer";
 import RepostCommentReducer from "../repost_comment_reducer";
+import ResolveModerationItemReducer from "../resolve_moderation_item_reducer";
+import ReviewBanAppealReducer from "../review_ban_appeal_reducer";
 import ReviewReportReducer from "../review_report_reducer";
 import ReviewTopicModeratorApplicationReducer from "../review_topic_moderator_application_reducer";
 import SaveBlockReducer from "../save_block_reducer";
 import SeedAdsReducer from "../seed_ads_reducer";
 import SeedDataReducer from "../seed_data_reducer";
+import SendGroupMessageReducer from "../send_group_message_reducer";
+import SendGroupMessageWithAttachmentReducer from "../send_group_message_with_attachment_reducer";
 import SendMessageReducer from "../send_message_reducer";
+import SendMessageWithAttachmentReducer from "../send_message_with_attachment_reducer";
 import ServerAddApiCreditsReducer from "../server_add_api_credits_reducer";
 import ServerDeleteUserReducer from "../server_delete_user_reducer";
 import ServerRecordApiUsageReducer from "../server_record_api_usage_reducer";
 import ServerRegisterApiKeyReducer from "../server_register_api_key_reducer";
 import ServerRevokeApiKeyReducer from "../server_revoke_api_key_reducer";
 import ServerUpdateProfileReducer from "../server_update_profile_reducer";
+import SetAccountPrivateReducer from "../set_account_private_reducer";
 import SetAdminReducer from "../set_admin_reducer";
+import SetAutoModerationConfigReducer from "../set_auto_moderation_config_reducer";
+import SetConversationAdminReducer from "../set_conversation_admin_reducer";
+import SetNotificationPreferenceReducer from "../set_notification_preference_reducer";
+import SetNotificationRetentionReducer from "../set_notification_retention_reducer";
 import SetTopicTaxonomyReducer from "../set_topic_taxonomy_reducer";
+import SetTopicTermOverrideReducer from "../set_topic_term_override_reducer";
+import ShadowBanUserReducer from "../shadow_ban_user_reducer";
 import SpendCreditsReducer from "../spend_credits_reducer";
 import StoreClerkMappingReducer from "../store_clerk_mapping_reducer";
+import SubmitBanAppealReducer from "../submit_ban_appeal_reducer";
+import SuspendUserReducer from "../suspend_user_reducer";
 import UnbanUserFromTopicReducer from "../unban_user_from_topic_reducer";
 import UnblockUserReducer from "../unblock_user_reducer";
 import UnclaimBlockReducer from "../unclaim_block_reducer";
@@ -74,21 +116,31 @@ import UnlikeCommentReducer from "../unlike_comment_reducer";
 import UnlikeVideoReducer from "../unlike_video_reducer";
 import UnmuteUserReducer from "../unmute_user_reducer";
 import UnsaveBlockReducer from "../unsave_block_reducer";
+import UnsendMessageReducer from "../unsend_message_reducer";
 import UpdateProfileReducer from "../update_profile_reducer";
 import UpdateProfileDetailsReducer from "../update_profile_details_reducer";
 import UpdateStripeAccountReducer from "../update_stripe_account_reducer";
 import UpdateTopicReducer from "../update_topic_reducer";
 
 export type AcceptMessageRequestParams = __Infer<typeof AcceptMessageRequestReducer>;
+export type AddBlockedTermParams = __Infer<typeof AddBlockedTermReducer>;
 export type AddCommentParams = __Infer<typeof AddCommentReducer>;
 export type AddCreditsParams = __Infer<typeof AddCreditsReducer>;
+export type AnnounceToTopicFollowersParams = __Infer<typeof AnnounceToTopicFollowersReducer>;
 export type ApplyTopicModeratorParams = __Infer<typeof ApplyTopicModeratorReducer>;
+export type ApproveFollowRequestParams = __Infer<typeof ApproveFollowRequestReducer>;
 export type BackfillTopicTaxonomyFromCategoriesParams = __Infer<typeof BackfillTopicTaxonomyFromCategoriesReducer>;
 export type BanUserFromTopicParams = __Infer<typeof BanUserFromTopicReducer>;
 export type BlockUserParams = __Infer<typeof BlockUserReducer>;
+export type CancelAdminActionParams = __Infer<typeof CancelAdminActionReducer>;
+export type CancelFollowRequestParams = __Infer<typeof CancelFollowRequestReducer>;
 export type ClaimBlockInTopicParams = __Infer<typeof ClaimBlockInTopicReducer>;
 export type ClearAllBlocksParams = __Infer<typeof ClearAllBlocksReducer>;
+export type ClearAuditLogQueryParams = __Infer<typeof ClearAuditLogQueryReducer>;
+export type ClearCommentViewParams = __Infer<typeof ClearCommentViewReducer>;
+export type ConfirmAdminActionParams = __Infer<typeof ConfirmAdminActionReducer>;
 export type CreateContestParams = __Infer<typeof CreateContestReducer>;
+export type CreateGroupConversationParams = __Infer<typeof CreateGroupConversationReducer>;
 export type CreateTopicParams = __Infer<typeof CreateTopicReducer>;
 export type CreateTopicTaxonomyNodeParams = __Infer<typeof CreateTopicTaxonomyNodeReducer>;
 export type DeclineMessageRequestParams = __Infer<typeof DeclineMessageRequestReducer>;
@@ -97,46 +149,79 @@ export type DeleteConversationParams = __Infer<typeof DeleteConversationReducer>
 export type DeleteTopicParams = __Infer<typeof DeleteTopicReducer>;
 export type DevClearAllUsersParams = __Infer<typeof DevClearAllUsersReducer>;
 export type DevSeedTopicParams = __Infer<typeof DevSeedTopicReducer>;
-export type DevSetAdminParams = __Infer<typeof DevSetAdminReducer>;
 export type DislikeVideoParams = __Infer<typeof DislikeVideoReducer>;
+export type DismissSuggestionParams = __Infer<typeof DismissSuggestionReducer>;
 export type EditBlockParams = __Infer<typeof EditBlockReducer>;
 export type EditCommentParams = __Infer<typeof EditCommentReducer>;
+export type EditMessageParams = __Infer<typeof EditMessageReducer>;
 export type FinalizeContestParams = __Infer<typeof FinalizeContestReducer>;
 export type FollowTopicParams = __Infer<typeof FollowTopicReducer>;
 export type FollowUserParams = __Infer<typeof FollowUserReducer>;
 export type IncrementTopicViewsParams = __Infer<typeof IncrementTopicViewsReducer>;
+export type InviteToConversationParams = __Infer<typeof InviteToConversationReducer>;
+export type LeaveConversationParams = __Infer<typeof LeaveConversationReducer>;
+export type LiftUserSanctionParams = __Infer<typeof LiftUserSanctionReducer>;
 export type LikeCommentParams = __Infer<typeof LikeCommentReducer>;
 export type LikeVideoParams = __Infer<typeof LikeVideoReducer>;
+export type LoadCommentViewParams = __Infer<typeof LoadCommentViewReducer>;
 export type MarkAdPaidParams = __Infer<typeof MarkAdPaidReducer>;
 export type MarkAllMessagesReadParams = __Infer<typeof MarkAllMessagesReadReducer>;
 export type MarkAllNotificationsReadParams = __Infer<typeof MarkAllNotificationsReadReducer>;
+export type MarkConversationReadParams = __Infer<typeof MarkConversationReadReducer>;
 export type MarkMessageReadParams = __Infer<typeof MarkMessageReadReducer>;
 export type MarkNotificationReadParams = __Infer<typeof MarkNotificationReadReducer>;
 export type ModRemoveBlockParams = __Infer<typeof ModRemoveBlockReducer>;
 export type MuteUserParams = __Infer<typeof MuteUserReducer>;
 export type PlaceAdParams = __Infer<typeof PlaceAdReducer>;
+export type QueryAuditLogByActorParams = __Infer<typeof QueryAuditLogByActorReducer>;
+export type QueryAuditLogByTargetParams = __Infer<typeof QueryAuditLogByTargetReducer>;
+export type ReactToMessageParams = __Infer<typeof ReactToMessageReducer>;
 export type RebalanceTopicParams = __Infer<typeof RebalanceTopicReducer>;
+export type RecomputeProfileCountersParams = __Infer<typeof RecomputeProfileCountersReducer>;
+export type RefreshFeedParams = __Infer<typeof RefreshFeedReducer>;
+export type RefreshMySuggestionsParams = __Infer<typeof RefreshMySuggestionsReducer>;
 export type RegisterUserParams = __Infer<typeof RegisterUserReducer>;
+export type RejectFollowRequestParams = __Infer<typeof RejectFollowRequestReducer>;
 export type RemoveAdParams = __Infer<typeof RemoveAdReducer>;
+export type RemoveBlockedTermParams = __Infer<typeof RemoveBlockedTermReducer>;
+export type RemoveFollowerParams = __Infer<typeof RemoveFollowerReducer>;
+export type RemoveFromConversationParams = __Infer<typeof RemoveFromConversationReducer>;
 export type RemoveTopicModeratorParams = __Infer<typeof RemoveTopicModeratorReducer>;
+export type RenameConversationParams = __Infer<typeof RenameConversationReducer>;
+export type ReportBlockParams = __Infer<typeof ReportBlockReducer>;
+export type ReportCommentParams = __Infer<typeof ReportCommentReducer>;
 export type ReportUserParams = __Infer<typeof ReportUserReducer>;
 export type RepostCommentParams = __Infer<typeof RepostCommentReducer>;
+export type ResolveModerationItemParams = __Infer<typeof ResolveModerationItemReducer>;
+export type ReviewBanAppealParams = __Infer<typeof ReviewBanAppealReducer>;
 export type ReviewReportParams = __Infer<typeof ReviewReportReducer>;
 export type ReviewTopicModeratorApplicationParams = __Infer<typeof ReviewTopicModeratorApplicationReducer>;
 export type SaveBlockParams = __Infer<typeof SaveBlockReducer>;
 export type SeedAdsParams = __Infer<typeof SeedAdsReducer>;
 export type SeedDataParams = __Infer<typeof SeedDataReducer>;
+export type SendGroupMessageParams = __Infer<typeof SendGroupMessageReducer>;
+export type SendGroupMessageWithAttachmentParams = __Infer<typeof SendGroupMessageWithAttachmentReducer>;
 export type SendMessageParams = __Infer<typeof SendMessageReducer>;
+export type SendMessageWithAttachmentParams = __Infer<typeof SendMessageWithAttachmentReducer>;
 export type ServerAddApiCreditsParams = __Infer<typeof ServerAddApiCreditsReducer>;
 export type ServerDeleteUserParams = __Infer<typeof ServerDeleteUserReducer>;
 export type ServerRecordApiUsageParams = __Infer<typeof ServerRecordApiUsageReducer>;
 export type ServerRegisterApiKeyParams = __Infer<typeof ServerRegisterApiKeyReducer>;
 export type ServerRevokeApiKeyParams = __Infer<typeof ServerRevokeApiKeyReducer>;
 export type ServerUpdateProfileParams = __Infer<typeof ServerUpdateProfileReducer>;
+export type SetAccountPrivateParams = __Infer<typeof SetAccountPrivateReducer>;
 export type SetAdminParams = __Infer<typeof SetAdminReducer>;
+export type SetAutoModerationConfigParams = __Infer<typeof SetAutoModerationConfigReducer>;
+export type SetConversationAdminParams = __Infer<typeof SetConversationAdminReducer>;
+export type SetNotificationPreferenceParams = __Infer<typeof SetNotificationPreferenceReducer>;
+export type SetNotificationRetentionParams = __Infer<typeof SetNotificationRetentionReducer>;
 export type SetTopicTaxonomyParams = __Infer<typeof SetTopicTaxonomyReducer>;
+export type SetTopicTermOverrideParams = __Infer<typeof SetTopicTermOverrideReducer>;
+export type ShadowBanUserParams = __Infer<typeof ShadowBanUserReducer>;
 export type SpendCreditsParams = __Infer<typeof SpendCreditsReducer>;
 export type StoreClerkMappingParams = __Infer<typeof StoreClerkMappingReducer>;
+export type SubmitBanAppealParams = __Infer<typeof SubmitBanAppealReducer>;
+export type SuspendUserParams = __Infer<typeof SuspendUserReducer>;
 export type UnbanUserFromTopicParams = __Infer<typeof UnbanUserFromTopicReducer>;
 export type UnblockUserParams = __Infer<typeof UnblockUserReducer>;
 export type UnclaimBlockParams = __Infer<typeof UnclaimBlockReducer>;
@@ -147,6 +232,7 @@ export type UnlikeCommentParams = __Infer<typeof UnlikeCommentReducer>;
 export type UnlikeVideoParams = __Infer<typeof UnlikeVideoReducer>;
 export type UnmuteUserParams = __Infer<typeof UnmuteUserReducer>;
 export type UnsaveBlockParams = __Infer<typeof UnsaveBlockReducer>;
+export type UnsendMessageParams = __Infer<typeof UnsendMessageReducer>;
 export type UpdateProfileParams = __Infer<typeof UpdateProfileReducer>;
 export type UpdateProfileDetailsParams = __Infer<typeof UpdateProfileDetailsReducer>;
 export type UpdateStripeAccountParams = __Infer<typeof UpdateStripeAccountReducer>;
@@ -15,4 +15,5 @@ export default __t.row({
   muterIdentity: __t.string().name("muter_identity"),
   mutedIdentity: __t.string().name("muted_identity"),
   createdAt: __t.u64().name("created_at"),
+  expiresAt: __t.option(__t.u64()).name("expires_at"),
 });
@@ -28,4 +28,10 @@ export default __t.row({
   socialYoutube: __t.option(__t.string()).name("social_youtube"),
   socialTiktok: __t.option(__t.string()).name("social_tiktok"),
   socialInstagram: __t.option(__t.string()).name("social_instagram"),
+  isPrivate: __t.bool().name("is_private"),
+  followerCount: __t.u64().name("follower_count"),
+  followingCount: __t.u64().name("following_count"),
+  topicFollowCount: __t.u64().name("topic_follow_count"),
+  totalLikesReceived: __t.u64().name("total_likes_received"),
+  videosPosted: __t.u64().name("videos_posted"),
 });
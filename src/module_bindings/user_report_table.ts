@@ -20,4 +20,10 @@ export default __t.row({
   reviewedBy: __t.string().name("reviewed_by"),
   createdAt: __t.u64().name("created_at"),
   reviewedAt: __t.u64().name("reviewed_at"),
+  targetType: __t.option(__t.string()).name("target_type"),
+  blockId: __t.u64().name("block_id"),
+  commentId: __t.u64().name("comment_id"),
+  topicId: __t.u64().name("topic_id"),
+  queueItemId: __t.u64().name("queue_item_id"),
+  resolution: __t.option(__t.string()),
 });
@@ -22,11 +22,22 @@ export interface CommentLike {
   createdAt: number;
 }
 
+export interface CommentViewEntry {
+  id: number;
+  blockId: number;
+  commentId: number;
+}
+
 interface CommentsState {
   comments: Map<number, Comment>;
   byBlock: Map<number, number[]>;
   commentLikes: Map<number, CommentLike>;
   likesByComment: Map<number, Set<string>>;
+  /** Block whose comment view I have open; its comments are limited to the view. */
+  viewBlockId: number | null;
+  /** My comment view entries (the open block's comments minus authors I muted). */
+  viewEntries: Map<number, CommentViewEntry>;
+  viewCommentIds: Set<number>;
 
   setComments: (comments: Comment[]) => void;
   addComment: (comment: Comment) => void;
@@ -37,6 +48,10 @@ interface CommentsState {
   addCommentLike: (like: CommentLike) => void;
   removeCommentLike: (id: number) => void;
 
+  setViewBlock: (blockId: number | null) => void;
+  addViewEntry: (entry: CommentViewEntry) => void;
+  removeViewEntry: (id: number) => void;
+
   getCommentsForBlock: (blockId: number) => Comment[];
   getTopLevelComments: (blockId: number) => Comment[];
   getReplies: (parentCommentId: number) => Comment[];
@@ -44,11 +59,25 @@ interface CommentsState {
   getComment: (id: number) => Comment | undefined;
 }
 
+function viewCommentIdsOf(entries: Map<number, CommentViewEntry>): Set<number> {
+  const ids = new Set<number>();
+  for (const e of entries.values()) ids.add(e.commentId);
+  return ids;
+}
+
+/** Comments on the block I have a view open for must be in that view. */
+function isInView(state: CommentsState, comment: Comment): boolean {
+  return state.viewBlockId !== comment.blockId || state.viewCommentIds.has(comment.id);
+}
+
 export const useCommentsStore = create<CommentsState>((set, get) => ({
   comments: new Map(),
   byBlock: new Map(),
   commentLikes: new Map(),
   likesByComment: new Map(),
+  viewBlockId: null,
+  viewEntries: new Map(),
+  viewCommentIds: new Set(),
 
   setComments: (comments) => {
     const map = new Map<number, Comment>();
@@ -131,6 +160,23 @@ export const useCommentsStore = create<CommentsState>((set, get) => ({
     set({ commentLikes, likesByComment });
   },
 
+  setViewBlock: (blockId) => {
+    set({ viewBlockId: blockId });
+  },
+
+  addViewEntry: (entry) => {
+    const viewEntries = new Map(get().viewEntries);
+    viewEntries.set(entry.id, entry);
+    set({ viewEntries, viewCommentIds: viewCommentIdsOf(viewEntries) });
+  },
+
+  removeViewEntry: (id) => {
+    if (!get().viewEntries.has(id)) return;
+    const viewEntries = new Map(get().viewEntries);
+    viewEntries.delete(id);
+    set({ viewEntries, viewCommentIds: viewCommentIdsOf(viewEntries) });
+  },
+
   getCommentsForBlock: (blockId) => {
     const { comments, byBlock } = get();
     const ids = byBlock.get(blockId) ?? [];
@@ -141,18 +187,21 @@ export const useCommentsStore = create<CommentsState>((set, get) => ({
   },
 
   getTopLevelComments: (blockId) => {
-    const { comments, byBlock } = get();
-    const ids = byBlock.get(blockId) ?? [];
+    const state = get();
+    const ids = state.byBlock.get(blockId) ?? [];
     return ids
-      .map((id) => comments.get(id))
-      .filter((c): c is Comment => c !== undefined && c.parentCommentId === null && c.repostOfId === null)
+      .map((id) => state.comments.get(id))
+      .filter(
+        (c): c is Comment =>
+          c !== undefined && c.parentCommentId === null && c.repostOfId === null && isInView(state, c)
+      )
       .sort((a, b) => b.createdAt - a.createdAt);
   },
 
   getReplies: (parentCommentId) => {
-    const { comments } = get();
-    return [...comments.values()]
-      .filter((c) => c.parentCommentId === parentCommentId)
+    const state = get();
+    return [...state.comments.values()]
+      .filter((c) => c.parentCommentId === parentCommentId && isInView(state, c))
       .sort((a, b) => a.createdAt - b.createdAt);
   },
 
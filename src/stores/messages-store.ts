@@ -27,6 +27,12 @@ export interface DirectMessage {
   isRead: boolean;
   isDeleted: boolean;
   createdAt: number;
+  /** 0 = never edited */
+  editedAt: number;
+  /** Shared block / topic / comment ids (0 = none) */
+  attachmentBlockId: number;
+  attachmentTopicId: number;
+  attachmentCommentId: number;
 }
 
 export interface ConversationMeta {
@@ -50,9 +56,20 @@ export interface Conversation {
   unreadCount: number;
 }
 
+/** My read state in one conversation (ConversationReadState). */
+export interface ReadCursor {
+  lastReadMessageId: number;
+  /** Set by delete_conversation: messages up to this id are hidden for me */
+  hiddenThroughMessageId: number;
+}
+
+/** My read state per conversation, keyed by conversationId. */
+export type ReadCursors = Map<number, ReadCursor>;
+
 interface MessagesState {
   messages: Map<number, DirectMessage>;
   conversations: Map<number, ConversationMeta>;
+  readCursors: ReadCursors;
   myIdentity: string | null;
   totalUnread: number;
   requestCount: number;
@@ -69,6 +86,9 @@ interface MessagesState {
   setConversations: (convs: ConversationMeta[]) => void;
   removeConversation: (id: number) => void;
 
+  setReadCursor: (conversationId: number, cursor: ReadCursor) => void;
+  removeReadCursor: (conversationId: number) => void;
+
   setActiveTab: (tab: "primary" | "requests") => void;
   setSelectedConversation: (id: number | null) => void;
 
@@ -79,17 +99,34 @@ interface MessagesState {
   getConversationById: (conversationId: number) => Conversation | null;
 }
 
+/**
+ * Unread = sent to me and past my read cursor. Legacy messages (no conversation)
+ * and conversations I have no cursor for yet fall back to the per-message flag.
+ */
+function isUnread(m: DirectMessage, myIdentity: string | null, readCursors: ReadCursors): boolean {
+  if (m.recipientIdentity !== myIdentity || !isVisible(m, readCursors)) return false;
+  const cursor = m.conversationId === 0 ? undefined : readCursors.get(m.conversationId);
+  return cursor === undefined ? !m.isRead : m.id > cursor.lastReadMessageId;
+}
+
+/** Whether a message is still in my view of its conversation. */
+function isVisible(m: DirectMessage, readCursors: ReadCursors): boolean {
+  if (m.isDeleted) return false;
+  return m.id > (readCursors.get(m.conversationId)?.hiddenThroughMessageId ?? 0);
+}
+
 function computeUnreadCounts(
   messages: Map<number, DirectMessage>,
   conversations: Map<number, ConversationMeta>,
-  myIdentity: string | null
+  myIdentity: string | null,
+  readCursors: ReadCursors
 ): { totalUnread: number; requestCount: number } {
   if (!myIdentity) return { totalUnread: 0, requestCount: 0 };
   let totalUnread = 0;
   let requestCount = 0;
 
   for (const m of messages.values()) {
-    if (m.recipientIdentity === myIdentity && !m.isRead && !m.isDeleted) {
+    if (isUnread(m, myIdentity, readCursors)) {
       totalUnread++;
     }
   }
@@ -107,6 +144,7 @@ function buildConversationList(
   messages: Map<number, DirectMessage>,
   conversations: Map<number, ConversationMeta>,
   myIdentity: string | null,
+  readCursors: ReadCursors,
   statusFilter: "active" | "request_pending"
 ): Conversation[] {
   if (!myIdentity) return [];
@@ -126,7 +164,7 @@ function buildConversationList(
     // Collect messages for this conversation
     const convMessages: DirectMessage[] = [];
     for (const m of messages.values()) {
-      if (m.conversationId === conv.id && !m.isDeleted) {
+      if (m.conversationId === conv.id && isVisible(m, readCursors)) {
         convMessages.push(m);
       }
     }
@@ -146,9 +184,12 @@ function buildConversationList(
       }
     }
 
+    // Cleared with delete_conversation and nothing new since
+    if (convMessages.length === 0 && readCursors.get(conv.id)?.hiddenThroughMessageId) continue;
+
     const sorted = [...convMessages].sort((a, b) => b.createdAt - a.createdAt);
     const unreadCount = convMessages.filter(
-      (m) => m.recipientIdentity === myIdentity && !m.isRead
+      (m) => isUnread(m, myIdentity, readCursors)
     ).length;
 
     result.push({
@@ -184,7 +225,7 @@ function buildConversationList(
     for (const [otherIdentity, msgs] of orphanMap.entries()) {
       const sorted = [...msgs].sort((a, b) => b.createdAt - a.createdAt);
       const unreadCount = msgs.filter(
-        (m) => m.recipientIdentity === myIdentity && !m.isRead
+        (m) => isUnread(m, myIdentity, readCursors)
       ).length;
       result.push({
         id: 0, // no server conversation
@@ -205,6 +246,7 @@ function buildConversationList(
 export const useMessagesStore = create<MessagesState>((set, get) => ({
   messages: new Map(),
   conversations: new Map(),
+  readCursors: new Map(),
   myIdentity: null,
   totalUnread: 0,
   requestCount: 0,
@@ -212,97 +254,113 @@ export const useMessagesStore = create<MessagesState>((set, get) => ({
   selectedConversationId: null,
 
   setMyIdentity: (identity) => {
-    const { messages, conversations } = get();
-    const counts = computeUnreadCounts(messages, conversations, identity);
+    const { messages, conversations, readCursors } = get();
+    const counts = computeUnreadCounts(messages, conversations, identity, readCursors);
     set({ myIdentity: identity, ...counts });
   },
 
   addMessage: (message) => {
-    const { messages, conversations, myIdentity } = get();
+    const { messages, conversations, myIdentity, readCursors } = get();
     const updated = new Map(messages);
     updated.set(message.id, message);
-    const counts = computeUnreadCounts(updated, conversations, myIdentity);
+    const counts = computeUnreadCounts(updated, conversations, myIdentity, readCursors);
     set({ messages: updated, ...counts });
   },
 
   updateMessage: (message) => {
-    const { messages, conversations, myIdentity } = get();
+    const { messages, conversations, myIdentity, readCursors } = get();
     const updated = new Map(messages);
     updated.set(message.id, message);
-    const counts = computeUnreadCounts(updated, conversations, myIdentity);
+    const counts = computeUnreadCounts(updated, conversations, myIdentity, readCursors);
     set({ messages: updated, ...counts });
   },
 
   setMessages: (msgs) => {
-    const { myIdentity, conversations } = get();
+    const { myIdentity, conversations, readCursors } = get();
     const updated = new Map<number, DirectMessage>();
     for (const m of msgs) updated.set(m.id, m);
-    const counts = computeUnreadCounts(updated, conversations, myIdentity);
+    const counts = computeUnreadCounts(updated, conversations, myIdentity, readCursors);
     set({ messages: updated, ...counts });
   },
 
   addConversation: (conv) => {
-    const { conversations, messages, myIdentity } = get();
+    const { conversations, messages, myIdentity, readCursors } = get();
     const updated = new Map(conversations);
     updated.set(conv.id, conv);
-    const counts = computeUnreadCounts(messages, updated, myIdentity);
+    const counts = computeUnreadCounts(messages, updated, myIdentity, readCursors);
     set({ conversations: updated, ...counts });
   },
 
   updateConversation: (conv) => {
-    const { conversations, messages, myIdentity } = get();
+    const { conversations, messages, myIdentity, readCursors } = get();
     const updated = new Map(conversations);
     updated.set(conv.id, conv);
-    const counts = computeUnreadCounts(messages, updated, myIdentity);
+    const counts = computeUnreadCounts(messages, updated, myIdentity, readCursors);
     set({ conversations: updated, ...counts });
   },
 
   setConversations: (convs) => {
-    const { messages, myIdentity } = get();
+    const { messages, myIdentity, readCursors } = get();
     const map = new Map<number, ConversationMeta>();
     for (const c of convs) map.set(c.id, c);
-    const counts = computeUnreadCounts(messages, map, myIdentity);
+    const counts = computeUnreadCounts(messages, map, myIdentity, readCursors);
     set({ conversations: map, ...counts });
   },
 
   removeConversation: (id) => {
-    const { conversations, messages, myIdentity } = get();
+    const { conversations, messages, myIdentity, readCursors } = get();
     const updated = new Map(conversations);
     updated.delete(id);
-    const counts = computeUnreadCounts(messages, updated, myIdentity);
+    const counts = computeUnreadCounts(messages, updated, myIdentity, readCursors);
     set({ conversations: updated, ...counts });
   },
 
+  setReadCursor: (conversationId, cursor) => {
+    const { messages, conversations, myIdentity, readCursors } = get();
+    const updated = new Map(readCursors);
+    updated.set(conversationId, cursor);
+    const counts = computeUnreadCounts(messages, conversations, myIdentity, updated);
+    set({ readCursors: updated, ...counts });
+  },
+
+  removeReadCursor: (conversationId) => {
+    const { messages, conversations, myIdentity, readCursors } = get();
+    const updated = new Map(readCursors);
+    updated.delete(conversationId);
+    const counts = computeUnreadCounts(messages, conversations, myIdentity, updated);
+    set({ readCursors: updated, ...counts });
+  },
+
   setActiveTab: (tab) => set({ activeTab: tab }),
   setSelectedConversation: (id) => set({ selectedConversationId: id }),
 
   getPrimaryConversations: () => {
-    const { messages, conversations, myIdentity } = get();
-    return buildConversationList(messages, conversations, myIdentity, "active");
+    const { messages, conversations, myIdentity, readCursors } = get();
+    return buildConversationList(messages, conversations, myIdentity, readCursors, "active");
   },
 
   getRequestConversations: () => {
-    const { messages, conversations, myIdentity } = get();
-    return buildConversationList(messages, conversations, myIdentity, "request_pending");
+    const { messages, conversations, myIdentity, readCursors } = get();
+    return buildConversationList(messages, conversations, myIdentity, readCursors, "request_pending");
   },
 
   // Legacy compat: returns all conversations (primary + requests)
   getConversations: () => {
-    const { messages, conversations, myIdentity } = get();
-    const primary = buildConversationList(messages, conversations, myIdentity, "active");
-    const requests = buildConversationList(messages, conversations, myIdentity, "request_pending");
+    const { messages, conversations, myIdentity, readCursors } = get();
+    const primary = buildConversationList(messages, conversations, myIdentity, readCursors, "active");
+    const requests = buildConversationList(messages, conversations, myIdentity, readCursors, "request_pending");
     return [...primary, ...requests].sort(
       (a, b) => (b.lastMessage?.createdAt ?? 0) - (a.lastMessage?.createdAt ?? 0)
     );
   },
 
   getConversation: (otherIdentity) => {
-    const { messages, myIdentity } = get();
+    const { messages, myIdentity, readCursors } = get();
     if (!myIdentity) return [];
     return [...messages.values()]
       .filter(
         (m) =>
-          !m.isDeleted &&
+          isVisible(m, readCursors) &&
           ((m.senderIdentity === myIdentity && m.recipientIdentity === otherIdentity) ||
             (m.senderIdentity === otherIdentity && m.recipientIdentity === myIdentity))
       )
@@ -310,7 +368,7 @@ export const useMessagesStore = create<MessagesState>((set, get) => ({
   },
 
   getConversationById: (conversationId) => {
-    const { conversations, messages, myIdentity } = get();
+    const { conversations, messages, myIdentity, readCursors } = get();
     if (!myIdentity) return null;
     const conv = conversations.get(conversationId);
     if (!conv) return null;
@@ -320,7 +378,7 @@ export const useMessagesStore = create<MessagesState>((set, get) => ({
 
     const convMessages: DirectMessage[] = [];
     for (const m of messages.values()) {
-      if (m.conversationId === conv.id && !m.isDeleted) {
+      if (m.conversationId === conv.id && isVisible(m, readCursors)) {
         convMessages.push(m);
       }
     }
@@ -328,7 +386,7 @@ export const useMessagesStore = create<MessagesState>((set, get) => ({
     const sorted = [...convMessages].sort((a, b) => b.createdAt - a.createdAt);
 
     const unreadCount = convMessages.filter(
-      (m) => m.recipientIdentity === myIdentity && !m.isRead
+      (m) => isUnread(m, myIdentity, readCursors)
     ).length;
 
     return {
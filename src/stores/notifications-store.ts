@@ -8,9 +8,13 @@ export type NotificationType =
   | "new_message"
   | "message_request"
   | "new_follow"
-  | "topic_new_video"
+  | "follow_request"
+  | "follow_request_approved"
+  | "new_video_in_topic"
+  | "topic_announcement"
   | "contest_result"
-  | "moderator_application_reviewed";
+  | "moderator_application_reviewed"
+  | "notification_digest";
 
 export interface Notification {
   id: number;
@@ -20,8 +24,15 @@ export interface Notification {
   notificationType: NotificationType;
   blockId: number;
   commentId: number;
+  topicId: number;
   isRead: boolean;
   createdAt: number;
+  /** I muted the actor; left out of the inbox and the unread count. */
+  actorMuted: boolean;
+  /** Digest-only type: shown in the inbox but not counted until the daily digest announces it. */
+  digest: boolean;
+  /** Distinct actors in a grouped notification; for notification_digest, the number of digested notifications. */
+  actorCount: number;
 }
 
 interface NotificationsState {
@@ -40,7 +51,7 @@ interface NotificationsState {
 function computeUnread(map: Map<number, Notification>): number {
   let count = 0;
   for (const n of map.values()) {
-    if (!n.isRead) count++;
+    if (!n.isRead && !n.actorMuted && !n.digest) count++;
   }
   return count;
 }